@@ -1,16 +1,21 @@
-use fj_math::{Circle, Point};
+use fj_math::{Circle, Ellipse, Point};
 
-use crate::geometry::{traits::GenPolyline, CurveBoundary, Tolerance};
+use crate::geometry::{
+    traits::{GenPolyline, PolylineOptions},
+    CurveBoundary, Tolerance,
+};
 
 /// # Approximate a circle
 ///
 /// ## Arguments
 ///
-/// Besides a circle, this method takes two arguments:
+/// Besides a circle, this method takes three arguments:
 ///
 /// - The `boundary` within which the circle should be approximated.
 /// - The `tolerance` that specifies how much the approximation is allowed to
 ///   deviate from the actual circle.
+/// - The `options`, which limit the number of segments (see
+///   [`PolylineOptions`]).
 ///
 /// ## Return Value
 ///
@@ -41,16 +46,43 @@ pub fn approx_circle<const D: usize>(
     circle: &Circle<D>,
     boundary: impl Into<CurveBoundary<Point<1>>>,
     tolerance: impl Into<Tolerance>,
+    options: PolylineOptions,
 ) -> Vec<(Point<1>, Point<D>)> {
     let boundary = boundary.into();
     let tolerance = tolerance.into();
 
     let mut points = Vec::new();
 
-    for point_curve in circle.generate_polyline(boundary, tolerance) {
+    for point_curve in
+        circle.generate_polyline_with_options(boundary, tolerance, options)
+    {
         let point_global = circle.point_from_circle_coords(point_curve);
         points.push((point_curve, point_global));
     }
 
     points
 }
+
+/// # Approximate an ellipse
+///
+/// Works just like [`approx_circle`], and provides the same guarantees.
+pub fn approx_ellipse<const D: usize>(
+    ellipse: &Ellipse<D>,
+    boundary: impl Into<CurveBoundary<Point<1>>>,
+    tolerance: impl Into<Tolerance>,
+    options: PolylineOptions,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+    let tolerance = tolerance.into();
+
+    let mut points = Vec::new();
+
+    for point_curve in
+        ellipse.generate_polyline_with_options(boundary, tolerance, options)
+    {
+        let point_global = ellipse.point_from_ellipse_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
@@ -1,14 +1,21 @@
 use std::collections::BTreeMap;
 
-use fj_math::{Circle, Line, Point};
+use fj_math::{Line, Point};
 
 use crate::{
-    geometry::{CurveBoundary, Geometry, Path, SurfaceGeom, Tolerance},
+    geometry::{
+        traits::PolylineOptions, CurveBoundary, Geometry, Path, SurfaceGeom,
+        Tolerance,
+    },
     storage::Handle,
     topology::{Curve, Surface},
 };
 
-use super::{circle::approx_circle, line::approx_line, ApproxPoint};
+use super::{
+    circle::{approx_circle, approx_ellipse},
+    line::approx_line,
+    ApproxPoint,
+};
 
 /// Approximate the provided curve
 ///
@@ -35,6 +42,7 @@ pub fn approx_curve_with_cache(
                 geometry.of_surface(surface),
                 boundary,
                 tolerance,
+                cache.max_segments,
             );
 
             cache.insert(curve.clone(), boundary, approx)
@@ -47,18 +55,38 @@ fn approx_curve(
     surface: &SurfaceGeom,
     boundary: CurveBoundary<Point<1>>,
     tolerance: impl Into<Tolerance>,
+    max_segments: usize,
 ) -> CurveApprox {
+    let options = PolylineOptions {
+        max_segments,
+        ..PolylineOptions::default()
+    };
+
     let SurfaceGeom { u, .. } = surface;
     let points = match (path, u) {
-        (Path::Circle(_), Path::Circle(_)) => approx_circle_on_curved_surface(),
+        (
+            Path::Circle(_) | Path::Ellipse(_),
+            Path::Circle(_) | Path::Ellipse(_),
+        ) => approx_circle_on_curved_surface(),
         (Path::Circle(circle), Path::Line(_)) => {
+            let tolerance = tolerance.into();
             approx_circle_on_straight_surface(
-                circle, boundary, surface, tolerance,
+                approx_circle(circle, boundary, tolerance, options),
+                surface,
+                tolerance,
             )
         }
-        (Path::Line(line), _) => {
-            approx_line_on_any_surface(line, boundary, surface, tolerance)
+        (Path::Ellipse(ellipse), Path::Line(_)) => {
+            let tolerance = tolerance.into();
+            approx_circle_on_straight_surface(
+                approx_ellipse(ellipse, boundary, tolerance, options),
+                surface,
+                tolerance,
+            )
         }
+        (Path::Line(line), _) => approx_line_on_any_surface(
+            line, boundary, surface, tolerance, options,
+        ),
     };
 
     CurveApprox { points }
@@ -69,14 +97,13 @@ fn approx_circle_on_curved_surface() -> Vec<ApproxPoint<1>> {
 }
 
 fn approx_circle_on_straight_surface(
-    circle: &Circle<2>,
-    boundary: CurveBoundary<Point<1>>,
+    approx: Vec<(Point<1>, Point<2>)>,
     surface: &SurfaceGeom,
-    tolerance: impl Into<Tolerance>,
+    tolerance: Tolerance,
 ) -> Vec<ApproxPoint<1>> {
-    let tolerance = tolerance.into();
-
-    approx_circle(circle, boundary, tolerance)
+    // The approximation of an ellipse is handled here too. It works the same
+    // way.
+    approx
         .into_iter()
         .map(|(point_curve, point_surface)| {
             // We're throwing away `point_surface` here, which is a bit weird,
@@ -105,6 +132,7 @@ fn approx_line_on_any_surface(
     boundary: CurveBoundary<Point<1>>,
     surface: &SurfaceGeom,
     tolerance: impl Into<Tolerance>,
+    options: PolylineOptions,
 ) -> Vec<ApproxPoint<1>> {
     let tolerance = tolerance.into();
 
@@ -116,7 +144,12 @@ fn approx_line_on_any_surface(
 
     let SurfaceGeom { u, .. } = surface;
     let approx_u = match u {
-        Path::Circle(circle) => approx_circle(circle, range_u, tolerance),
+        Path::Circle(circle) => {
+            approx_circle(circle, range_u, tolerance, options)
+        }
+        Path::Ellipse(ellipse) => {
+            approx_ellipse(ellipse, range_u, tolerance, options)
+        }
         Path::Line(line) => approx_line(line),
     };
 
@@ -151,12 +184,37 @@ impl CurveApprox {
 }
 
 /// Cache for curve approximations
-#[derive(Default)]
+///
+/// Besides the cached approximations, this carries the configuration that
+/// applies to all approximations computed through it.
 pub struct CurveApproxCache {
     inner: BTreeMap<(Handle<Curve>, CurveBoundary<Point<1>>), CurveApprox>,
+    max_segments: usize,
 }
 
 impl CurveApproxCache {
+    /// Create a cache that limits the number of segments per curve
+    ///
+    /// Curve approximations that would require more segments to meet the
+    /// tolerance are capped at `max_segments`, with a warning. See
+    /// [`PolylineOptions::max_segments`].
+    pub fn with_max_segments(max_segments: usize) -> Self {
+        Self {
+            inner: BTreeMap::new(),
+            max_segments,
+        }
+    }
+
+    /// The number of curve approximations in the cache
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     fn get(
         &self,
         handle: &Handle<Curve>,
@@ -186,6 +244,12 @@ impl CurveApproxCache {
     }
 }
 
+impl Default for CurveApproxCache {
+    fn default() -> Self {
+        Self::with_max_segments(PolylineOptions::default().max_segments)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::TAU;
@@ -197,7 +261,10 @@ mod tests {
         algorithms::approx::{
             circle::approx_circle, curve::approx_curve, ApproxPoint,
         },
-        geometry::{CurveBoundary, Path, SurfaceGeom},
+        geometry::{
+            traits::PolylineOptions, CurveBoundary, Path, SurfaceGeom,
+            Tolerance,
+        },
         operations::build::BuildSurface,
         topology::Surface,
         Core,
@@ -212,7 +279,9 @@ mod tests {
         let boundary = CurveBoundary::from(boundary);
 
         let tolerance = 1.;
-        let approx = approx_curve(&path, surface, boundary, tolerance);
+        let max_segments = PolylineOptions::default().max_segments;
+        let approx =
+            approx_curve(&path, surface, boundary, tolerance, max_segments);
 
         assert_eq!(approx.points, vec![]);
     }
@@ -227,7 +296,9 @@ mod tests {
         let boundary = CurveBoundary::from(boundary);
 
         let tolerance = 1.;
-        let approx = approx_curve(&path, &surface, boundary, tolerance);
+        let max_segments = PolylineOptions::default().max_segments;
+        let approx =
+            approx_curve(&path, &surface, boundary, tolerance, max_segments);
 
         assert_eq!(approx.points, vec![]);
     }
@@ -250,20 +321,32 @@ mod tests {
         let boundary = CurveBoundary::from([[0.], [TAU]]);
 
         let tolerance = 1.;
-        let approx = approx_curve(&path, &surface_geom, boundary, tolerance);
-
-        let expected_approx = approx_circle(&circle, boundary, tolerance)
-            .into_iter()
-            .map(|(point_local, _)| {
-                let point_surface = path.point_from_path_coords(point_local);
-                let point_global = core
-                    .layers
-                    .geometry
-                    .of_surface(&surface)
-                    .point_from_surface_coords(point_surface, tolerance);
-                ApproxPoint::new(point_local, point_global)
-            })
-            .collect::<Vec<_>>();
+        let max_segments = PolylineOptions::default().max_segments;
+        let approx = approx_curve(
+            &path,
+            &surface_geom,
+            boundary,
+            tolerance,
+            max_segments,
+        );
+
+        let expected_approx = approx_circle(
+            &circle,
+            boundary,
+            tolerance,
+            PolylineOptions::default(),
+        )
+        .into_iter()
+        .map(|(point_local, _)| {
+            let point_surface = path.point_from_path_coords(point_local);
+            let point_global = core
+                .layers
+                .geometry
+                .of_surface(&surface)
+                .point_from_surface_coords(point_surface, tolerance);
+            ApproxPoint::new(point_local, point_global)
+        })
+        .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
     }
 
@@ -278,20 +361,50 @@ mod tests {
         let boundary = CurveBoundary::from([[0.], [TAU]]);
 
         let tolerance = 1.;
-        let approx = approx_curve(&path, &surface_geom, boundary, tolerance);
-
-        let expected_approx = approx_circle(&circle, boundary, tolerance)
-            .into_iter()
-            .map(|(point_local, _)| {
-                let point_surface = path.point_from_path_coords(point_local);
-                let point_global = core
-                    .layers
-                    .geometry
-                    .of_surface(&surface)
-                    .point_from_surface_coords(point_surface, tolerance);
-                ApproxPoint::new(point_local, point_global)
-            })
-            .collect::<Vec<_>>();
+        let max_segments = PolylineOptions::default().max_segments;
+        let approx = approx_curve(
+            &path,
+            &surface_geom,
+            boundary,
+            tolerance,
+            max_segments,
+        );
+
+        let expected_approx = approx_circle(
+            &circle,
+            boundary,
+            tolerance,
+            PolylineOptions::default(),
+        )
+        .into_iter()
+        .map(|(point_local, _)| {
+            let point_surface = path.point_from_path_coords(point_local);
+            let point_global = core
+                .layers
+                .geometry
+                .of_surface(&surface)
+                .point_from_surface_coords(point_surface, tolerance);
+            ApproxPoint::new(point_local, point_global)
+        })
+        .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
     }
+
+    #[test]
+    fn approx_circle_with_max_segments() -> anyhow::Result<()> {
+        let core = Core::new();
+
+        let surface = core.layers.geometry.xz_plane();
+        let path = Path::circle_from_radius(1000.);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+
+        // This tolerance would require millions of segments.
+        let tolerance = Tolerance::from_scalar(1e-9)?;
+        let approx = approx_curve(&path, surface, boundary, tolerance, 16);
+
+        // The boundary points are not part of the approximation.
+        assert_eq!(approx.points.len(), 15);
+
+        Ok(())
+    }
 }
@@ -2,10 +2,10 @@
 //!
 //! See [`CycleApprox`].
 
-use fj_math::LineSegment;
+use fj_math::{LineSegment, Polygon, Scalar};
 
 use crate::{
-    geometry::{CurveBoundary, Geometry, Tolerance},
+    geometry::{Geometry, Tolerance},
     storage::Handle,
     topology::{Cycle, Surface},
 };
@@ -30,22 +30,8 @@ pub fn approx_cycle(
         .half_edges()
         .pairs()
         .map(|(half_edge, next_half_edge)| {
-            let boundary = CurveBoundary {
-                inner: [
-                    geometry
-                        .of_vertex(half_edge.start_vertex())
-                        .unwrap()
-                        .local_on(half_edge.curve())
-                        .unwrap()
-                        .position,
-                    geometry
-                        .of_vertex(next_half_edge.start_vertex())
-                        .unwrap()
-                        .local_on(half_edge.curve())
-                        .unwrap()
-                        .position,
-                ],
-            };
+            let boundary = half_edge
+                .curve_boundary(next_half_edge.start_vertex(), geometry);
             let [start_position_curve, _] = boundary.inner;
 
             let start = approx_vertex(
@@ -74,7 +60,7 @@ pub fn approx_cycle(
 }
 
 /// An approximation of a [`Cycle`]
-#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct CycleApprox {
     /// The approximated half-edges that make up the approximated cycle
     pub half_edges: Vec<HalfEdgeApprox>,
@@ -96,6 +82,21 @@ impl CycleApprox {
         points
     }
 
+    /// Construct the polygon that approximates the cycle, in surface coordinates
+    pub fn polygon(&self) -> Polygon<2> {
+        Polygon::from_points(
+            self.points().into_iter().map(|point| point.local_form),
+        )
+    }
+
+    /// Compute the signed area of the approximated cycle
+    ///
+    /// The area is computed in surface coordinates. See
+    /// [`fj_math::signed_area`].
+    pub fn signed_area(&self) -> Scalar {
+        self.polygon().signed_area()
+    }
+
     /// Construct the segments that approximate the cycle
     pub fn segments(&self) -> Vec<LineSegment<3>> {
         let mut segments = Vec::new();
@@ -2,10 +2,15 @@
 //!
 //! See [`FaceApprox`].
 
-use std::{collections::BTreeSet, ops::Deref};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+};
 
 use crate::{
+    algorithms::cancel::Cancelled,
     geometry::{Geometry, Tolerance},
+    queries::{canonical_face, hash_canonical_face, CanonicalFace},
     storage::Handle,
     topology::{Face, Handedness, ObjectSet},
     validation::ValidationConfig,
@@ -26,44 +31,94 @@ impl Approx for &ObjectSet<Face> {
         cache: &mut Self::Cache,
         geometry: &Geometry,
     ) -> Self::Approximation {
-        let tolerance = tolerance.into();
+        match approx_faces(self, tolerance, cache, || Ok(()), geometry) {
+            Ok(approx) => approx,
+            Err(Cancelled) => {
+                unreachable!("Approximation can't be cancelled from here")
+            }
+        }
+    }
+}
 
-        let approx = self
-            .into_iter()
-            .map(|face| approx_face(face.clone(), tolerance, cache, geometry))
-            .collect();
-
-        let min_distance = ValidationConfig::default().distinct_min_distance;
-        let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
-
-        // Run some validation code on the approximation.
-        for approx in &approx {
-            let approx: &FaceApprox = approx;
-
-            for a in &approx.points() {
-                for b in &all_points {
-                    let distance = (b.global_form - a.global_form).magnitude();
-
-                    if b.global_form != a.global_form && distance < min_distance
-                    {
-                        panic!(
-                            "Invalid approximation: \
-                            Distinct points are too close \
-                            (a: {:?}, b: {:?}, distance: {distance})",
-                            a.global_form, b.global_form,
-                        );
-                    }
-                }
+/// Approximate a subset of faces, for example those that changed after an edit
+///
+/// The approximation of each face is identical to the one that is part of the
+/// approximation of the full shell or solid. Approximations of curves only
+/// depend on the curve and the tolerance, so edges that are shared with faces
+/// outside of the subset are approximated in the same way.
+impl Approx for &[Handle<Face>] {
+    type Approximation = BTreeSet<FaceApprox>;
+    type Cache = ApproxCache;
 
-                all_points.insert(*a);
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        geometry: &Geometry,
+    ) -> Self::Approximation {
+        match approx_faces(self, tolerance, cache, || Ok(()), geometry) {
+            Ok(approx) => approx,
+            Err(Cancelled) => {
+                unreachable!("Approximation can't be cancelled from here")
             }
         }
+    }
+}
 
-        approx
+/// Approximate the provided faces, calling `on_face` after each one
+///
+/// Stops early, if `on_face` returns an error.
+pub(super) fn approx_faces<'r>(
+    faces: impl IntoIterator<Item = &'r Handle<Face>>,
+    tolerance: impl Into<Tolerance>,
+    cache: &mut ApproxCache,
+    mut on_face: impl FnMut() -> Result<(), Cancelled>,
+    geometry: &Geometry,
+) -> Result<BTreeSet<FaceApprox>, Cancelled> {
+    let tolerance = tolerance.into();
+
+    let approx = faces
+        .into_iter()
+        .map(|face| {
+            let approx = approx_face(face.clone(), tolerance, cache, geometry);
+            on_face()?;
+            Ok(approx)
+        })
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let min_distance = ValidationConfig::default().distinct_min_distance;
+    let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
+
+    // Run some validation code on the approximation.
+    for approx in &approx {
+        let approx: &FaceApprox = approx;
+
+        for a in &approx.points() {
+            for b in &all_points {
+                let distance = (b.global_form - a.global_form).magnitude();
+
+                if b.global_form != a.global_form && distance < min_distance {
+                    panic!(
+                        "Invalid approximation: \
+                        Distinct points are too close \
+                        (a: {:?}, b: {:?}, distance: {distance})",
+                        a.global_form, b.global_form,
+                    );
+                }
+            }
+
+            all_points.insert(*a);
+        }
     }
+
+    Ok(approx)
 }
 
 /// Approximate the provided face
+///
+/// Returns the cached approximation, if the same face, or a face with the same
+/// content, has been approximated with the same tolerance before. See
+/// [`FaceApproxCache`].
 pub fn approx_face(
     face: Handle<Face>,
     tolerance: impl Into<Tolerance>,
@@ -72,6 +127,16 @@ pub fn approx_face(
 ) -> FaceApprox {
     let tolerance = tolerance.into();
 
+    if let Some(approx) = cache.face.get(&face, tolerance) {
+        return approx.with_face(face);
+    }
+
+    let content = canonical_face(&face, geometry);
+    if let Some(approx) = cache.face.get_by_content(&content, tolerance) {
+        cache.face.insert(face.clone(), content, approx.clone());
+        return approx.with_face(face);
+    }
+
     let exterior = approx_cycle(
         face.region().exterior().deref(),
         face.surface(),
@@ -93,11 +158,137 @@ pub fn approx_face(
     }
 
     let coord_handedness = face.coord_handedness(geometry);
-    FaceApprox {
-        face,
+    let approx = CachedFaceApprox {
         exterior,
         interiors,
         coord_handedness,
+        tolerance,
+    };
+    cache.face.insert(face.clone(), content, approx.clone());
+
+    approx.with_face(face)
+}
+
+/// Cache for face approximations
+///
+/// Approximations are cached per face and tolerance. Looking up a face that
+/// has been approximated before is cheap, as it only requires comparing
+/// handles.
+///
+/// A face that hasn't been approximated before might still have the same
+/// content as one that has. This is the case for faces that consist of
+/// different objects, as a result of unrelated edits elsewhere in the model.
+/// Those are found using their [`ContentHash`]. Since different content could
+/// result in the same hash, the content is compared too, before an
+/// approximation is reused.
+///
+/// Like the caches for curves and vertices, this cache assumes that the
+/// geometry of an object doesn't change, once it has been approximated. Use a
+/// fresh cache, if that isn't the case.
+///
+/// [`ContentHash`]: crate::queries::ContentHash
+#[derive(Default)]
+pub struct FaceApproxCache {
+    by_face: BTreeMap<(Handle<Face>, Tolerance), CachedFaceApprox>,
+    by_content:
+        BTreeMap<(u64, Tolerance), Vec<(CanonicalFace, CachedFaceApprox)>>,
+    hits: usize,
+}
+
+impl FaceApproxCache {
+    /// The number of faces whose approximation is in the cache
+    ///
+    /// Faces with the same content share an approximation, but count
+    /// separately.
+    pub fn len(&self) -> usize {
+        self.by_face.len()
+    }
+
+    /// Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.by_face.is_empty()
+    }
+
+    /// The number of approximations that were served from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    fn get(
+        &mut self,
+        face: &Handle<Face>,
+        tolerance: Tolerance,
+    ) -> Option<CachedFaceApprox> {
+        let approx = self.by_face.get(&(face.clone(), tolerance)).cloned();
+        if approx.is_some() {
+            self.hits += 1;
+        }
+        approx
+    }
+
+    fn get_by_content(
+        &mut self,
+        content: &CanonicalFace,
+        tolerance: Tolerance,
+    ) -> Option<CachedFaceApprox> {
+        let key = (hash_canonical_face(content), tolerance);
+        let approx = self
+            .by_content
+            .get(&key)?
+            .iter()
+            .find(|(cached, _)| cached == content)
+            .map(|(_, approx)| approx.clone());
+        if approx.is_some() {
+            self.hits += 1;
+        }
+        approx
+    }
+
+    fn insert(
+        &mut self,
+        face: Handle<Face>,
+        content: CanonicalFace,
+        approx: CachedFaceApprox,
+    ) {
+        let tolerance = approx.tolerance;
+
+        let same_hash = self
+            .by_content
+            .entry((hash_canonical_face(&content), tolerance))
+            .or_default();
+        if !same_hash.iter().any(|(cached, _)| *cached == content) {
+            same_hash.push((content, approx.clone()));
+        }
+
+        self.by_face.insert((face, tolerance), approx);
+    }
+}
+
+/// A [`FaceApprox`], without the face it approximates
+#[derive(Clone)]
+struct CachedFaceApprox {
+    exterior: CycleApprox,
+    interiors: BTreeSet<CycleApprox>,
+    coord_handedness: Handedness,
+    tolerance: Tolerance,
+}
+
+impl CachedFaceApprox {
+    fn with_face(self, face: Handle<Face>) -> FaceApprox {
+        let Self {
+            exterior,
+            interiors,
+            coord_handedness,
+            tolerance,
+        } = self;
+
+        FaceApprox {
+            face,
+            exterior,
+            interiors,
+            coord_handedness,
+            tolerance,
+        }
     }
 }
 
@@ -115,6 +306,12 @@ pub struct FaceApprox {
 
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
+
+    /// The absolute tolerance that the face was approximated with
+    ///
+    /// Useful for debugging, if the tolerance was derived from some other value
+    /// (like the size of the model), instead of being specified directly.
+    pub tolerance: Tolerance,
 }
 
 impl FaceApprox {
@@ -131,3 +328,102 @@ impl FaceApprox {
         points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        algorithms::approx::{Approx, ApproxCache},
+        fixtures,
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+        Core,
+    };
+
+    use super::approx_face;
+
+    #[test]
+    fn approximate_subset_of_faces() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+
+        let tolerance = 0.01;
+        let geometry = &core.layers.geometry;
+
+        let full = (&cylinder).approx(tolerance, geometry);
+
+        let subset = cylinder
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .take(2)
+            .cloned()
+            .collect::<Vec<_>>();
+        let partial = subset.as_slice().approx(tolerance, geometry);
+
+        let expected = full
+            .into_iter()
+            .filter(|approx| subset.contains(&approx.face))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial, expected);
+    }
+
+    #[test]
+    fn reuse_approximation_of_unchanged_face() {
+        let mut core = Core::new();
+
+        let triangle = |points, core: &mut Core| {
+            Face::triangle(points, core).face.insert(core)
+        };
+        let a = triangle([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]], &mut core);
+        let b = triangle([[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]], &mut core);
+
+        let tolerance = 0.1;
+        let mut cache = ApproxCache::default();
+        let geometry = &core.layers.geometry;
+
+        let first = approx_face(a.clone(), tolerance, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 0);
+
+        let second = approx_face(a.clone(), tolerance, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 1);
+        assert_eq!(first, second);
+
+        approx_face(b, tolerance, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 1);
+        assert_eq!(cache.face.len(), 2);
+
+        approx_face(a, 0.01, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 1);
+        assert_eq!(cache.face.len(), 3);
+    }
+
+    #[test]
+    fn reuse_approximation_of_face_with_same_content() {
+        let mut core = Core::new();
+
+        let points = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+        let a = Face::triangle(points, &mut core).face.insert(&mut core);
+        let b = Face::triangle(points, &mut core).face.insert(&mut core);
+        assert_ne!(a, b);
+
+        let tolerance = 0.1;
+        let mut cache = ApproxCache::default();
+        let geometry = &core.layers.geometry;
+
+        let approx_a = approx_face(a, tolerance, &mut cache, geometry);
+        let approx_b = approx_face(b.clone(), tolerance, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 1);
+        assert_eq!(cache.face.len(), 2);
+        assert_eq!(approx_a.exterior, approx_b.exterior);
+
+        // Now the face itself is in the cache.
+        approx_face(b, tolerance, &mut cache, geometry);
+        assert_eq!(cache.face.hits(), 2);
+        assert_eq!(cache.face.len(), 2);
+    }
+}
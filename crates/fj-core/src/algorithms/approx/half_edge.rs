@@ -62,7 +62,7 @@ pub fn approx_half_edge(
 /// approximation of its curve. The second vertex is left out, as half-edge
 /// approximations are usually used to build cycle approximations, and this way,
 /// the caller doesn't have to deal with duplicate vertices.
-#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct HalfEdgeApprox {
     /// The points that approximate the half-edge
     pub points: Vec<ApproxPoint<2>>,
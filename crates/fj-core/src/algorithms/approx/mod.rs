@@ -6,6 +6,7 @@ pub mod half_edge;
 pub mod shell;
 pub mod sketch;
 pub mod solid;
+pub mod wire;
 
 mod circle;
 mod curve;
@@ -18,12 +19,14 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use curve::CurveApproxCache;
+pub use curve::CurveApproxCache;
 use fj_math::Point;
-use vertex::VertexApproxCache;
+pub(crate) use vertex::VertexApproxCache;
 
 use crate::geometry::{Geometry, Tolerance};
 
+use self::face::FaceApproxCache;
+
 /// Approximate an object
 pub trait Approx: Sized {
     /// The approximation of the object
@@ -58,13 +61,51 @@ pub trait Approx: Sized {
 }
 
 /// Cache for half-edge approximations
+///
+/// Curve approximations are cached per curve and boundary, regardless of the
+/// surface they are approximated on. Faces that share a curve, like the caps and
+/// side faces of a swept sketch, therefore use the same points along that curve,
+/// if they are approximated using the same cache. This keeps the resulting mesh
+/// free of cracks and T-junctions.
 #[derive(Default)]
 pub struct ApproxCache {
     /// Cache for vertex approximations
     pub vertex: VertexApproxCache,
 
     /// Cache for curve approximations
+    ///
+    /// Use [`CurveApproxCache::with_max_segments`] to limit the number of
+    /// segments per curve.
     pub curve: CurveApproxCache,
+
+    /// Cache for face approximations
+    pub face: FaceApproxCache,
+}
+
+impl ApproxCache {
+    /// Count the approximations in each part of the cache
+    pub fn counts(&self) -> ApproxCacheCounts {
+        ApproxCacheCounts {
+            vertices: self.vertex.len(),
+            curves: self.curve.len(),
+            faces: self.face.len(),
+        }
+    }
+}
+
+/// The number of approximations in an [`ApproxCache`]
+///
+/// Returned by [`ApproxCache::counts`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ApproxCacheCounts {
+    /// The number of vertex approximations
+    pub vertices: usize,
+
+    /// The number of curve approximations, each within a specific boundary
+    pub curves: usize,
+
+    /// The number of face approximations
+    pub faces: usize,
 }
 
 /// A point from an approximation, with local and global forms
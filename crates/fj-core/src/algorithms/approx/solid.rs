@@ -2,9 +2,17 @@
 
 use std::collections::BTreeSet;
 
-use crate::{geometry::Geometry, topology::Solid};
+use crate::{
+    algorithms::cancel::{CancelToken, Cancelled},
+    geometry::Geometry,
+    topology::Solid,
+    Core,
+};
 
-use super::{face::FaceApprox, Approx, ApproxCache, Tolerance};
+use super::{
+    face::{approx_face, approx_faces, FaceApprox},
+    Approx, ApproxCache, Tolerance,
+};
 
 impl Approx for &Solid {
     type Approximation = BTreeSet<FaceApprox>;
@@ -26,3 +34,258 @@ impl Approx for &Solid {
             .collect()
     }
 }
+
+impl Solid {
+    /// Approximate the solid, reporting progress after each face
+    ///
+    /// Produces the same result as [`Approx::approx_with_cache`]. If `progress`
+    /// is provided, it is called after each face has been approximated, with
+    /// the number of faces approximated so far, and the total number of faces.
+    /// This can be used to drive a progress bar.
+    ///
+    /// A face that is referenced by multiple shells is approximated, and
+    /// counted, once for each reference.
+    pub fn approx_with_progress(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut ApproxCache,
+        progress: Option<&mut dyn FnMut(usize, usize)>,
+        geometry: &Geometry,
+    ) -> BTreeSet<FaceApprox> {
+        match approx_solid(self, tolerance, cache, None, progress, geometry) {
+            Ok(approx) => approx,
+            Err(Cancelled) => {
+                unreachable!("Approximation can't be cancelled without token")
+            }
+        }
+    }
+
+    /// Approximate the solid, unless cancelled through the provided token
+    ///
+    /// Works like [`Solid::approx_with_progress`], but checks `cancel` before
+    /// approximating each face, and returns [`Cancelled`], if cancellation has
+    /// been requested. `progress` is called before each check, so it can be
+    /// used to cancel the approximation too.
+    pub fn approx_cancellable(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut ApproxCache,
+        cancel: &CancelToken,
+        progress: Option<&mut dyn FnMut(usize, usize)>,
+        geometry: &Geometry,
+    ) -> Result<BTreeSet<FaceApprox>, Cancelled> {
+        approx_solid(self, tolerance, cache, Some(cancel), progress, geometry)
+    }
+
+    /// Approximate the solid, passing each face approximation to `sink`
+    ///
+    /// This is an alternative to [`Approx::approx`] for very large solids. It
+    /// approximates one face at a time, instead of collecting all of them into
+    /// a `BTreeSet`. That allows callers (like exporters) to process each face
+    /// and drop it, before the next one is approximated.
+    ///
+    /// Since nothing is collected, nothing is deduplicated either: A face that
+    /// is referenced by multiple shells is passed to `sink` once for each
+    /// reference. It is up to the caller to handle that, if required.
+    ///
+    /// `sink` is passed the core too, so it can triangulate the approximation.
+    pub fn approx_streaming(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+        mut sink: impl FnMut(FaceApprox, &mut Core),
+    ) {
+        let tolerance = tolerance.into();
+        let mut cache = ApproxCache::default();
+
+        for shell in self.shells() {
+            for face in shell.faces() {
+                let approx = approx_face(
+                    face.clone(),
+                    tolerance,
+                    &mut cache,
+                    &core.layers.geometry,
+                );
+                sink(approx, core);
+            }
+        }
+    }
+}
+
+fn approx_solid(
+    solid: &Solid,
+    tolerance: impl Into<Tolerance>,
+    cache: &mut ApproxCache,
+    cancel: Option<&CancelToken>,
+    mut progress: Option<&mut dyn FnMut(usize, usize)>,
+    geometry: &Geometry,
+) -> Result<BTreeSet<FaceApprox>, Cancelled> {
+    let tolerance = tolerance.into();
+
+    let check = || match cancel {
+        Some(cancel) => cancel.check(),
+        None => Ok(()),
+    };
+    check()?;
+
+    let total = solid.shells().iter().map(|shell| shell.faces().len()).sum();
+    let mut done = 0;
+
+    let mut approx = BTreeSet::new();
+    for shell in solid.shells() {
+        let on_face = || {
+            if let Some(progress) = progress.as_mut() {
+                done += 1;
+                progress(done, total);
+            }
+
+            check()
+        };
+
+        approx.extend(approx_faces(
+            shell.faces(),
+            tolerance,
+            cache,
+            on_face,
+            geometry,
+        )?);
+    }
+
+    Ok(approx)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, ApproxCache},
+            bounding_volume::BoundingVolume,
+            cancel::{CancelToken, Cancelled},
+        },
+        geometry::Tolerance,
+        operations::build::BuildSolid,
+        topology::Solid,
+        Core,
+    };
+
+    #[test]
+    fn streamed_faces_match_batch_approximation() {
+        let mut core = Core::new();
+
+        let tetrahedron = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let solid = tetrahedron.solid;
+        let tolerance = core.tolerance();
+
+        let batch = (&solid).approx(tolerance, &core.layers.geometry);
+
+        let mut streamed = BTreeSet::new();
+        solid.approx_streaming(tolerance, &mut core, |approx, _| {
+            streamed.insert(approx);
+        });
+
+        assert_eq!(streamed.len(), 4);
+        assert_eq!(streamed, batch);
+    }
+
+    #[test]
+    fn faces_carry_resolved_tolerance() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let tetrahedron = Solid::tetrahedron(
+            [[0., 0., 0.], [2., 0., 0.], [0., 2., 0.], [0., 0., 2.]],
+            &mut core,
+        );
+        let solid = tetrahedron.solid;
+
+        // Resolve a tolerance relative to the size of the solid, like `fj` does
+        // for models without a user-defined tolerance.
+        let aabb = (&solid)
+            .aabb(&core.layers.geometry)
+            .expect("Solid has faces");
+        let min_extent = aabb
+            .size()
+            .components
+            .into_iter()
+            .min()
+            .expect("Vector has components");
+        let tolerance = Tolerance::from_scalar(min_extent / 1000.)?;
+        assert!(tolerance.inner() > core.tolerance().inner());
+
+        let approx = (&solid).approx(tolerance, &core.layers.geometry);
+
+        assert_eq!(approx.len(), 4);
+        for face in approx {
+            assert_eq!(face.tolerance, tolerance);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress_is_reported_per_face() {
+        let mut core = Core::new();
+
+        let tetrahedron = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let solid = tetrahedron.solid;
+        let tolerance = core.tolerance();
+
+        let batch = (&solid).approx(tolerance, &core.layers.geometry);
+
+        let mut calls = Vec::new();
+        let approx = solid.approx_with_progress(
+            tolerance,
+            &mut ApproxCache::default(),
+            Some(&mut |done, total| calls.push((done, total))),
+            &core.layers.geometry,
+        );
+
+        assert_eq!(calls, [(1, 4), (2, 4), (3, 4), (4, 4)]);
+        assert_eq!(approx, batch);
+
+        let without_progress = solid.approx_with_progress(
+            tolerance,
+            &mut ApproxCache::default(),
+            None,
+            &core.layers.geometry,
+        );
+        assert_eq!(without_progress, batch);
+    }
+
+    #[test]
+    fn cancel_approximation() {
+        let mut core = Core::new();
+
+        let tetrahedron = Solid::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let solid = tetrahedron.solid;
+
+        let cancel = CancelToken::new();
+
+        let mut num_faces = 0;
+        let result = solid.approx_cancellable(
+            core.tolerance(),
+            &mut ApproxCache::default(),
+            &cancel,
+            Some(&mut |done, _| {
+                num_faces = done;
+                if done == 2 {
+                    cancel.cancel();
+                }
+            }),
+            &core.layers.geometry,
+        );
+
+        assert_eq!(result, Err(Cancelled));
+        assert_eq!(num_faces, 2);
+    }
+}
@@ -48,6 +48,16 @@ pub struct VertexApproxCache {
 }
 
 impl VertexApproxCache {
+    /// The number of vertex approximations in the cache
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Get an approximated vertex from the cache
     pub fn get(&self, handle: &Handle<Vertex>) -> Option<Point<3>> {
         self.inner.get(handle).cloned()
@@ -0,0 +1,149 @@
+//! Wire approximation
+//!
+//! See [`WireApprox`].
+
+use crate::{
+    geometry::{Geometry, Tolerance},
+    storage::Handle,
+    topology::{Surface, Wire},
+};
+
+use super::{
+    half_edge::{approx_half_edge, HalfEdgeApprox},
+    vertex::approx_vertex,
+    ApproxCache, ApproxPoint,
+};
+
+/// Approximate the provided wire
+pub fn approx_wire(
+    wire: &Wire,
+    surface: &Handle<Surface>,
+    tolerance: impl Into<Tolerance>,
+    cache: &mut ApproxCache,
+    geometry: &Geometry,
+) -> WireApprox {
+    let tolerance = tolerance.into();
+
+    let mut half_edges = Vec::new();
+    let mut end = None;
+
+    for (half_edge, end_vertex) in wire.half_edges_with_end_vertices() {
+        let boundary = half_edge.curve_boundary(end_vertex, geometry);
+        let [start_position_curve, end_position_curve] = boundary.inner;
+
+        let start = approx_vertex(
+            half_edge.start_vertex().clone(),
+            half_edge.curve(),
+            surface,
+            start_position_curve,
+            tolerance,
+            &mut cache.vertex,
+            geometry,
+        );
+
+        half_edges.push(approx_half_edge(
+            half_edge,
+            surface,
+            start,
+            boundary,
+            tolerance,
+            &mut cache.curve,
+            geometry,
+        ));
+
+        let end_curve = approx_vertex(
+            end_vertex.clone(),
+            half_edge.curve(),
+            surface,
+            end_position_curve,
+            tolerance,
+            &mut cache.vertex,
+            geometry,
+        );
+        let end_surface = geometry
+            .of_curve(half_edge.curve())
+            .and_then(|curve| curve.local_on(surface))
+            .expect("Expected geometry of curve to be defined on surface")
+            .path
+            .point_from_path_coords(end_curve.local_form);
+        end = Some(ApproxPoint::new(end_surface, end_curve.global_form));
+    }
+
+    WireApprox { half_edges, end }
+}
+
+/// An approximation of a [`Wire`]
+#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct WireApprox {
+    /// The approximated half-edges that make up the approximated wire
+    pub half_edges: Vec<HalfEdgeApprox>,
+
+    /// The point where the wire ends
+    ///
+    /// This is `None`, if the wire has no half-edges.
+    pub end: Option<ApproxPoint<2>>,
+}
+
+impl WireApprox {
+    /// Compute the points that approximate the wire
+    ///
+    /// Unlike the points of a cycle approximation, these don't end with the
+    /// first point. They end where the wire ends.
+    pub fn points(&self) -> Vec<ApproxPoint<2>> {
+        let mut points = Vec::new();
+
+        for approx in &self.half_edges {
+            points.extend(approx.points.iter().copied());
+        }
+        points.extend(self.end);
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::approx::ApproxCache,
+        operations::{build::BuildWire, insert::Insert},
+        topology::Wire,
+        Core,
+    };
+
+    use super::approx_wire;
+
+    #[test]
+    fn open_polyline() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let wire = Wire::polyline(
+            [[0., 0.], [1., 0.], [1., 1.], [2., 1.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+        assert_eq!(wire.half_edges().len(), 3);
+
+        let approx = approx_wire(
+            &wire,
+            &surface,
+            core.tolerance(),
+            &mut ApproxCache::default(),
+            &core.layers.geometry,
+        );
+
+        let points = approx
+            .points()
+            .into_iter()
+            .map(|point| point.global_form)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [2., 1., 0.]]
+                .map(Point::from),
+        );
+    }
+}
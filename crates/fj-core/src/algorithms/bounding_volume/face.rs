@@ -29,6 +29,17 @@ impl super::BoundingVolume<3> for &Face {
 
                         aabb_bottom.merged(&aabb_top)
                     }
+                    Path::Ellipse(ellipse) => {
+                        // Same as for the circle, do it for the whole ellipse.
+
+                        let aabb_bottom = ellipse.aabb();
+                        let aabb_top = Aabb {
+                            min: aabb_bottom.min + *v,
+                            max: aabb_bottom.max + *v,
+                        };
+
+                        aabb_bottom.merged(&aabb_top)
+                    }
                     Path::Line(_) => {
                         // A bounding volume must include the body it bounds,
                         // but does not need to match it precisely. So it's
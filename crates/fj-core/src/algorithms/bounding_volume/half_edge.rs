@@ -32,18 +32,16 @@ impl super::BoundingVolume<2>
                     max: circle.center() + center_to_min_max,
                 })
             }
+            Path::Ellipse(ellipse) => {
+                // Same as for the circle, calculate the AABB of the whole
+                // ellipse.
+                Some(ellipse.aabb())
+            }
             Path::Line(_) => {
                 let points =
-                    [half_edge.start_vertex(), end_vertex].map(|vertex| {
-                        let point_curve = geometry
-                            .of_vertex(vertex)
-                            .unwrap()
-                            .local_on(half_edge.curve())
-                            .unwrap()
-                            .position;
-
-                        path.point_from_path_coords(point_curve)
-                    });
+                    half_edge.curve_boundary(end_vertex, geometry).inner.map(
+                        |point_curve| path.point_from_path_coords(point_curve),
+                    );
 
                 Some(Aabb::<2>::from_points(points))
             }
@@ -0,0 +1,50 @@
+//! # Cancellation of long-running algorithms
+//!
+//! See [`CancelToken`].
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A token that can be used to cancel a long-running algorithm
+///
+/// Clones of a token share the same state. Keep one clone (for example in a UI
+/// thread) and pass the other to the algorithm. Cancelling through any clone
+/// makes algorithms that check the token return [`Cancelled`] at their next
+/// opportunity.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Create a new token, that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Indicate whether cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return [`Cancelled`], if cancellation has been requested
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            return Err(Cancelled);
+        }
+
+        Ok(())
+    }
+}
+
+/// An algorithm was cancelled through a [`CancelToken`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Operation was cancelled")]
+pub struct Cancelled;
@@ -3,11 +3,14 @@
 pub mod ray_segment;
 
 mod line_segment;
+mod ray_face;
 
 use fj_math::{Point, Vector};
 
 pub use self::line_segment::LineSegmentIntersection;
 
+pub(crate) use self::ray_face::intersect_ray_face;
+
 /// Compute the intersection between a tuple of objects
 ///
 /// # Implementation Note
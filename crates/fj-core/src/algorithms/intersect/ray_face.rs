@@ -0,0 +1,152 @@
+//! Intersection between a ray and the area of a face
+
+use fj_math::{Point, Polygon, Scalar, Vector};
+
+use crate::geometry::{Path, SurfaceGeom};
+
+/// Intersect a ray with the area of a face
+///
+/// The face is described by its surface, and by the polygons that approximate
+/// its cycles in surface coordinates. The polygons are treated as one, using
+/// the even-odd rule. Points that are within `tolerance` of their boundary
+/// count as part of the face.
+///
+/// Returns the distance of each intersection from the origin of the ray, in
+/// units of `direction`.
+pub fn intersect_ray_face(
+    surface: &SurfaceGeom,
+    polygons: &[Polygon<2>],
+    origin: Point<3>,
+    direction: Vector<3>,
+    tolerance: Scalar,
+) -> Vec<Scalar> {
+    intersect_ray(surface, origin, direction)
+        .into_iter()
+        .filter(|(point, _)| contains(polygons, *point, surface, tolerance))
+        .map(|(_, distance)| distance)
+        .collect()
+}
+
+/// Determine whether the area bounded by the provided polygons contains a point
+///
+/// For surfaces that are curved along the u-axis, the point is also checked at
+/// the positions that are a full turn away.
+fn contains(
+    polygons: &[Polygon<2>],
+    point: Point<2>,
+    surface: &SurfaceGeom,
+    tolerance: Scalar,
+) -> bool {
+    let turns = match surface.u {
+        Path::Line(_) => 0,
+        Path::Circle(_) | Path::Ellipse(_) => 1,
+    };
+
+    (-turns..=turns).any(|turn| {
+        let point =
+            point + Vector::from([Scalar::TAU * f64::from(turn), Scalar::ZERO]);
+
+        let is_on_boundary = polygons
+            .iter()
+            .any(|polygon| polygon.is_on_boundary(point, tolerance));
+        let winding_number = polygons
+            .iter()
+            .map(|polygon| polygon.winding_number(point))
+            .sum::<i32>();
+
+        is_on_boundary || winding_number % 2 != 0
+    })
+}
+
+/// Intersect a ray with a surface
+///
+/// Returns the surface coordinates of each intersection, and its distance from
+/// the origin of the ray, in units of `direction`. A ray that runs parallel to
+/// the surface's v-axis never intersects it.
+fn intersect_ray(
+    surface: &SurfaceGeom,
+    origin: Point<3>,
+    direction: Vector<3>,
+) -> Vec<(Point<2>, Scalar)> {
+    // The surface is made up of lines along the v-axis, one for each point on
+    // the u-axis. The ray can only intersect the ones within the plane that
+    // contains the ray and is parallel to the v-axis.
+    let normal = direction.cross(&surface.v);
+    if normal.magnitude() == Scalar::ZERO {
+        return Vec::new();
+    }
+
+    let positions_on_u = match surface.u {
+        Path::Line(line) => {
+            let denominator = line.direction().dot(&normal);
+            if denominator == Scalar::ZERO {
+                return Vec::new();
+            }
+
+            vec![(origin - line.origin()).dot(&normal) / denominator]
+        }
+        Path::Circle(circle) => angles_in_plane(
+            circle.center(),
+            circle.a(),
+            circle.b(),
+            origin,
+            normal,
+        ),
+        Path::Ellipse(ellipse) => angles_in_plane(
+            ellipse.center(),
+            ellipse.a(),
+            ellipse.b(),
+            origin,
+            normal,
+        ),
+    };
+
+    positions_on_u
+        .into_iter()
+        .map(|u| {
+            // Find `v` and `distance`, such that
+            // `point_on_u + surface.v * v == origin + direction * distance`.
+            let to_point = surface.u.point_from_path_coords([u]) - origin;
+
+            let dd = direction.dot(&direction);
+            let dv = direction.dot(&surface.v);
+            let vv = surface.v.dot(&surface.v);
+            let pd = to_point.dot(&direction);
+            let pv = to_point.dot(&surface.v);
+
+            let determinant = dv * dv - dd * vv;
+            let distance = (dv * pv - vv * pd) / determinant;
+            let v = (dd * pv - dv * pd) / determinant;
+
+            (Point::from([u, v]), distance)
+        })
+        .collect()
+}
+
+/// Find the angles at which a circle or ellipse crosses a plane
+fn angles_in_plane(
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    point_on_plane: Point<3>,
+    normal: Vector<3>,
+) -> Vec<Scalar> {
+    // Solve `a_n * cos(angle) + b_n * sin(angle) = c_n`.
+    let [a_n, b_n] = [a, b].map(|axis| axis.dot(&normal));
+    let c_n = (point_on_plane - center).dot(&normal);
+
+    let r = (a_n * a_n + b_n * b_n).sqrt();
+    if r == Scalar::ZERO || c_n.abs() > r {
+        return Vec::new();
+    }
+
+    let phi = b_n.atan2(a_n);
+    let delta = (c_n / r).acos();
+
+    let mut angles = vec![phi + delta];
+    if delta != Scalar::ZERO {
+        angles.push(phi - delta);
+    }
+
+    angles
+}
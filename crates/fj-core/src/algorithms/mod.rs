@@ -12,5 +12,6 @@
 
 pub mod approx;
 pub mod bounding_volume;
+pub mod cancel;
 pub mod intersect;
 pub mod triangulate;
@@ -5,42 +5,51 @@ use spade::HasPosition;
 
 use crate::{algorithms::approx::cycle::CycleApprox, topology::Handedness};
 
+use super::Seed;
+
 /// Create a Delaunay triangulation of all points
+///
+/// The points are inserted into the triangulation in an order that is shuffled
+/// using the provided seed. Where the triangulation is ambiguous (for example,
+/// if more than three points lie on a circle), the result depends on that
+/// order. The same seed always yields the same result.
+///
+/// Returns `None`, if the triangulation contains a degenerate triangle. This
+/// can happen for near-degenerate input, due to rounding errors.
 pub fn triangulate(
     cycles: impl IntoIterator<Item = CycleApprox>,
     coord_handedness: Handedness,
-) -> Vec<[TriangulationPoint; 3]> {
+    seed: Seed,
+) -> Option<Vec<[TriangulationPoint; 3]>> {
     use spade::Triangulation as _;
 
-    let mut triangulation = spade::ConstrainedDelaunayTriangulation::<_>::new();
-
-    let mut points = BTreeMap::new();
+    let cycles = cycles
+        .into_iter()
+        .map(|cycle_approx| cycle_approx.points())
+        .collect::<Vec<_>>();
 
-    for cycle_approx in cycles {
-        let mut handle_prev = None;
+    let mut points = cycles.iter().flatten().copied().collect::<Vec<_>>();
+    points.sort();
+    points.dedup();
+    shuffle(&mut points, seed);
 
-        for point in cycle_approx.points() {
-            let handle = match points.get(&point) {
-                Some(handle) => *handle,
-                None => {
-                    let handle = triangulation
-                        .insert(TriangulationPoint {
-                            point_surface: point.local_form,
-                            point_global: point.global_form,
-                        })
-                        .expect("Inserted invalid point into triangulation");
-
-                    points.insert(point, handle);
+    let mut triangulation = spade::ConstrainedDelaunayTriangulation::<_>::new();
 
-                    handle
-                }
-            };
+    let mut handles = BTreeMap::new();
+    for point in points {
+        let handle = triangulation
+            .insert(TriangulationPoint {
+                point_surface: point.local_form,
+                point_global: point.global_form,
+            })
+            .expect("Inserted invalid point into triangulation");
 
-            if let Some(handle_prev) = handle_prev {
-                triangulation.add_constraint(handle_prev, handle);
-            }
+        handles.insert(point, handle);
+    }
 
-            handle_prev = Some(handle);
+    for cycle in cycles {
+        for (a, b) in cycle.iter().zip(cycle.iter().skip(1)) {
+            triangulation.add_constraint(handles[a], handles[b]);
         }
     }
 
@@ -52,10 +61,9 @@ pub fn triangulate(
             v1.point_surface,
             v2.point_surface,
         ]);
-        assert!(
-            triangle.is_valid(),
-            "Expecting triangles created by triangulation to be valid.",
-        );
+        if !triangle.is_valid() {
+            return None;
+        }
 
         let required_winding = match coord_handedness {
             Handedness::LeftHanded => Winding::Cw,
@@ -71,7 +79,7 @@ pub fn triangulate(
         triangles.push(triangle);
     }
 
-    triangles
+    Some(triangles)
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -91,3 +99,23 @@ impl HasPosition for TriangulationPoint {
         }
     }
 }
+
+/// Shuffle the provided items, using a Fisher-Yates shuffle
+///
+/// Random numbers are generated using SplitMix64, which is simple and fully
+/// determined by the seed.
+fn shuffle<T>(items: &mut [T], Seed(mut state): Seed) {
+    let mut next = || {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    };
+
+    for i in (1..items.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
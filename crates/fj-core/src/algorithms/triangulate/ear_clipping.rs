@@ -0,0 +1,226 @@
+use fj_math::{Point, Scalar};
+
+use crate::topology::Handedness;
+
+use super::delaunay::TriangulationPoint;
+
+/// Triangulate a polygon with holes, by clipping its ears
+///
+/// This is slower than Delaunay triangulation and produces worse triangles,
+/// but it is simple enough to deal with near-degenerate polygons, that trip up
+/// the Delaunay triangulation. It serves as a fallback for those cases.
+///
+/// Expects the cycles to be open, meaning their first point is not repeated at
+/// the end. Holes are connected to the exterior via bridges, turning the
+/// polygon into a single cycle, before ears are clipped from that.
+///
+/// Triangles that are degenerate, because their points are collinear, are not
+/// returned. They don't cover any area.
+pub fn triangulate(
+    exterior: Vec<TriangulationPoint>,
+    interiors: Vec<Vec<TriangulationPoint>>,
+    coord_handedness: Handedness,
+) -> Vec<[TriangulationPoint; 3]> {
+    let mut polygon = orient(exterior, true);
+
+    let mut holes = interiors
+        .into_iter()
+        .filter(|hole| !hole.is_empty())
+        .map(|hole| orient(hole, false))
+        .collect::<Vec<_>>();
+
+    // Bridging the rightmost hole first makes it less likely that a bridge
+    // blocks the way for another.
+    holes.sort_by_key(|hole| max_u(hole));
+    while let Some(hole) = holes.pop() {
+        polygon = bridge(polygon, hole, &holes);
+    }
+
+    let mut triangles = Vec::new();
+
+    while polygon.len() > 3 {
+        let n = polygon.len();
+        let corners = |i: usize| {
+            [polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]]
+        };
+
+        let ear = (0..n).find(|&i| is_ear(corners(i), &polygon));
+        let collinear =
+            || (0..n).find(|&i| orient2d(corners(i).map(surface)) == 0.);
+        let convex =
+            || (0..n).find(|&i| orient2d(corners(i).map(surface)) > 0.);
+
+        // If there's no ear, the polygon is either degenerate or so close to
+        // it, that rounding errors got in the way. Removing a vertex anyway
+        // makes sure we finish.
+        let i = ear.or_else(collinear).or_else(convex).unwrap_or(0);
+
+        triangles.push(corners(i));
+        polygon.remove(i);
+    }
+    if let [a, b, c] = polygon.as_slice() {
+        triangles.push([*a, *b, *c]);
+    }
+
+    triangles
+        .into_iter()
+        .filter(|&triangle| orient2d(triangle.map(surface)) != 0.)
+        .map(|[a, b, c]| match coord_handedness {
+            Handedness::RightHanded => [a, b, c],
+            Handedness::LeftHanded => [a, c, b],
+        })
+        .collect()
+}
+
+/// Compute the signed area of a closed polygon in surface coordinates
+///
+/// See [`fj_math::signed_area`].
+pub fn signed_area(points: &[TriangulationPoint]) -> Scalar {
+    let points = points
+        .iter()
+        .map(|point| point.point_surface)
+        .collect::<Vec<_>>();
+
+    fj_math::signed_area(&points)
+}
+
+fn orient(
+    mut points: Vec<TriangulationPoint>,
+    ccw: bool,
+) -> Vec<TriangulationPoint> {
+    if (signed_area(&points) > Scalar::ZERO) != ccw {
+        points.reverse();
+    }
+    points
+}
+
+fn bridge(
+    polygon: Vec<TriangulationPoint>,
+    hole: Vec<TriangulationPoint>,
+    other_holes: &[Vec<TriangulationPoint>],
+) -> Vec<TriangulationPoint> {
+    let edges = |points: &[TriangulationPoint]| {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| [a.point_surface, b.point_surface])
+            .collect::<Vec<_>>()
+    };
+    let obstacles = [&polygon, &hole]
+        .into_iter()
+        .chain(other_holes)
+        .flat_map(|points| edges(points))
+        .collect::<Vec<_>>();
+
+    let mut candidates = Vec::new();
+    for (i, p) in polygon.iter().enumerate() {
+        for (j, h) in hole.iter().enumerate() {
+            let bridge = [p.point_surface, h.point_surface];
+            let distance = bridge[0].distance_to(&bridge[1]);
+            let is_clear =
+                !obstacles.iter().any(|&edge| segments_cross(bridge, edge));
+
+            candidates.push((!is_clear, distance, i, j));
+        }
+    }
+
+    // Prefer bridges that don't cross any edges, then shorter ones.
+    let Some(&(_, _, i, j)) =
+        candidates.iter().min_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)))
+    else {
+        return polygon;
+    };
+
+    // Walk the polygon up to `p`, then all the way around the hole from `h`
+    // back to `h`, then back to `p`, and on along the rest of the polygon.
+    let mut bridged = polygon[..=i].to_vec();
+    bridged.extend(hole[j..].iter().chain(&hole[..=j]));
+    bridged.extend(&polygon[i..]);
+    bridged
+}
+
+fn is_ear(
+    [a, b, c]: [TriangulationPoint; 3],
+    polygon: &[TriangulationPoint],
+) -> bool {
+    let [pa, pb, pc] = [a, b, c].map(surface);
+
+    if orient2d([pa, pb, pc]) <= 0. {
+        return false;
+    }
+
+    // Bridges duplicate vertices, so other vertices might be at the same
+    // positions as the corners of the ear. They don't prevent it from being
+    // clipped.
+    polygon
+        .iter()
+        .map(|point| point.point_surface)
+        .filter(|point| ![pa, pb, pc].contains(point))
+        .all(|p| {
+            orient2d([pa, pb, p]) < 0.
+                || orient2d([pb, pc, p]) < 0.
+                || orient2d([pc, pa, p]) < 0.
+        })
+}
+
+fn segments_cross([a, b]: [Point<2>; 2], [c, d]: [Point<2>; 2]) -> bool {
+    let [o1, o2, o3, o4] =
+        [[a, b, c], [a, b, d], [c, d, a], [c, d, b]].map(orient2d);
+    o1 * o2 < 0. && o3 * o4 < 0.
+}
+
+fn max_u(points: &[TriangulationPoint]) -> Scalar {
+    points
+        .iter()
+        .map(|point| point.point_surface.u)
+        .max()
+        .unwrap_or(Scalar::ZERO)
+}
+
+fn surface(point: TriangulationPoint) -> Point<2> {
+    point.point_surface
+}
+
+fn orient2d(points: [Point<2>; 3]) -> f64 {
+    let [a, b, c] = points.map(|point| robust::Coord {
+        x: point.u.into_f64(),
+        y: point.v.into_f64(),
+    });
+    robust::orient2d(a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::topology::Handedness;
+
+    use super::{signed_area, triangulate, TriangulationPoint};
+
+    #[test]
+    fn square_with_hole() {
+        let points = |points: [[f64; 2]; 4]| {
+            points
+                .map(|point| TriangulationPoint {
+                    point_surface: Point::from(point),
+                    point_global: Point::from(point).to_xyz(),
+                })
+                .to_vec()
+        };
+
+        let exterior = points([[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let interior = points([[1., 1.], [1., 3.], [3., 3.], [3., 1.]]);
+
+        let triangles =
+            triangulate(exterior, vec![interior], Handedness::RightHanded);
+
+        let mut area = Scalar::ZERO;
+        for triangle in triangles {
+            let triangle_area = signed_area(&triangle);
+            assert!(triangle_area > Scalar::ZERO);
+
+            area += triangle_area;
+        }
+        assert_eq!(area, Scalar::from(12.));
+    }
+}
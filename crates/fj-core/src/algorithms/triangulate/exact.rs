@@ -0,0 +1,104 @@
+use fj_interop::{Color, Mesh};
+use fj_math::Point;
+
+use crate::{
+    geometry::{Geometry, Path},
+    storage::Handle,
+    topology::{Cycle, Face, Solid},
+};
+
+use super::{delaunay::TriangulationPoint, ear_clipping};
+
+/// Triangulate a solid that is bounded by planar faces with straight edges
+///
+/// See [`Solid::mesh_exact`].
+pub fn triangulate_solid_exact(
+    solid: &Solid,
+    geometry: &Geometry,
+) -> Result<Mesh<Point<3>>, ExactMeshError> {
+    let mut mesh = Mesh::new();
+
+    for face in solid.shells().iter().flat_map(|shell| shell.faces()) {
+        for points in triangulate_face_exact(face, geometry)? {
+            mesh.push_triangle(points, Color::default());
+        }
+    }
+
+    Ok(mesh)
+}
+
+fn triangulate_face_exact(
+    face: &Face,
+    geometry: &Geometry,
+) -> Result<Vec<[Point<3>; 3]>, ExactMeshError> {
+    let exterior = cycle_points(face, face.region().exterior(), geometry)?;
+    let interiors = face
+        .region()
+        .interiors()
+        .iter()
+        .map(|interior| cycle_points(face, interior, geometry))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let triangles = ear_clipping::triangulate(
+        exterior,
+        interiors,
+        face.coord_handedness(geometry),
+    );
+
+    Ok(triangles
+        .into_iter()
+        .map(|triangle| triangle.map(|point| point.point_global))
+        .collect())
+}
+
+fn cycle_points(
+    face: &Face,
+    cycle: &Handle<Cycle>,
+    geometry: &Geometry,
+) -> Result<Vec<TriangulationPoint>, ExactMeshError> {
+    let surface = geometry.of_surface(face.surface());
+    let Path::Line(u) = surface.u else {
+        return Err(ExactMeshError::CurvedSurface);
+    };
+
+    cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next)| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(face.surface()))
+                .expect("Expected geometry of curve to be defined on surface")
+                .path;
+            let Path::Line(line) = path else {
+                return Err(ExactMeshError::CurvedEdge);
+            };
+
+            let [start, _] = half_edge
+                .curve_boundary(next.start_vertex(), geometry)
+                .inner;
+            let point_surface = line.point_from_line_coords(start);
+            let point_global = u.point_from_line_coords([point_surface.u])
+                + surface.v * point_surface.v;
+
+            Ok(TriangulationPoint {
+                point_surface,
+                point_global,
+            })
+        })
+        .collect()
+}
+
+/// Error computing an exact mesh
+///
+/// Returned by [`Solid::mesh_exact`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ExactMeshError {
+    /// A face of the solid is not planar
+    #[error("Exact meshing only supports planar faces")]
+    CurvedSurface,
+
+    /// An edge of the solid is not straight
+    #[error("Exact meshing only supports straight edges")]
+    CurvedEdge,
+}
@@ -0,0 +1,185 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    mem,
+    sync::Arc,
+};
+
+use fj_interop::Mesh;
+use fj_math::Point;
+
+use crate::{
+    algorithms::approx::{Approx, ApproxCache, VertexApproxCache},
+    geometry::Tolerance,
+    operations::presentation::{GetColor, GetMaterial},
+    queries::ContentHash,
+    topology::Solid,
+    Core,
+};
+
+use super::Triangulate;
+
+/// Cache for triangulations at different levels of detail
+///
+/// Requested tolerances are snapped down to the nearest power of two, and a
+/// triangulation is only computed once per such bucket. This is intended for
+/// viewers, which request a slightly different tolerance for every zoom level,
+/// but don't need a new mesh for each one.
+///
+/// Snapping down means the mesh is never less precise than what was requested,
+/// but it can be up to twice as precise.
+///
+/// Vertex approximations don't depend on the tolerance, and are shared between
+/// all buckets.
+///
+/// Solids are identified by their [`ContentHash`], together with the colors and
+/// materials of their faces, as those end up in the mesh too. Changing any of
+/// that results in a cache miss, but the meshes for previous versions are
+/// kept.
+#[derive(Default)]
+pub struct LodCache {
+    meshes: BTreeMap<(u64, i32), Arc<Mesh<Point<3>>>>,
+    vertices: VertexApproxCache,
+}
+
+impl LodCache {
+    /// Construct an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Triangulate the solid, or return a cached triangulation
+    ///
+    /// The returned mesh was triangulated using [`LodCache::bucket`] of the
+    /// requested tolerance.
+    pub fn approx(
+        &mut self,
+        solid: &Solid,
+        requested_tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Arc<Mesh<Point<3>>> {
+        let exponent = exponent(requested_tolerance.into());
+        let key = (key_of(solid, core), exponent);
+
+        if let Some(mesh) = self.meshes.get(&key) {
+            return mesh.clone();
+        }
+
+        let tolerance = Tolerance::from(2f64.powi(exponent));
+
+        let mut cache = ApproxCache {
+            vertex: mem::take(&mut self.vertices),
+            ..ApproxCache::default()
+        };
+        let approx = solid.approx_with_cache(
+            tolerance,
+            &mut cache,
+            &core.layers.geometry,
+        );
+        self.vertices = cache.vertex;
+
+        let mut mesh = Mesh::new();
+        for face in approx {
+            face.triangulate_into_mesh(&mut mesh, core);
+        }
+        let mesh = Arc::new(mesh);
+
+        self.meshes.insert(key, mesh.clone());
+        mesh
+    }
+
+    /// Snap a tolerance to its bucket
+    ///
+    /// Returns the largest power of two that is not larger than the tolerance.
+    pub fn bucket(tolerance: impl Into<Tolerance>) -> Tolerance {
+        Tolerance::from(2f64.powi(exponent(tolerance.into())))
+    }
+
+    /// Remove all cached triangulations
+    pub fn clear(&mut self) {
+        self.meshes.clear();
+        self.vertices = VertexApproxCache::default();
+    }
+}
+
+/// Identify a solid by everything that ends up in its mesh
+///
+/// Like [`ContentHash`], this doesn't depend on the order of the faces.
+fn key_of(solid: &Solid, core: &mut Core) -> u64 {
+    let mut faces = solid
+        .shells()
+        .iter()
+        .flat_map(|shell| shell.faces().iter())
+        .map(|face| {
+            let content_hash = face.content_hash(&core.layers.geometry);
+            let color = face.region().get_color(core);
+            let material = face.region().get_material(core);
+
+            (content_hash, color, material)
+        })
+        .collect::<Vec<_>>();
+    faces.sort();
+
+    let mut hasher = DefaultHasher::new();
+    faces.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn exponent(tolerance: Tolerance) -> i32 {
+    tolerance.inner().into_f64().log2().floor() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use fj_interop::MaterialId;
+
+    use crate::{
+        fixtures,
+        geometry::Tolerance,
+        operations::presentation::{SetColor, SetMaterial},
+        Core,
+    };
+
+    use super::LodCache;
+
+    #[test]
+    fn reuse_mesh_within_bucket() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+
+        assert_eq!(LodCache::bucket(0.3), Tolerance::from(0.25));
+        assert_eq!(LodCache::bucket(0.25), Tolerance::from(0.25));
+
+        let mut cache = LodCache::new();
+        let a = cache.approx(&cylinder, 0.3, &mut core);
+        let b = cache.approx(&cylinder, 0.26, &mut core);
+        let c = cache.approx(&cylinder, 0.1, &mut core);
+
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+    }
+
+    #[test]
+    fn new_mesh_after_changing_presentation() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+        let region = cylinder.shells().only().faces().first().region().clone();
+
+        let mut cache = LodCache::new();
+        let a = cache.approx(&cylinder, 0.1, &mut core);
+
+        region.set_color([255, 0, 0], &mut core);
+        let b = cache.approx(&cylinder, 0.1, &mut core);
+
+        region.set_material(MaterialId(1), &mut core);
+        let c = cache.approx(&cylinder, 0.1, &mut core);
+
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&b, &c));
+        assert!(c.triangles().any(|triangle| triangle.material.is_some()));
+    }
+}
@@ -1,14 +1,30 @@
 //! Shape triangulation
 
 mod delaunay;
+mod ear_clipping;
+mod exact;
+mod lod;
+mod multi_res;
 mod polygon;
 
 use fj_interop::Mesh;
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
-use crate::{geometry::Tolerance, operations::presentation::GetColor, Core};
+use crate::{
+    geometry::Tolerance,
+    operations::presentation::{GetColor, GetMaterial},
+    Core,
+};
 
-use self::polygon::Polygon;
+use self::{delaunay::TriangulationPoint, polygon::Polygon};
+
+pub(crate) use self::exact::triangulate_solid_exact;
+
+pub use self::{
+    exact::ExactMeshError,
+    lod::LodCache,
+    multi_res::{MultiResApprox, Resolution},
+};
 
 use super::approx::{face::FaceApprox, Approx};
 
@@ -35,64 +51,189 @@ where
 {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
         let (approx, tolerance) = self;
+        (approx, tolerance, Seed::default()).triangulate_into_mesh(mesh, core);
+    }
+}
+
+impl<T> Triangulate for (T, Tolerance, Seed)
+where
+    T: Approx,
+    T::Approximation: IntoIterator<Item = FaceApprox>,
+{
+    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
+        let (approx, tolerance, seed) = self;
 
         let approx = approx.approx(tolerance, &core.layers.geometry);
 
         for approx in approx {
-            approx.triangulate_into_mesh(mesh, core);
+            (approx, seed).triangulate_into_mesh(mesh, core);
         }
     }
 }
 
 impl Triangulate for FaceApprox {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
-        let face_as_polygon = Polygon::new()
-            .with_exterior(
-                self.exterior
-                    .points()
-                    .into_iter()
-                    .map(|point| point.local_form),
-            )
-            .with_interiors(self.interiors.iter().map(|interior| {
-                interior.points().into_iter().map(|point| point.local_form)
-            }));
-
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
-        let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
-        });
-
-        let color = self.face.region().get_color(core).unwrap_or_default();
-
-        for triangle in triangles {
-            let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, color);
+        (self, Seed::default()).triangulate_into_mesh(mesh, core);
+    }
+}
+
+impl Triangulate for (FaceApprox, Seed) {
+    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
+        let (approx, seed) = self;
+        let color = approx.face.region().get_color(core).unwrap_or_default();
+        let material = approx.face.region().get_material(core);
+
+        for points in triangulate_face(approx, seed) {
+            mesh.push_triangle_with_material(points, color, material);
         }
     }
 }
 
+/// The seed for algorithms that make use of randomization
+///
+/// Triangulation shuffles the order in which it processes points. Where the
+/// result is ambiguous, it depends on that order, and therefore on the seed.
+/// The same input, triangulated with the same seed, always results in the same
+/// output.
+///
+/// Approximation, which triangulation builds on, involves no randomization. It
+/// is deterministic, as required by [`GenPolyline`].
+///
+/// The default seed is a fixed value.
+///
+/// [`GenPolyline`]: crate::geometry::traits::GenPolyline
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Seed(pub u64);
+
+impl Default for Seed {
+    fn default() -> Self {
+        // An arbitrary value. Changing it changes the output of triangulation.
+        Self(0x5eed)
+    }
+}
+
+/// Triangulate a face approximation into triangles in global coordinates
+///
+/// Uses Delaunay triangulation. If that fails to produce a valid result, which
+/// can happen for near-degenerate faces, falls back to ear clipping.
+pub(crate) fn triangulate_face(
+    approx: FaceApprox,
+    seed: Seed,
+) -> Vec<[Point<3>; 3]> {
+    let face_as_polygon = Polygon::new()
+        .with_exterior(
+            approx
+                .exterior
+                .points()
+                .into_iter()
+                .map(|point| point.local_form),
+        )
+        .with_interiors(approx.interiors.iter().map(|interior| {
+            interior.points().into_iter().map(|point| point.local_form)
+        }));
+
+    // The cycle approximations are closed, meaning their first point is
+    // repeated at the end. Ear clipping expects open cycles.
+    let [exterior, interiors] = [
+        vec![approx.exterior.points()],
+        approx
+            .interiors
+            .iter()
+            .map(|interior| interior.points())
+            .collect(),
+    ]
+    .map(|cycles| {
+        cycles
+            .into_iter()
+            .map(|mut points| {
+                points.pop();
+                points
+                    .into_iter()
+                    .map(|point| TriangulationPoint {
+                        point_surface: point.local_form,
+                        point_global: point.global_form,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    let exterior = exterior.into_iter().flatten().collect::<Vec<_>>();
+
+    let coord_handedness = approx.coord_handedness;
+    let cycles = [approx.exterior].into_iter().chain(approx.interiors);
+    let triangles = delaunay::triangulate(cycles, coord_handedness, seed)
+        .map(|mut triangles| {
+            triangles.retain(|triangle| {
+                face_as_polygon.contains_triangle(
+                    triangle.map(|point| point.point_surface),
+                )
+            });
+            triangles
+        })
+        .filter(|triangles| covers_face(triangles, &exterior, &interiors));
+
+    let triangles = match triangles {
+        Some(triangles) => triangles,
+        None => {
+            tracing::warn!(
+                "Delaunay triangulation of face failed. Falling back to ear \
+                clipping."
+            );
+            ear_clipping::triangulate(exterior, interiors, coord_handedness)
+        }
+    };
+
+    triangles
+        .into_iter()
+        .map(|triangle| triangle.map(|point| point.point_global))
+        .collect()
+}
+
+/// Check whether the triangles cover the face
+///
+/// The triangles have already been filtered to lie within the face, so they
+/// cover it, if their combined area is the same as that of the face.
+fn covers_face(
+    triangles: &[[TriangulationPoint; 3]],
+    exterior: &[TriangulationPoint],
+    interiors: &[Vec<TriangulationPoint>],
+) -> bool {
+    let area_of_face = interiors.iter().fold(
+        ear_clipping::signed_area(exterior).abs(),
+        |area, interior| area - ear_clipping::signed_area(interior).abs(),
+    );
+    let area_of_triangles = triangles
+        .iter()
+        .map(|triangle| ear_clipping::signed_area(triangle).abs())
+        .fold(Scalar::ZERO, |sum, area| sum + area);
+
+    let epsilon = Scalar::from(1e-9) * area_of_face.abs().max(Scalar::ONE);
+    (area_of_face - area_of_triangles).abs() <= epsilon
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use fj_interop::Mesh;
     use fj_math::{Point, Scalar};
 
     use crate::{
         algorithms::approx::{face::approx_face, ApproxCache},
+        fixtures,
         geometry::Tolerance,
         operations::{
-            build::{BuildCycle, BuildFace},
+            build::{BuildCycle, BuildFace, BuildRegion},
             insert::Insert,
+            reverse::Reverse,
             update::{UpdateFace, UpdateRegion},
         },
         storage::Handle,
-        topology::{Cycle, Face},
+        topology::{Cycle, Face, Region},
         Core,
     };
 
-    use super::Triangulate;
+    use super::{Seed, Triangulate};
 
     #[test]
     fn simple() -> anyhow::Result<()> {
@@ -305,6 +446,138 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sliver() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The vertex at `b` is so close to the line between `a` and `c`, that
+        // the Delaunay triangulation creates a degenerate triangle.
+        let a = [0., 0.];
+        let b = [1., 1e-9];
+        let c = [2., 0.];
+        let d = [2., 1.];
+        let e = [0., 1.];
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |region, core| {
+                    region.update_exterior(
+                        |_, core| {
+                            Cycle::polygon([a, b, c, d, e], surface, core)
+                        },
+                        core,
+                    )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+
+        let triangles = triangulate(face, &mut core)?;
+
+        let mut area = Scalar::ZERO;
+        for triangle in triangles.triangles() {
+            let [a, b, c] = triangle.inner.points;
+            let normal = (b - a).cross(&(c - a));
+
+            assert!(triangle.inner.is_valid());
+            assert!(normal.z > Scalar::ZERO);
+
+            area += normal.magnitude() / 2.;
+        }
+        assert!((area - Scalar::from(2.)).abs() < Scalar::from(1e-6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_cap_shares_rim_with_side_faces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        // The region has a hole, so the cap has an inner rim too, which it
+        // shares with the side face of the hole.
+        let region = Region::circle([1., 1.], 0.7, surface.clone(), &mut core)
+            .add_interiors(
+                [Cycle::circle([1., 1.], 0.3, surface, &mut core)
+                    .reverse(&mut core)],
+                &mut core,
+            );
+        let solid = fixtures::sweep_down(region, 0., 1., &mut core);
+
+        // A coarse tolerance, so the rims are approximated with few points,
+        // and any mismatch between the faces would result in visible cracks.
+        // It's still fine enough to add points between the vertices.
+        let tolerance = Tolerance::from_scalar(0.02)?;
+        let mesh = (&solid, tolerance).triangulate(&mut core);
+
+        let mut cap = BTreeSet::new();
+        let mut sides = BTreeSet::new();
+        for triangle in mesh.triangles() {
+            let points = triangle.inner.points;
+            let on_rim = points.into_iter().filter(|point| point.z.is_zero());
+
+            if points.iter().all(|point| point.z.is_zero()) {
+                cap.extend(on_rim);
+            } else {
+                sides.extend(on_rim);
+            }
+        }
+
+        // The cap and the side faces share the curves along the rims. Their
+        // approximations use the same points, so there are no T-junctions.
+        let center = Point::from([1., 1., 0.]);
+        let inner_rim = cap
+            .iter()
+            .filter(|point| point.distance_to(&center) < Scalar::from(0.5))
+            .count();
+        assert!(inner_rim > 4);
+        assert!(cap.len() - inner_rim > 4);
+        assert_eq!(cap, sides);
+
+        Ok(())
+    }
+
+    #[test]
+    fn same_seed() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // All points of a circle approximation are cocircular, so there are
+        // many valid Delaunay triangulations.
+        let face = Face::circle(
+            core.layers.topology.surfaces.xy_plane(),
+            [0., 0.],
+            1.,
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let seed = Seed(3);
+
+        let triangulate = |core: &mut Core| {
+            let approx = approx_face(
+                face.clone(),
+                tolerance,
+                &mut ApproxCache::default(),
+                &core.layers.geometry,
+            );
+            (approx, seed)
+                .triangulate(core)
+                .triangles()
+                .collect::<Vec<_>>()
+        };
+
+        let a = triangulate(&mut core);
+        let b = triangulate(&mut core);
+
+        assert!(!a.is_empty());
+        assert_eq!(a, b);
+
+        Ok(())
+    }
+
     fn triangulate(
         face: Handle<Face>,
         core: &mut Core,
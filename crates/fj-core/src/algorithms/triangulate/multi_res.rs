@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use fj_interop::Mesh;
+use fj_math::Point;
+
+use crate::{geometry::Tolerance, topology::Solid, Core};
+
+use super::LodCache;
+
+/// A named resolution of a [`MultiResApprox`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Resolution {
+    /// A coarse resolution, suitable for interactive display
+    Display,
+
+    /// A fine resolution, suitable for exporting to a file
+    Export,
+}
+
+/// Triangulations of a solid at multiple resolutions
+///
+/// Each resolution has its own tolerance. A triangulation is only computed,
+/// when it is requested for the first time.
+///
+/// This is a thin layer over [`LodCache`], which does the caching. Like there,
+/// the tolerances are snapped to buckets, and vertex approximations are shared
+/// between all resolutions.
+pub struct MultiResApprox {
+    solid: Solid,
+    tolerances: BTreeMap<Resolution, Tolerance>,
+    cache: LodCache,
+}
+
+impl MultiResApprox {
+    /// Construct an instance for the provided solid and tolerances
+    pub fn new(
+        solid: Solid,
+        display: impl Into<Tolerance>,
+        export: impl Into<Tolerance>,
+    ) -> Self {
+        let tolerances = BTreeMap::from([
+            (Resolution::Display, LodCache::bucket(display)),
+            (Resolution::Export, LodCache::bucket(export)),
+        ]);
+
+        Self {
+            solid,
+            tolerances,
+            cache: LodCache::new(),
+        }
+    }
+
+    /// Access the tolerance of the provided resolution
+    ///
+    /// This is the bucket of the tolerance that was provided on construction.
+    /// See [`LodCache::bucket`].
+    pub fn tolerance(&self, resolution: Resolution) -> Tolerance {
+        self.tolerances[&resolution]
+    }
+
+    /// Triangulate the solid at the provided resolution
+    ///
+    /// Returns the cached triangulation, if the resolution has been requested
+    /// before.
+    pub fn mesh(
+        &mut self,
+        resolution: Resolution,
+        core: &mut Core,
+    ) -> Arc<Mesh<Point<3>>> {
+        let tolerance = self.tolerances[&resolution];
+        self.cache.approx(&self.solid, tolerance, core)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{fixtures, Core};
+
+    use super::{MultiResApprox, Resolution};
+
+    #[test]
+    fn export_is_finer_than_display() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+
+        let mut approx = MultiResApprox::new(cylinder, 0.1, 0.001);
+
+        let display = approx.mesh(Resolution::Display, &mut core);
+        let export = approx.mesh(Resolution::Export, &mut core);
+
+        assert!(export.triangles().count() > display.triangles().count());
+        assert!(Arc::ptr_eq(
+            &approx.mesh(Resolution::Display, &mut core),
+            &display,
+        ));
+    }
+}
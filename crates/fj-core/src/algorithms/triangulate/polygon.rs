@@ -1,9 +1,5 @@
 use fj_interop::ext::SliceExt;
-use fj_math::{LineSegment, Point, PolyChain, Triangle};
-
-use crate::algorithms::intersect::{
-    ray_segment::RaySegmentIntersection, HorizontalRayToTheRight, Intersect,
-};
+use fj_math::{LineSegment, Point, PolyChain, Scalar, Triangle};
 
 #[derive(Default)]
 pub struct Polygon {
@@ -108,98 +104,31 @@ impl Polygon {
 
     /// Check whether the polygon contains a point
     ///
-    /// # Implementation Note
-    ///
-    /// This code is being duplicated by the `Contains<Point<2>>` implementation
-    /// for `Face`. It would be nice to be able to consolidate the duplication,
-    /// but this has turned out to be difficult.
+    /// The exterior and interior chains are treated as one polygon, using the
+    /// even-odd rule. That way, the result doesn't depend on the winding of the
+    /// chains. Points on the boundary are contained.
     fn contains_point(&self, point: impl Into<Point<2>>) -> bool {
-        let ray = HorizontalRayToTheRight {
-            origin: point.into(),
-        };
-
-        let mut num_hits = 0;
-
-        for chain in Some(&self.exterior).into_iter().chain(&self.interiors) {
-            let edges = chain.segments();
-
-            // We need to properly detect the ray passing the boundary at the
-            // "seam" of the polygon, i.e. the vertex between the last and the
-            // first segment. The logic in the loop properly takes care of that,
-            // as long as we initialize the `previous_hit` variable with the
-            // result of the last segment.
-            let mut previous_hit = edges
-                .last()
-                .copied()
-                .and_then(|edge| (&ray, &edge).intersect());
-
-            for edge in edges {
-                let hit = (&ray, &edge).intersect();
-
-                let count_hit = match (hit, previous_hit) {
-                    (
-                        Some(
-                            RaySegmentIntersection::RayStartsOnSegment
-                            | RaySegmentIntersection::RayStartsOnOnFirstVertex
-                            | RaySegmentIntersection::RayStartsOnSecondVertex,
-                        ),
-                        _,
-                    ) => {
-                        // If the ray starts on the boundary of the polygon,
-                        // there's nothing else to check. By the definition of
-                        // this intersection test, the polygon contains the
-                        // point.
-                        return true;
-                    }
-                    (Some(RaySegmentIntersection::RayHitsSegment), _) => {
-                        // We're hitting a segment right-on. Clear case.
-                        true
-                    }
-                    (
-                        Some(RaySegmentIntersection::RayHitsUpperVertex),
-                        Some(RaySegmentIntersection::RayHitsLowerVertex),
-                    )
-                    | (
-                        Some(RaySegmentIntersection::RayHitsLowerVertex),
-                        Some(RaySegmentIntersection::RayHitsUpperVertex),
-                    ) => {
-                        // If we're hitting a vertex, only count it if we've hit
-                        // the other kind of vertex right before.
-                        //
-                        // That means, we're passing through the polygon
-                        // boundary at where two edges touch. Depending on the
-                        // order in which edges are checked, we're seeing this
-                        // as a hit to one edge's lower/upper vertex, then the
-                        // other edge's opposite vertex.
-                        //
-                        // If we're seeing two of the same vertices in a row,
-                        // we're not actually passing through the polygon
-                        // boundary. Then we're just touching a vertex without
-                        // passing through anything.
-                        true
-                    }
-                    (Some(RaySegmentIntersection::RayHitsSegmentAndAreParallel), _) => {
-                        // A parallel edge must be completely ignored. Its
-                        // presence won't change anything, so we can treat it as
-                        // if it wasn't there, and its neighbors were connected
-                        // to each other.
-                        continue;
-                    }
-                    _ => {
-                        // Any other case is not a valid hit.
-                        false
-                    }
-                };
-
-                if count_hit {
-                    num_hits += 1;
-                }
-
-                previous_hit = hit;
-            }
+        let point = point.into();
+
+        let chains = Some(&self.exterior)
+            .into_iter()
+            .chain(&self.interiors)
+            .map(|chain| fj_math::Polygon::from(chain.clone()))
+            .collect::<Vec<_>>();
+
+        if chains
+            .iter()
+            .any(|chain| chain.is_on_boundary(point, Scalar::ZERO))
+        {
+            return true;
         }
 
-        num_hits % 2 == 1
+        let winding_number = chains
+            .iter()
+            .map(|chain| chain.winding_number(point))
+            .sum::<i32>();
+
+        winding_number % 2 != 0
     }
 }
 
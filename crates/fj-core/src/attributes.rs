@@ -0,0 +1,254 @@
+//! # User-defined attributes of objects
+//!
+//! See [`Attributes`].
+
+use std::{
+    any::{Any, TypeId},
+    collections::BTreeMap,
+    fmt,
+    sync::Weak,
+};
+
+use crate::storage::{Handle, ObjectId};
+
+/// # Arbitrary user data, attached to stored objects
+///
+/// Attributes can be used to attach names, tags, or custom IDs to objects,
+/// without modifying the objects themselves. Each object can have one value of
+/// each type attached to it.
+///
+/// Attributes are keyed by the identity of the object (see [`Handle::id`]), not
+/// by the handle itself. They don't keep the object alive, and attributes of
+/// an object are not carried over to objects derived from it, as those have a
+/// different identity.
+///
+/// Once an object no longer exists, the values attached to it are treated as
+/// absent. [`Attributes::prune`] removes them.
+#[derive(Clone, Default)]
+pub struct Attributes {
+    values: BTreeMap<(ObjectId, TypeId), Entry>,
+}
+
+impl Attributes {
+    /// Attach a value to the provided object
+    ///
+    /// Returns the value of the same type that was previously attached to the
+    /// object, if any.
+    pub fn set<T, O>(&mut self, handle: &Handle<O>, value: T) -> Option<T>
+    where
+        T: Clone + 'static,
+        O: 'static,
+    {
+        let entry = Entry {
+            object: Box::new(handle.downgrade()),
+            value: Box::new(value),
+        };
+
+        self.values
+            .insert((handle.id(), TypeId::of::<T>()), entry)
+            .filter(Entry::is_alive)
+            .map(|previous| {
+                *previous
+                    .value
+                    .into_any()
+                    .downcast()
+                    .expect("Values are keyed by their type")
+            })
+    }
+
+    /// Access the value of the provided type, attached to the provided object
+    pub fn get<T, O>(&self, handle: &Handle<O>) -> Option<&T>
+    where
+        T: Clone + 'static,
+    {
+        self.values
+            .get(&(handle.id(), TypeId::of::<T>()))
+            .filter(|entry| entry.is_alive())
+            .map(|entry| {
+                // Dereference the box explicitly. It implements
+                // `AttributeValue` itself, and would be downcast instead of
+                // the value otherwise.
+                (*entry.value)
+                    .as_any()
+                    .downcast_ref()
+                    .expect("Values are keyed by their type")
+            })
+    }
+
+    /// Remove the value of the provided type from the provided object
+    pub fn remove<T, O>(&mut self, handle: &Handle<O>) -> Option<T>
+    where
+        T: Clone + 'static,
+    {
+        self.values
+            .remove(&(handle.id(), TypeId::of::<T>()))
+            .filter(Entry::is_alive)
+            .map(|entry| {
+                *entry
+                    .value
+                    .into_any()
+                    .downcast()
+                    .expect("Values are keyed by their type")
+            })
+    }
+
+    /// Indicate whether no values are attached to any existing objects
+    pub fn is_empty(&self) -> bool {
+        !self.values.values().any(Entry::is_alive)
+    }
+
+    /// Remove the values attached to objects that no longer exist
+    pub fn prune(&mut self) {
+        self.values.retain(|_, entry| entry.is_alive());
+    }
+}
+
+impl fmt::Debug for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Attributes")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+struct Entry {
+    object: Box<dyn Referent>,
+    value: Box<dyn AttributeValue>,
+}
+
+impl Entry {
+    fn is_alive(&self) -> bool {
+        // An object's identity is its location in memory. If the object no
+        // longer exists, another one might have taken its place.
+        self.object.is_alive()
+    }
+}
+
+/// # A weak, type-erased reference to an object
+trait Referent {
+    fn is_alive(&self) -> bool;
+    fn clone_box(&self) -> Box<dyn Referent>;
+}
+
+impl<T> Referent for Weak<T>
+where
+    T: 'static,
+{
+    fn is_alive(&self) -> bool {
+        self.strong_count() > 0
+    }
+
+    fn clone_box(&self) -> Box<dyn Referent> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Referent> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+trait AttributeValue {
+    fn as_any(&self) -> &dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+    fn clone_box(&self) -> Box<dyn AttributeValue>;
+}
+
+impl<T> AttributeValue for T
+where
+    T: Clone + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn AttributeValue> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn AttributeValue> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{
+        operations::{build::BuildFace, insert::Insert},
+        storage::Store,
+        topology::Face,
+        Core,
+    };
+
+    use super::Attributes;
+
+    #[test]
+    fn attach_name_to_face() {
+        let mut core = Core::new();
+
+        let face = Face::triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            &mut core,
+        )
+        .face
+        .insert(&mut core);
+        let other = Face::triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            &mut core,
+        )
+        .face
+        .insert(&mut core);
+
+        core.attributes.set(&face, String::from("front"));
+
+        assert_eq!(
+            core.attributes.get::<String, _>(&face),
+            Some(&String::from("front"))
+        );
+        assert_eq!(core.attributes.get::<u32, _>(&face), None);
+        assert_eq!(core.attributes.get::<String, _>(&other), None);
+
+        let previous = core.attributes.set(&face, String::from("back"));
+        assert_eq!(previous.as_deref(), Some("front"));
+
+        let fork = core.fork();
+        assert_eq!(
+            fork.attributes.get::<String, _>(&face).map(String::as_str),
+            Some("back")
+        );
+    }
+
+    #[test]
+    fn attributes_dont_keep_objects_alive() {
+        let mut attributes = Attributes::default();
+
+        let object = Rc::new(());
+        let weak = Rc::downgrade(&object);
+
+        {
+            let mut store = Store::<Rc<()>>::new();
+            let handle = store.reserve();
+            store.insert(handle.clone(), object);
+
+            attributes.set(&handle, String::from("temporary"));
+            assert!(!attributes.is_empty());
+        }
+
+        // The store and the handle are gone, so the object must be too.
+        assert!(weak.upgrade().is_none());
+        assert!(attributes.is_empty());
+
+        attributes.prune();
+        assert_eq!(format!("{attributes:?}"), "Attributes { len: 0 }");
+    }
+}
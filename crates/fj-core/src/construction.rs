@@ -0,0 +1,197 @@
+//! # Construction geometry
+//!
+//! See [`Construction`].
+
+use std::collections::BTreeMap;
+
+use fj_math::{Line, Point, Vector};
+
+use crate::geometry::{SurfaceGeom, Tolerance};
+
+/// # Named reference entities, that are not part of the shape
+///
+/// Construction geometry (reference points, axes, and planes) helps with
+/// building a model, for example as targets for snapping. It is not part of any
+/// solid, and therefore not included in approximations or exports.
+///
+/// Construction geometry is available to nearest-feature queries. See
+/// [`NearestFeatureToPoint`].
+///
+/// [`NearestFeatureToPoint`]: crate::queries::NearestFeatureToPoint
+#[derive(Clone, Debug, Default)]
+pub struct Construction {
+    entities: BTreeMap<String, ConstructionEntity>,
+}
+
+impl Construction {
+    /// Add an entity with the provided name
+    ///
+    /// Returns the entity that was previously stored under that name, if any.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        entity: impl Into<ConstructionEntity>,
+    ) -> Option<ConstructionEntity> {
+        self.entities.insert(name.into(), entity.into())
+    }
+
+    /// Access the entity with the provided name
+    pub fn get(&self, name: &str) -> Option<&ConstructionEntity> {
+        self.entities.get(name)
+    }
+
+    /// Remove the entity with the provided name
+    pub fn remove(&mut self, name: &str) -> Option<ConstructionEntity> {
+        self.entities.remove(name)
+    }
+
+    /// Iterate over all entities, along with their names
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ConstructionEntity)> {
+        self.entities
+            .iter()
+            .map(|(name, entity)| (name.as_str(), entity))
+    }
+}
+
+/// An entity of construction geometry
+///
+/// See [`Construction`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConstructionEntity {
+    /// A reference point
+    Point(Point<3>),
+
+    /// A reference axis
+    Line(Line<3>),
+
+    /// A reference plane
+    Plane(Plane),
+}
+
+impl ConstructionEntity {
+    /// Compute the point on the entity that is nearest to the provided point
+    pub fn closest_point(&self, point: impl Into<Point<3>>) -> Point<3> {
+        let point = point.into();
+
+        match self {
+            Self::Point(entity) => *entity,
+            Self::Line(line) => {
+                line.point_from_line_coords(line.point_to_line_coords(point))
+            }
+            Self::Plane(plane) => {
+                let normal = plane.normal.normalize();
+                point - normal * (point - plane.origin).dot(&normal)
+            }
+        }
+    }
+}
+
+impl From<Point<3>> for ConstructionEntity {
+    fn from(point: Point<3>) -> Self {
+        Self::Point(point)
+    }
+}
+
+impl From<Line<3>> for ConstructionEntity {
+    fn from(line: Line<3>) -> Self {
+        Self::Line(line)
+    }
+}
+
+impl From<Plane> for ConstructionEntity {
+    fn from(plane: Plane) -> Self {
+        Self::Plane(plane)
+    }
+}
+
+/// A reference plane
+///
+/// See [`ConstructionEntity`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Plane {
+    /// A point on the plane
+    pub origin: Point<3>,
+
+    /// The normal of the plane
+    ///
+    /// Doesn't need to be normalized, but must not be zero.
+    pub normal: Vector<3>,
+}
+
+impl Plane {
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// The surface coordinates are those of the [`SurfaceGeom`] that the plane
+    /// converts into.
+    ///
+    /// Uses [`f64::EPSILON`] as the tolerance. That is too strict for most
+    /// points that are the result of a computation. Use
+    /// [`Plane::point_model_to_surface_with_tolerance`] for those.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the point is not on the plane.
+    pub fn point_model_to_surface(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        self.point_model_to_surface_with_tolerance(point, f64::EPSILON)
+    }
+
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// Works like [`Plane::point_model_to_surface`], except that the point
+    /// only needs to be on the plane within the provided tolerance. A point
+    /// that is off the plane, but within the tolerance, is projected onto it.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the distance of the point from the plane exceeds the
+    /// tolerance.
+    pub fn point_model_to_surface_with_tolerance(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Point<2> {
+        let point = point.into();
+
+        SurfaceGeom::from(*self)
+            .point_to_surface_coords(point, tolerance)
+            .unwrap_or_else(|| {
+                panic!("Point {point:?} is not on plane {self:?}")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use super::Plane;
+
+    #[test]
+    fn point_model_to_surface_with_tolerance() {
+        let plane = Plane {
+            origin: Point::origin(),
+            normal: Vector::unit_z(),
+        };
+        let point = Point::from([1., 2., 1e-6]);
+
+        let point_surface =
+            plane.point_model_to_surface_with_tolerance(point, 1e-3);
+        let projected = Point::from([1., 2., 0.]);
+        assert_eq!(point_surface, plane.point_model_to_surface(projected));
+    }
+
+    #[test]
+    #[should_panic]
+    fn point_model_to_surface_with_strict_tolerance() {
+        let plane = Plane {
+            origin: Point::origin(),
+            normal: Vector::unit_z(),
+        };
+        let point = Point::from([1., 2., 1e-6]);
+
+        plane.point_model_to_surface_with_tolerance(point, 1e-9);
+    }
+}
@@ -3,7 +3,14 @@
 //! See [`Core`].
 
 use crate::{
-    geometry::Tolerance, layers::Layers, validation::ValidationConfig,
+    algorithms::approx::{ApproxCache, ApproxCacheCounts},
+    attributes::Attributes,
+    construction::Construction,
+    geometry::{GeometryConfig, GeometryCounts, Tolerance},
+    layers::Layers,
+    queries::ModelDiff,
+    topology::TopologyCounts,
+    validation::ValidationConfig,
 };
 
 /// An instance of the Fornjot core
@@ -12,25 +19,106 @@ use crate::{
 pub struct Core {
     /// The layers of data that make up the state of a core instance
     pub layers: Layers,
+
+    /// Construction geometry, that is not part of any shape
+    pub construction: Construction,
+
+    /// User-defined attributes of objects
+    pub attributes: Attributes,
 }
 
 impl Core {
     /// Construct an instance of `Core`
     pub fn new() -> Self {
         let layers = Layers::default();
-        Self { layers }
+        Self {
+            layers,
+            construction: Construction::default(),
+            attributes: Attributes::default(),
+        }
+    }
+
+    /// Construct an instance of `Core`, using the provided geometry config
+    ///
+    /// This can be used to change the orientation of the basis planes.
+    pub fn with_geometry_config(config: GeometryConfig) -> Self {
+        let layers = Layers::with_geometry_config(config);
+        Self {
+            layers,
+            construction: Construction::default(),
+            attributes: Attributes::default(),
+        }
     }
 
     /// Construct an instance of `Core`, using the provided configuration
     pub fn with_validation_config(config: ValidationConfig) -> Self {
         let layers = Layers::with_validation_config(config);
-        Self { layers }
+        Self {
+            layers,
+            construction: Construction::default(),
+            attributes: Attributes::default(),
+        }
+    }
+
+    /// Fork this instance, to explore changes independently of the original
+    ///
+    /// See [`Layers::fork`].
+    pub fn fork(&self) -> Self {
+        let layers = self.layers.fork();
+        Self {
+            layers,
+            construction: self.construction.clone(),
+            attributes: self.attributes.clone(),
+        }
+    }
+
+    /// Compare the objects of this instance with those of another
+    ///
+    /// Considers the objects that were inserted through either instance. A
+    /// fork (see [`Core::fork`]) starts out with the objects of the instance it
+    /// was created from, so comparing a fork with its original yields the
+    /// objects that were inserted into either, since the fork was created.
+    ///
+    /// This includes objects that were only created in intermediate steps. To
+    /// compare only the objects that make up two versions of a solid, use
+    /// [`DiffObjects::diff`].
+    ///
+    /// [`DiffObjects::diff`]: crate::queries::DiffObjects::diff
+    pub fn diff(&self, other: &Core) -> ModelDiff {
+        ModelDiff::from_sets(
+            self.layers.topology.inserted(),
+            other.layers.topology.inserted(),
+        )
     }
 
     /// Access the tolerance value used for intermediate geometry representation
     pub fn tolerance(&self) -> Tolerance {
         self.layers.validation.config.tolerance
     }
+
+    /// Gather statistics about the contents of this instance
+    ///
+    /// This is meant for introspection, for example while debugging or working
+    /// on performance. Counting objects requires iterating over the stores, so
+    /// calling this is not free for large models.
+    pub fn stats(&self) -> CoreStats {
+        CoreStats {
+            topology: self.layers.topology.counts(),
+            geometry: self.layers.geometry.counts(),
+            approx_cache: None,
+        }
+    }
+
+    /// Gather statistics, including the sizes of an approximation cache
+    ///
+    /// Approximation caches are not owned by `Core`, so the one to report on
+    /// needs to be provided. Otherwise, this works like [`Core::stats`].
+    pub fn stats_with_cache(&self, cache: &ApproxCache) -> CoreStats {
+        CoreStats {
+            approx_cache: Some(cache.counts()),
+            ..self.stats()
+        }
+    }
 }
 
 impl Default for Core {
@@ -38,3 +126,157 @@ impl Default for Core {
         Self::new()
     }
 }
+
+/// Statistics about the contents of a [`Core`]
+///
+/// Returned by [`Core::stats`] and [`Core::stats_with_cache`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CoreStats {
+    /// The number of objects of each type in the topology layer
+    ///
+    /// The stores are append-only, so this includes objects that are no
+    /// longer referenced by any other object.
+    pub topology: TopologyCounts,
+
+    /// The number of objects that have geometry defined in the geometry layer
+    pub geometry: GeometryCounts,
+
+    /// The number of approximations in an approximation cache
+    ///
+    /// This is only available, if the stats were gathered using
+    /// [`Core::stats_with_cache`].
+    pub approx_cache: Option<ApproxCacheCounts>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Vector;
+
+    use crate::{
+        algorithms::approx::{Approx, ApproxCache},
+        fixtures,
+        geometry::{Path, SurfaceGeom},
+        operations::{
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateShell, UpdateSolid},
+        },
+        topology::{AnyObject, Surface},
+    };
+
+    use super::Core;
+
+    #[test]
+    fn fork() {
+        let mut core = Core::new();
+
+        let surface = Surface::new().insert(&mut core);
+        let original = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::unit_y(),
+        };
+        core.layers
+            .geometry
+            .define_surface(surface.clone(), original);
+
+        let mut fork = core.fork();
+        fork.layers.geometry.redefine_surface(
+            surface.clone(),
+            SurfaceGeom {
+                u: Path::x_axis(),
+                v: Vector::unit_z(),
+            },
+        );
+
+        assert_eq!(core.layers.geometry.of_surface(&surface), &original);
+        assert_eq!(
+            fork.layers.geometry.of_surface(&surface).v,
+            Vector::unit_z()
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let mut core = Core::new();
+
+        let original = fixtures::unit_cube(&mut core);
+
+        let mut fork = core.fork();
+        let shell = original.shells().only();
+        let face = shell.faces().first();
+        let edited = original.update_shell(
+            shell,
+            |shell, core| {
+                [shell.update_face(
+                    face,
+                    |face, core| [face.reverse(core)],
+                    core,
+                )]
+            },
+            &mut fork,
+        );
+
+        let diff = core.diff(&fork);
+
+        // Nothing was inserted into the original, since the fork was created.
+        assert!(diff.only_in_self.is_empty());
+
+        // The edit inserted a new shell and face into the fork.
+        let new_shell = edited.shells().only();
+        assert!(diff.only_in_other.contains(&new_shell.clone().into()));
+        let new_faces = new_shell
+            .faces()
+            .iter()
+            .filter(|face| diff.only_in_other.contains(&(*face).clone().into()))
+            .count();
+        assert_eq!(new_faces, 1);
+        assert!(diff
+            .only_in_other
+            .iter()
+            .all(|object| !matches!(object, AnyObject::Solid(_))));
+
+        // Everything else is shared by both.
+        assert!(diff.common.contains(&shell.clone().into()));
+        for face in shell.faces() {
+            assert!(diff.common.contains(&face.clone().into()));
+        }
+
+        assert_eq!(fork.diff(&core).only_in_self, diff.only_in_other);
+
+        // Ignore validation errors caused by the reversed face.
+        let _ = fork.layers.validation.take_errors();
+    }
+
+    #[test]
+    fn stats() {
+        let mut core = Core::new();
+
+        let empty = core.stats();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        let stats = core.stats();
+
+        // The topology layer also contains objects created in intermediate
+        // steps, but only the final ones have geometry defined for them.
+        assert_eq!(stats.geometry.vertices - empty.geometry.vertices, 8);
+        assert_eq!(stats.geometry.curves - empty.geometry.curves, 12);
+        assert_eq!(stats.topology.faces - empty.topology.faces, 6);
+        assert_eq!(stats.topology.shells - empty.topology.shells, 1);
+        assert_eq!(stats.approx_cache, None);
+
+        let mut cache = ApproxCache::default();
+        (&cube).approx_with_cache(
+            core.tolerance(),
+            &mut cache,
+            &core.layers.geometry,
+        );
+
+        let approx_cache = core
+            .stats_with_cache(&cache)
+            .approx_cache
+            .expect("Stats were gathered with cache");
+        assert_eq!(approx_cache.vertices, 8);
+        assert_eq!(approx_cache.faces, 6);
+    }
+}
@@ -0,0 +1,73 @@
+//! # Solids that are shared between tests
+//!
+//! Many tests need a simple solid to work with. The functions in this module
+//! build them by sweeping a sketch in the xy-plane downward, along the negative
+//! z-axis. The regions of the sketch are counter-clockwise, when viewed from
+//! above, so the faces of the resulting solids point outward.
+
+use fj_math::Vector;
+
+use crate::{
+    geometry::Path,
+    operations::{
+        build::{BuildRegion, BuildSketch, BuildSurface},
+        sweep::SweepSketch,
+        update::UpdateSketch,
+    },
+    topology::{Region, Sketch, Solid, Surface},
+    Core,
+};
+
+/// # Build a cube with edges of length `1`
+///
+/// The cube extends from the origin to `[1, 1, -1]`.
+pub fn unit_cube(core: &mut Core) -> Solid {
+    cuboid([0., 0., -1.], [1., 1., 0.], core)
+}
+
+/// # Build an axis-aligned cuboid between the provided corners
+pub fn cuboid(min: [f64; 3], max: [f64; 3], core: &mut Core) -> Solid {
+    let [x0, y0, z0] = min;
+    let [x1, y1, z1] = max;
+
+    let surface = core.layers.topology.surfaces.space_2d();
+    let region = Region::polygon(
+        [[x0, y0], [x1, y0], [x1, y1], [x0, y1]],
+        surface,
+        core,
+    );
+
+    sweep_down(region, z1, z1 - z0, core)
+}
+
+/// # Build a cylinder with the provided radius and height
+///
+/// The top cap is centered on the origin.
+pub fn cylinder(radius: f64, height: f64, core: &mut Core) -> Solid {
+    let surface = core.layers.topology.surfaces.space_2d();
+    let region = Region::circle([0., 0.], radius, surface, core);
+
+    sweep_down(region, 0., height, core)
+}
+
+/// # Sweep a region downward, from the plane at `z` by `depth`
+///
+/// This can be used for regions that the other functions in this module don't
+/// cover, like ones with holes.
+pub fn sweep_down(
+    region: Region,
+    z: f64,
+    depth: f64,
+    core: &mut Core,
+) -> Solid {
+    let surface = if z == 0. {
+        core.layers.topology.surfaces.xy_plane()
+    } else {
+        let (u, _) = Path::line_from_points([[0., 0., z], [1., 0., z]]);
+        Surface::from_uv(u, Vector::unit_y(), core)
+    };
+
+    Sketch::empty(&core.layers.topology)
+        .add_regions([region], core)
+        .sweep_sketch(surface, Vector::from([0., 0., -depth]), core)
+}
@@ -52,6 +52,34 @@ impl<T: CurveBoundaryElement> CurveBoundary<T> {
     }
 }
 
+impl CurveBoundary<Point<1>> {
+    /// Subdivide the boundary into `n` sub-boundaries of equal length
+    ///
+    /// The sub-boundaries are contiguous, have the same direction as this
+    /// boundary, and together cover it. Returns no sub-boundaries, if `n` is
+    /// zero.
+    pub fn subdivide(&self, n: usize) -> Vec<Self> {
+        let [start, end] = self.inner;
+
+        let points = (0..=n)
+            .map(|i| {
+                // Make sure the last sub-boundary ends exactly where this one
+                // does, regardless of rounding errors.
+                if i == n {
+                    end
+                } else {
+                    start + (end - start) * (i as f64 / n as f64)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        points
+            .windows(2)
+            .map(|points| Self::from([points[0], points[1]]))
+            .collect()
+    }
+}
+
 impl Default for CurveBoundary<Point<1>> {
     fn default() -> Self {
         Self {
@@ -113,3 +141,24 @@ impl CurveBoundaryElement for Point<1> {
 impl CurveBoundaryElement for Vertex {
     type Repr = Handle<Vertex>;
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use super::CurveBoundary;
+
+    #[test]
+    fn subdivide() {
+        let boundary = CurveBoundary::<Point<1>>::from([[0.], [1.]]);
+
+        assert_eq!(boundary.subdivide(0), Vec::new());
+        assert_eq!(boundary.subdivide(1), vec![boundary]);
+        assert_eq!(
+            boundary.subdivide(4),
+            [[0., 0.25], [0.25, 0.5], [0.5, 0.75], [0.75, 1.]]
+                .map(|[a, b]| CurveBoundary::from([[a], [b]]))
+                .to_vec(),
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use fj_math::Vector;
+
+use super::{Path, SurfaceGeom};
+
+/// Configuration for the geometry layer
+///
+/// Controls the orientation of the basis planes, which are defined when the
+/// geometry layer is created. Anything that is defined on those planes, like
+/// sketches that are swept from them, uses the orientation chosen here.
+///
+/// The default orientation is the one that Fornjot has always used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GeometryConfig {
+    /// The geometry of the xy-plane
+    ///
+    /// Defaults to `u` along the x-axis and `v` along the y-axis.
+    pub xy_plane: SurfaceGeom,
+
+    /// The geometry of the xz-plane
+    ///
+    /// Defaults to `u` along the x-axis and `v` along the z-axis.
+    pub xz_plane: SurfaceGeom,
+
+    /// The geometry of the yz-plane
+    ///
+    /// Defaults to `u` along the y-axis and `v` along the z-axis.
+    pub yz_plane: SurfaceGeom,
+}
+
+impl Default for GeometryConfig {
+    fn default() -> Self {
+        Self {
+            xy_plane: SurfaceGeom {
+                u: Path::x_axis(),
+                v: Vector::unit_y(),
+            },
+            xz_plane: SurfaceGeom {
+                u: Path::x_axis(),
+                v: Vector::unit_z(),
+            },
+            yz_plane: SurfaceGeom {
+                u: Path::y_axis(),
+                v: Vector::unit_z(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        geometry::{Path, SurfaceGeom},
+        topology::Topology,
+    };
+
+    use super::{super::Geometry, GeometryConfig};
+
+    #[test]
+    fn flipped_xz_plane() {
+        let topology = Topology::new();
+        let geometry = Geometry::new(
+            &topology,
+            GeometryConfig {
+                xz_plane: SurfaceGeom {
+                    u: Path::x_axis(),
+                    v: -Vector::unit_z(),
+                },
+                ..GeometryConfig::default()
+            },
+        );
+
+        let xz_plane = geometry.of_surface(&topology.surfaces.xz_plane());
+        let tolerance = 0.001;
+
+        let point_surface = Point::from([1., 2.]);
+        let point_global =
+            xz_plane.point_from_surface_coords(point_surface, tolerance);
+        assert_eq!(point_global, Point::from([1., 0., -2.]));
+
+        // The plane's axes are orthonormal, so we can convert back into surface
+        // coordinates by projecting onto them.
+        let u = xz_plane.vector_from_surface_coords([1., 0.], tolerance);
+        let v = xz_plane.vector_from_surface_coords([0., 1.], tolerance);
+        let offset = point_global - xz_plane.origin();
+        assert_eq!(
+            Point::from([offset.dot(&u), offset.dot(&v)]),
+            point_surface
+        );
+    }
+}
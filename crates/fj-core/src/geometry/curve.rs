@@ -5,7 +5,7 @@ use crate::{storage::Handle, topology::Surface};
 use super::{traits::GenPolyline, Path};
 
 /// The geometric definition of a curve
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct CurveGeom {
     /// # The redundant local definitions of the curve geometry
     ///
@@ -37,7 +37,7 @@ impl CurveGeom {
 }
 
 /// The geometric definition of a curve, in 2D surface coordinates
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LocalCurveGeom {
     /// The path that defines the curve on its surface
     pub path: Path<2>,
@@ -71,3 +71,46 @@ pub enum CurveGeom2 {
         geometry: Arc<dyn GenPolyline<3>>,
     },
 }
+
+/// Curves are equal, if they are defined on the same surfaces, with paths that
+/// are approximately equal on each of them.
+#[cfg(test)]
+impl approx::AbsDiffEq for CurveGeom {
+    type Epsilon = <fj_math::Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        fj_math::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.definitions.len() == other.definitions.len()
+            && self.definitions.iter().zip(&other.definitions).all(
+                |((surface_a, a), (surface_b, b))| {
+                    surface_a == surface_b
+                        && a.path.abs_diff_eq(&b.path, epsilon)
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+impl approx::RelativeEq for CurveGeom {
+    fn default_max_relative() -> Self::Epsilon {
+        fj_math::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.definitions.len() == other.definitions.len()
+            && self.definitions.iter().zip(&other.definitions).all(
+                |((surface_a, a), (surface_b, b))| {
+                    surface_a == surface_b
+                        && a.path.relative_eq(&b.path, epsilon, max_relative)
+                },
+            )
+    }
+}
@@ -4,13 +4,20 @@ use std::iter;
 
 use fj_math::{Circle, LineSegment, Point, Scalar, Sign};
 
-use crate::geometry::{traits::GenPolyline, CurveBoundary, Tolerance};
+use crate::geometry::{
+    traits::{GenPolyline, ParamDistribution, PolylineOptions},
+    CurveBoundary, Tolerance,
+};
 
 impl<const D: usize> GenPolyline<D> for Circle<D> {
     fn origin(&self) -> Point<D> {
         self.center() + self.a()
     }
 
+    fn point_at(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_circle_coords(point_curve)
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
@@ -49,13 +56,38 @@ impl<const D: usize> GenPolyline<D> for Circle<D> {
         }
     }
 
-    fn generate_polyline(
+    fn generate_polyline_with_options(
         &self,
         boundary: CurveBoundary<Point<1>>,
         tolerance: Tolerance,
+        options: PolylineOptions,
     ) -> Vec<Point<1>> {
-        let params = CircleApproxParams::new(self, tolerance);
-        params.approx_circle(boundary).collect()
+        let [start, end] = boundary.inner;
+
+        match options.distribution {
+            ParamDistribution::Uniform => {
+                let mut points =
+                    CircleApproxParams::with_options(self, tolerance, &options)
+                        .approx_circle(boundary)
+                        .collect::<Vec<_>>();
+                if options.include_endpoint {
+                    points.push(end);
+                }
+
+                points
+            }
+            ParamDistribution::Custom(distribution) => {
+                let [min, max] = boundary.normalize().inner;
+                let points = distribution(boundary)
+                    .into_iter()
+                    .filter(|point| min < *point && *point < max);
+
+                iter::once(start)
+                    .chain(points)
+                    .chain(iter::once(end))
+                    .collect()
+            }
+        }
     }
 }
 
@@ -70,16 +102,46 @@ impl CircleApproxParams {
     pub fn new<const D: usize>(
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        Self::with_options(circle, tolerance, &PolylineOptions::default())
+    }
+
+    /// Compute path approximation parameters, respecting the segment limits
+    /// of the provided options
+    pub fn with_options<const D: usize>(
+        circle: &Circle<D>,
+        tolerance: impl Into<Tolerance>,
+        options: &PolylineOptions,
     ) -> Self {
         let radius = circle.a().magnitude();
 
-        let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
-            3.,
+        // If the tolerance is larger than the diameter of the circle, the
+        // argument to `acos` would be out of range. Any number of vertices
+        // would be within tolerance in that case, so we can just clamp it.
+        let cos_half_angle =
+            (Scalar::ONE - (tolerance.into().inner() / radius)).max(-1.);
+
+        let min_segments = options.min_segments.max(3);
+        let max_segments = options.max_segments.max(min_segments);
+
+        let mut num_vertices_to_approx_full_circle = Scalar::max(
+            Scalar::PI / cos_half_angle.acos(),
+            Scalar::from(min_segments as f64),
         )
         .ceil();
 
+        if num_vertices_to_approx_full_circle
+            > Scalar::from(max_segments as f64)
+        {
+            tracing::warn!(
+                "Approximating circle of radius {radius} within tolerance \
+                requires {num_vertices_to_approx_full_circle} segments. \
+                Limiting to {max_segments}.",
+            );
+            num_vertices_to_approx_full_circle =
+                Scalar::from(max_segments as f64);
+        }
+
         let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
 
         Self { increment }
@@ -90,6 +152,13 @@ impl CircleApproxParams {
         self.increment
     }
 
+    /// Determine whether the range between `a` and `b`, in units of the
+    /// increment, covers the full circle
+    fn is_full_period(&self, a: Scalar, b: Scalar) -> bool {
+        let period = Scalar::TAU / self.increment();
+        ((b - a).abs() - period).abs() <= period * Scalar::from(1e-9)
+    }
+
     /// Generate points to approximate the circle within the boundary
     pub fn approx_circle(
         &self,
@@ -99,6 +168,18 @@ impl CircleApproxParams {
 
         let [a, b] = boundary.inner.map(|point| point.t / self.increment());
         let direction = (b - a).sign();
+
+        // If the boundary covers the full circle, the end of the boundary is
+        // the same point as its start. Due to floating point error, it might
+        // not be exactly a whole number of increments away from the start
+        // though, which would cause the seam point to be generated as part of
+        // the approximation. Snap the end to the exact number of increments.
+        let b = if self.is_full_period(a, b) {
+            let num_increments = (Scalar::TAU / self.increment()).round();
+            a + num_increments * direction.to_scalar()
+        } else {
+            b
+        };
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
         // We can't generate a point exactly at the boundaries of the range as
@@ -132,16 +213,39 @@ impl CircleApproxParams {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::TAU;
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
 
+    use approx::assert_abs_diff_eq;
     use fj_math::{Point, Scalar};
 
     use crate::geometry::{
-        curves::circle::Circle, traits::GenPolyline, CurveBoundary, Tolerance,
+        curves::circle::Circle,
+        traits::{GenPolyline, ParamDistribution, PolylineOptions},
+        CurveBoundary, Tolerance,
     };
 
     use super::CircleApproxParams;
 
+    #[test]
+    fn point_at() {
+        let circle = Circle::new([1., 2.], [2., 0.], [0., 2.]);
+
+        for (t, expected) in [
+            (0., [3., 2.]),
+            (FRAC_PI_2, [1., 4.]),
+            (PI, [-1., 2.]),
+            (PI + FRAC_PI_2, [1., 0.]),
+            (TAU, [3., 2.]),
+        ] {
+            let point = circle.point_at(Point::from([t]));
+            assert_abs_diff_eq!(
+                point,
+                Point::from(expected),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
     #[test]
     fn increment_for_circle() {
         test_increment(1., 0.5, 3.);
@@ -239,4 +343,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn seam_of_full_circle() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        // Choose the tolerance such, that we need 12 vertices to approximate
+        // the full circle. For this increment, the end of the following
+        // boundary ends up slightly more than a whole number of increments
+        // away from its start, due to floating point error.
+        let tolerance = Tolerance::from_scalar(0.035)?;
+
+        let start = Point::from([FRAC_PI_2]);
+        let end = Point::from([FRAC_PI_2 + TAU]);
+        let boundary = CurveBoundary::from([start, end]);
+
+        let seam = circle.point_from_circle_coords(start);
+        let num_seam_points = |points: &[Point<1>]| {
+            points
+                .iter()
+                .filter(|point| {
+                    circle.point_from_circle_coords(**point).distance_to(&seam)
+                        < Scalar::from(1e-9)
+                })
+                .count()
+        };
+
+        // By default, the boundary is not part of the polyline. The caller
+        // provides the start of the boundary, closing the loop.
+        let open = circle.generate_polyline(boundary, tolerance);
+        let open = [start].into_iter().chain(open).collect::<Vec<_>>();
+        assert_eq!(num_seam_points(&open), 1);
+
+        // If requested, the end of the boundary is appended.
+        let closed = circle.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions {
+                include_endpoint: true,
+                ..PolylineOptions::default()
+            },
+        );
+        assert_eq!(num_seam_points(&closed), 1);
+        assert_eq!(closed.last(), Some(&end));
+
+        Ok(())
+    }
+
+    #[test]
+    fn min_segments_for_tiny_circle() -> anyhow::Result<()> {
+        // The tolerance is much larger than the circle, so without a minimum,
+        // the approximation would degenerate.
+        let circle = Circle::from_center_and_radius([0., 0.], 0.01);
+        let tolerance = Tolerance::from_scalar(1.)?;
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+
+        let points = circle.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions {
+                include_endpoint: true,
+                min_segments: 8,
+                ..PolylineOptions::default()
+            },
+        );
+        assert_eq!(points.len(), 8);
+
+        let points = circle.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions {
+                include_endpoint: true,
+                ..PolylineOptions::default()
+            },
+        );
+        assert_eq!(points.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn max_segments_for_tiny_tolerance() -> anyhow::Result<()> {
+        // The tolerance is so small, that the approximation would require
+        // millions of segments.
+        let circle = Circle::from_center_and_radius([0., 0.], 1000.);
+        let tolerance = Tolerance::from_scalar(1e-9)?;
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+
+        let points = circle.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions {
+                include_endpoint: true,
+                max_segments: 1000,
+                ..PolylineOptions::default()
+            },
+        );
+        assert_eq!(points.len(), 1000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_distribution() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let tolerance = Tolerance::from_scalar(0.1)?;
+        let boundary = CurveBoundary::from([[0.], [PI]]);
+
+        // Points that are not strictly within the boundary are ignored.
+        let distribution = ParamDistribution::Custom(|_| {
+            [0., 0.1, 0.2, 1.5, PI, 4.]
+                .map(|t| Point::from([t]))
+                .to_vec()
+        });
+
+        // Both boundary points are always included, regardless of
+        // `include_endpoint`.
+        for include_endpoint in [false, true] {
+            let points = circle.generate_polyline_with_options(
+                boundary,
+                tolerance,
+                PolylineOptions {
+                    include_endpoint,
+                    distribution,
+                    ..PolylineOptions::default()
+                },
+            );
+            assert_eq!(
+                points,
+                [0., 0.1, 0.2, 1.5, PI].map(|t| Point::from([t])),
+            );
+            assert_eq!(points.first(), Some(&Point::from([0.])));
+            assert_eq!(points.last(), Some(&Point::from([PI])));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,88 @@
+//! # Geometry code specific to ellipses
+
+use fj_math::{Circle, Ellipse, LineSegment, Point};
+
+use crate::geometry::{
+    traits::{GenPolyline, PolylineOptions},
+    CurveBoundary, Tolerance,
+};
+
+impl<const D: usize> GenPolyline<D> for Ellipse<D> {
+    fn origin(&self) -> Point<D> {
+        self.center() + self.a()
+    }
+
+    fn point_at(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_ellipse_coords(point_curve)
+    }
+
+    fn line_segment_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> LineSegment<D> {
+        let LineSegment { points_line, .. } =
+            proxy_circle(self).line_segment_at(point_curve, tolerance);
+
+        LineSegment {
+            points: points_line
+                .map(|point_curve| self.point_from_ellipse_coords(point_curve)),
+            points_line,
+        }
+    }
+
+    fn generate_polyline_with_options(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+        options: PolylineOptions,
+    ) -> Vec<Point<1>> {
+        proxy_circle(self)
+            .generate_polyline_with_options(boundary, tolerance, options)
+    }
+}
+
+/// Build a circle, whose approximation also approximates the ellipse
+///
+/// An ellipse is the image of a circle under a linear map, and the deviation of
+/// an approximation is scaled by at most the semi-major axis of the ellipse.
+/// Approximating a circle with that radius therefore results in points that,
+/// in ellipse coordinates, approximate the ellipse within the same tolerance.
+fn proxy_circle<const D: usize>(ellipse: &Ellipse<D>) -> Circle<D> {
+    let [major, _] = ellipse.radii();
+    Circle::from_center_and_radius(ellipse.center(), major)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_math::{Ellipse, Point};
+
+    use crate::geometry::{traits::GenPolyline, CurveBoundary, Tolerance};
+
+    #[test]
+    fn polyline_within_tolerance() -> anyhow::Result<()> {
+        let ellipse = Ellipse::new([0., 0.], [3., 0.], [0., 1.]);
+        let tolerance = Tolerance::from_scalar(0.1)?;
+
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let points = ellipse.generate_polyline(boundary, tolerance);
+        assert!(!points.is_empty());
+
+        for point_curve in points {
+            // The deviation is largest halfway between two points.
+            let segment = ellipse.line_segment_at(point_curve, tolerance);
+            let [a, b] = segment.points_line.map(|point| point.t);
+            let middle = Point::from([(a + b) / 2.]);
+
+            let on_ellipse = ellipse.point_from_ellipse_coords(middle);
+            let on_segment = segment.points[0]
+                + (segment.points[1] - segment.points[0]) / 2.;
+
+            assert!(on_ellipse.distance_to(&on_segment) <= tolerance.inner(),);
+        }
+
+        Ok(())
+    }
+}
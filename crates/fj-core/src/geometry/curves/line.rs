@@ -2,20 +2,27 @@
 
 use fj_math::{Line, LineSegment, Point};
 
-use crate::geometry::{traits::GenPolyline, CurveBoundary, Tolerance};
+use crate::geometry::{
+    traits::{GenPolyline, PolylineOptions},
+    CurveBoundary, Tolerance,
+};
 
 impl<const D: usize> GenPolyline<D> for Line<D> {
     fn origin(&self) -> Point<D> {
         self.origin()
     }
 
+    fn point_at(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_line_coords(point_curve)
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
         _: Tolerance,
     ) -> LineSegment<D> {
         // Collapse line segment into a point, as per documentation.
-        let point = self.origin() + self.direction() * point_curve.t;
+        let point = self.point_at(point_curve);
 
         LineSegment {
             points: [point; 2],
@@ -23,11 +30,36 @@ impl<const D: usize> GenPolyline<D> for Line<D> {
         }
     }
 
-    fn generate_polyline(
+    fn generate_polyline_with_options(
         &self,
         boundary: CurveBoundary<Point<1>>,
         _: Tolerance,
+        _: PolylineOptions,
     ) -> Vec<Point<1>> {
         boundary.inner.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point};
+
+    use crate::geometry::traits::GenPolyline;
+
+    #[test]
+    fn point_at() {
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 2., 3.]),
+            [2., 0., -1.].into(),
+        );
+
+        for (t, expected) in [
+            (0., [1., 2., 3.]),
+            (1., [3., 2., 2.]),
+            (-0.5, [0., 2., 3.5]),
+            (2.5, [6., 2., 0.5]),
+        ] {
+            assert_eq!(line.point_at(Point::from([t])), Point::from(expected));
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! # Geometry code specific to various types of curves
 
 pub mod circle;
+pub mod ellipse;
 pub mod line;
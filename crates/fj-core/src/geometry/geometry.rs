@@ -1,18 +1,19 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use fj_math::Vector;
+use fj_math::Point;
 
 use crate::{
     storage::Handle,
-    topology::{Curve, Surface, Topology, Vertex},
+    topology::{AnyObject, Curve, Stored, Surface, Topology, Vertex},
 };
 
 use super::{
-    vertex::LocalVertexGeom, CurveGeom, CurveGeom2, LocalCurveGeom, Path,
-    SurfaceGeom, VertexGeom,
+    vertex::LocalVertexGeom, CurveGeom, CurveGeom2, GeometryConfig,
+    LocalCurveGeom, SurfaceGeom, Tolerance, VertexGeom,
 };
 
 /// Geometric data that is associated with topological objects
+#[derive(Clone)]
 pub struct Geometry {
     curve: BTreeMap<Handle<Curve>, CurveGeom>,
     curve2: BTreeMap<Handle<Curve>, CurveGeom2>,
@@ -28,7 +29,10 @@ pub struct Geometry {
 
 impl Geometry {
     /// Create a new instance of `Geometry`
-    pub fn new(topology: &Topology) -> Self {
+    ///
+    /// The geometry of the basis planes is defined according to the provided
+    /// configuration.
+    pub fn new(topology: &Topology, config: GeometryConfig) -> Self {
         let mut self_ = Self {
             curve: BTreeMap::new(),
             curve2: BTreeMap::new(),
@@ -42,31 +46,22 @@ impl Geometry {
             yz_plane: topology.surfaces.yz_plane(),
         };
 
-        self_.define_surface_inner(
-            self_.xy_plane.clone(),
-            SurfaceGeom {
-                u: Path::x_axis(),
-                v: Vector::unit_y(),
-            },
-        );
-        self_.define_surface_inner(
-            self_.xz_plane.clone(),
-            SurfaceGeom {
-                u: Path::x_axis(),
-                v: Vector::unit_z(),
-            },
-        );
-        self_.define_surface_inner(
-            self_.yz_plane.clone(),
-            SurfaceGeom {
-                u: Path::y_axis(),
-                v: Vector::unit_z(),
-            },
-        );
+        self_.define_surface_inner(self_.xy_plane.clone(), config.xy_plane);
+        self_.define_surface_inner(self_.xz_plane.clone(), config.xz_plane);
+        self_.define_surface_inner(self_.yz_plane.clone(), config.yz_plane);
 
         self_
     }
 
+    /// Create an independent copy of this instance
+    ///
+    /// Changes to the copy don't affect the original, and vice versa. Geometry
+    /// that is reference-counted (like [`CurveGeom2`]) is shared between both,
+    /// as it can't be changed in place anyway.
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
     pub(crate) fn define_curve_inner(
         &mut self,
         curve: Handle<Curve>,
@@ -108,6 +103,27 @@ impl Geometry {
         self.surface.insert(surface, geometry);
     }
 
+    pub(crate) fn redefine_surface_inner(
+        &mut self,
+        surface: Handle<Surface>,
+        geometry: SurfaceGeom,
+    ) {
+        if surface == self.space_2d {
+            panic!("Attempting to redefine geometry of 2D space");
+        }
+        if surface == self.xy_plane
+            || surface == self.xz_plane
+            || surface == self.yz_plane
+        {
+            panic!("Attempting to redefine basis plane.");
+        }
+
+        let Some(existing) = self.surface.get_mut(&surface) else {
+            panic!("Attempting to redefine surface that has not been defined");
+        };
+        *existing = geometry;
+    }
+
     pub(crate) fn define_vertex_inner(
         &mut self,
         vertex: Handle<Vertex>,
@@ -121,11 +137,79 @@ impl Geometry {
             .insert(curve, geometry);
     }
 
+    pub(crate) fn prune_inner(
+        &mut self,
+        reachable: &BTreeSet<AnyObject<Stored>>,
+    ) {
+        let is_reachable =
+            |object: AnyObject<Stored>| reachable.contains(&object);
+
+        self.surface.retain(|surface, _| {
+            *surface == self.xy_plane
+                || *surface == self.xz_plane
+                || *surface == self.yz_plane
+                || is_reachable(surface.clone().into())
+        });
+
+        // Curves can be defined in 2D space, which has no surface geometry.
+        // Sketches are defined there, so those definitions must be kept.
+        self.curve.retain(|curve, geometry| {
+            geometry.definitions.retain(|surface, _| {
+                *surface == self.space_2d || self.surface.contains_key(surface)
+            });
+            is_reachable(curve.clone().into())
+                && !geometry.definitions.is_empty()
+        });
+        self.curve2
+            .retain(|curve, _| is_reachable(curve.clone().into()));
+
+        self.vertex.retain(|vertex, geometry| {
+            geometry
+                .definitions
+                .retain(|curve, _| self.curve.contains_key(curve));
+            is_reachable(vertex.clone().into())
+                && !geometry.definitions.is_empty()
+        });
+    }
+
+    /// # Count the objects that have geometry defined for them
+    pub fn counts(&self) -> GeometryCounts {
+        GeometryCounts {
+            curves: self.curve.len(),
+            curves_2: self.curve2.len(),
+            surfaces: self.surface.len(),
+            vertices: self.vertex.len(),
+        }
+    }
+
     /// # Access the geometry of the provided curve
     pub fn of_curve(&self, curve: &Handle<Curve>) -> Option<&CurveGeom> {
         self.curve.get(curve)
     }
 
+    /// # Iterate over all local definitions of all curves
+    ///
+    /// Yields one item per curve and surface that the curve is defined on. A
+    /// curve that is defined on two surfaces shows up twice.
+    ///
+    /// The definitions are sorted by curve, then by surface, using the order
+    /// of their handles. Iterating multiple times yields the same order, but
+    /// that order is only stable within a process. Handles are ordered by the
+    /// address of the object they refer to, which varies between runs.
+    /// Exporters that need deterministic output across runs must sort by
+    /// something else.
+    pub fn all_curve_definitions(
+        &self,
+    ) -> impl Iterator<Item = (Handle<Curve>, Handle<Surface>, &LocalCurveGeom)>
+    {
+        self.curve.iter().flat_map(|(curve, geometry)| {
+            geometry
+                .definitions
+                .iter()
+                .map(|(surface, local)| (curve.clone(), surface.clone(), local))
+        })
+    }
+
     /// # Access the geometry of the provided curve
     ///
     /// ## Implementation Note
@@ -154,6 +238,41 @@ impl Geometry {
         self.vertex.get(vertex)
     }
 
+    /// # Compute the position of the provided vertex in model coordinates
+    ///
+    /// The vertex is defined locally on curves, which are in turn defined
+    /// locally on surfaces. The first of those definitions that leads to a
+    /// surface with defined geometry is used to compute the position. The
+    /// tolerance is required to convert from the coordinates of curved
+    /// surfaces.
+    ///
+    /// Returns `None`, if no such definition exists.
+    pub fn vertex_position(
+        &self,
+        vertex: &Handle<Vertex>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Point<3>> {
+        let tolerance = tolerance.into();
+
+        self.of_vertex(vertex)?.definitions.iter().find_map(
+            |(curve, local_vertex)| {
+                let (surface, local_curve) =
+                    self.of_curve(curve)?.definitions.iter().find(
+                        |(surface, _)| self.surface.contains_key(*surface),
+                    )?;
+
+                let point_surface = local_curve
+                    .path
+                    .point_from_path_coords(local_vertex.position);
+
+                Some(
+                    self.of_surface(surface)
+                        .point_from_surface_coords(point_surface, tolerance),
+                )
+            },
+        )
+    }
+
     /// Access the geometry of the xy-plane
     pub fn xy_plane(&self) -> &SurfaceGeom {
         self.of_surface(&self.xy_plane)
@@ -169,3 +288,109 @@ impl Geometry {
         self.of_surface(&self.yz_plane)
     }
 }
+
+/// The number of objects that have geometry defined in [`Geometry`]
+///
+/// Returned by [`Geometry::counts`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct GeometryCounts {
+    /// The number of curves with local geometry on at least one surface
+    pub curves: usize,
+
+    /// The number of curves with surface-independent geometry
+    pub curves_2: usize,
+
+    /// The number of surfaces with geometry
+    ///
+    /// This includes the basis planes, which are always defined.
+    pub surfaces: usize,
+
+    /// The number of vertices with local geometry on at least one curve
+    pub vertices: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Point;
+
+    use crate::{
+        operations::{build::BuildSolid, insert::Insert},
+        topology::{Solid, Vertex},
+        Core,
+    };
+
+    #[test]
+    fn all_curve_definitions() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+        let faces = [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+            .map(|face| face.to_vec());
+        Solid::from_faces(&faces, &mut core)?;
+
+        let definitions = || {
+            core.layers
+                .geometry
+                .all_curve_definitions()
+                .map(|(curve, surface, _)| (curve, surface))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(definitions(), definitions());
+
+        // Each edge of the tetrahedron is shared by two faces, and its curve is
+        // defined on the surfaces of both.
+        let definitions = definitions();
+        assert_eq!(definitions.len(), 12);
+        for (curve, _) in &definitions {
+            let count = definitions
+                .iter()
+                .filter(|(other, _)| other == curve)
+                .count();
+            assert_eq!(count, 2);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn vertex_position() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let points = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+            .map(Point::from);
+        let [a, b, c, d] = points;
+        let faces = [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+            .map(|face| face.to_vec());
+
+        let tetrahedron = Solid::from_faces(&faces, &mut core)?;
+
+        let positions = tetrahedron
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .flat_map(|face| face.region().exterior().half_edges().iter())
+            .map(|half_edge| {
+                core.layers
+                    .geometry
+                    .vertex_position(half_edge.start_vertex(), core.tolerance())
+                    .expect("Vertex position must be defined")
+            })
+            .collect::<BTreeSet<_>>();
+        assert_eq!(positions, BTreeSet::from(points));
+
+        let undefined = Vertex::new().insert(&mut core);
+        assert_eq!(
+            core.layers
+                .geometry
+                .vertex_position(&undefined, core.tolerance()),
+            None,
+        );
+
+        Ok(())
+    }
+}
@@ -5,19 +5,25 @@ pub mod traits;
 pub mod util;
 
 mod boundary;
+mod config;
 mod curve;
 mod geometry;
 mod path;
+mod projection;
 mod surface;
+mod surface_intersection;
 mod tolerance;
 mod vertex;
 
 pub use self::{
     boundary::{CurveBoundary, CurveBoundaryElement},
+    config::GeometryConfig,
     curve::{CurveGeom, CurveGeom2, LocalCurveGeom},
-    geometry::Geometry,
+    geometry::{Geometry, GeometryCounts},
     path::Path,
-    surface::SurfaceGeom,
+    projection::ProjectedCurve,
+    surface::{DegenerateSurface, SurfaceGeom},
+    surface_intersection::UnsupportedIntersection,
     tolerance::{InvalidTolerance, Tolerance},
     vertex::{LocalVertexGeom, VertexGeom},
 };
@@ -2,7 +2,7 @@
 //!
 //! See [`Path`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Transform, Vector};
 
 /// A path through surface (2D) or global (3D) space
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -10,6 +10,12 @@ pub enum Path<const D: usize> {
     /// A circle
     Circle(Circle<D>),
 
+    /// An ellipse
+    ///
+    /// Results from transforming a circle non-uniformly, for example by scaling
+    /// it along one axis.
+    Ellipse(Ellipse<D>),
+
     /// A line
     Line(Line<D>),
 }
@@ -63,7 +69,16 @@ impl Path<3> {
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
         match self {
-            Self::Circle(curve) => Self::Circle(curve.transform(transform)),
+            Self::Circle(curve) => {
+                // Rigid transforms and uniform scaling keep a circle circular.
+                // Anything else turns it into an ellipse.
+                if Ellipse::from(curve).transform(transform).is_circle() {
+                    Self::Circle(curve.transform(transform))
+                } else {
+                    Self::Ellipse(Ellipse::from(curve).transform(transform))
+                }
+            }
+            Self::Ellipse(curve) => Self::Ellipse(curve.transform(transform)),
             Self::Line(curve) => Self::Line(curve.transform(transform)),
         }
     }
@@ -102,6 +117,26 @@ impl<const D: usize> Path<D> {
         Self::Line(Line::from_points_with_line_coords(points))
     }
 
+    /// Access the direction of the path, if it is a line
+    ///
+    /// Returns `None`, if the path is not a line.
+    pub fn line_direction(&self) -> Option<Vector<D>> {
+        match self {
+            Self::Circle(_) | Self::Ellipse(_) => None,
+            Self::Line(line) => Some(line.direction()),
+        }
+    }
+
+    /// Access the center and radius of the path, if it is a circle
+    ///
+    /// Returns `None`, if the path is not a circle.
+    pub fn circle_params(&self) -> Option<(Point<D>, Scalar)> {
+        match self {
+            Self::Circle(circle) => Some((circle.center(), circle.radius())),
+            Self::Ellipse(_) | Self::Line(_) => None,
+        }
+    }
+
     /// Convert a point on the path into surface coordinates
     pub fn point_from_path_coords(
         &self,
@@ -109,6 +144,7 @@ impl<const D: usize> Path<D> {
     ) -> Point<D> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
@@ -120,6 +156,9 @@ impl<const D: usize> Path<D> {
     ) -> Vector<D> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
             Self::Line(line) => line.vector_from_line_coords(vector),
         }
     }
@@ -129,7 +168,81 @@ impl<const D: usize> Path<D> {
     pub fn reverse(self) -> Self {
         match self {
             Self::Circle(circle) => Self::Circle(circle.reverse()),
+            Self::Ellipse(ellipse) => Self::Ellipse(ellipse.reverse()),
             Self::Line(line) => Self::Line(line.reverse()),
         }
     }
 }
+
+#[cfg(test)]
+impl<const D: usize> approx::AbsDiffEq for Path<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match (self, other) {
+            (Self::Circle(a), Self::Circle(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Ellipse(a), Self::Ellipse(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Line(a), Self::Line(b)) => a.abs_diff_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+impl<const D: usize> approx::RelativeEq for Path<D> {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        match (self, other) {
+            (Self::Circle(a), Self::Circle(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Self::Ellipse(a), Self::Ellipse(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Self::Line(a), Self::Line(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use super::Path;
+
+    #[test]
+    fn line_direction() {
+        let (line, _) = Path::line_from_points([[1., 1.], [1., 3.]]);
+        let circle = Path::<2>::circle_from_radius(1.);
+
+        assert_eq!(line.line_direction(), Some(Vector::from([0., 2.])));
+        assert_eq!(circle.line_direction(), None);
+    }
+
+    #[test]
+    fn circle_params() {
+        let circle = Path::circle_from_center_and_radius([1., 2., 3.], 2.);
+        let line = Path::<3>::x_axis();
+
+        assert_eq!(
+            circle.circle_params(),
+            Some((Point::from([1., 2., 3.]), Scalar::from(2.))),
+        );
+        assert_eq!(line.circle_params(), None);
+    }
+}
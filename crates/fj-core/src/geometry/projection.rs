@@ -0,0 +1,287 @@
+//! Projection of curves into the coordinates of a surface
+
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
+
+use super::{
+    traits::{GenPolyline, PolylineOptions},
+    CurveBoundary, Path, SurfaceGeom, Tolerance,
+};
+
+/// A 3D curve, projected into the coordinates of a surface
+///
+/// Returned by [`SurfaceGeom::project_curve`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProjectedCurve {
+    /// The projected curve can be represented by a path
+    ///
+    /// The path uses the same curve coordinates as the original curve.
+    Path(Path<2>),
+
+    /// The projected curve can only be represented as a polyline
+    ///
+    /// The points of the polyline are in surface coordinates, ordered from the
+    /// start of the boundary to its end.
+    Polyline(Vec<Point<2>>),
+}
+
+pub fn project_curve(
+    surface: &SurfaceGeom,
+    curve: &dyn GenPolyline<3>,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) -> Option<ProjectedCurve> {
+    let points_curve = sample(curve, boundary, tolerance);
+
+    let mut points_surface: Vec<Point<2>> = Vec::new();
+    for &point_curve in &points_curve {
+        let point_global = point_on_polyline(curve, point_curve, tolerance);
+        let mut point_surface =
+            point_to_surface_coords(surface, point_global, tolerance)?;
+
+        // The u-coordinate of a curved surface is an angle, which jumps from
+        // one full turn back to zero at the seam. Keep the coordinates
+        // continuous, so the projected curve doesn't jump with them.
+        if let (Path::Circle(_) | Path::Ellipse(_), Some(previous)) =
+            (surface.u, points_surface.last())
+        {
+            while point_surface.u - previous.u > Scalar::PI {
+                point_surface.u -= Scalar::TAU;
+            }
+            while previous.u - point_surface.u > Scalar::PI {
+                point_surface.u += Scalar::TAU;
+            }
+        }
+
+        points_surface.push(point_surface);
+    }
+
+    // A curve that crosses the surface at the points of its polyline, without
+    // lying within it, would go undetected by the loop above. Check the points
+    // in between too.
+    for points in points_curve.windows(2) {
+        let point_curve = Point::from([(points[0].t + points[1].t) / 2.]);
+        let point_global = point_on_polyline(curve, point_curve, tolerance);
+        point_to_surface_coords(surface, point_global, tolerance)?;
+    }
+
+    let samples = points_curve
+        .into_iter()
+        .zip(points_surface.iter().copied())
+        .collect::<Vec<_>>();
+
+    let fits = |path: &Path<2>| {
+        samples.iter().all(|&(point_curve, point_surface)| {
+            path.point_from_path_coords(point_curve)
+                .distance_to(&point_surface)
+                <= tolerance.inner()
+        })
+    };
+    let path = fit_line(&samples)
+        .filter(fits)
+        .or_else(|| fit_conic(&samples, tolerance).filter(fits));
+
+    Some(match path {
+        Some(path) => ProjectedCurve::Path(path),
+        None => ProjectedCurve::Polyline(points_surface),
+    })
+}
+
+/// Sample the curve at the points of its polyline, including the boundary
+fn sample(
+    curve: &dyn GenPolyline<3>,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) -> Vec<Point<1>> {
+    let [start, _] = boundary.inner;
+
+    let polyline = curve.generate_polyline_with_options(
+        boundary,
+        tolerance,
+        PolylineOptions {
+            include_endpoint: true,
+            ..PolylineOptions::default()
+        },
+    );
+
+    let mut points = vec![start];
+    for point in polyline {
+        let previous = *points.last().expect("Just added a point");
+        if point != previous {
+            points.push(point);
+        }
+    }
+
+    points
+}
+
+fn point_on_polyline(
+    curve: &dyn GenPolyline<3>,
+    point_curve: Point<1>,
+    tolerance: Tolerance,
+) -> Point<3> {
+    let segment = curve.line_segment_at(point_curve, tolerance);
+    let [a, b] = segment.points;
+    let [a_curve, b_curve] = segment.points_line;
+
+    if a_curve == b_curve {
+        return a;
+    }
+
+    a + (b - a) * ((point_curve.t - a_curve.t) / (b_curve.t - a_curve.t))
+}
+
+pub(super) fn point_to_surface_coords(
+    surface: &SurfaceGeom,
+    point: Point<3>,
+    tolerance: Tolerance,
+) -> Option<Point<2>> {
+    let v = surface.v;
+
+    let point_surface = match surface.u {
+        Path::Line(line) => {
+            let d = line.direction();
+            let r = point - line.origin();
+
+            // Solve the normal equations for `r = d * s + v * t`.
+            let [dd, dv, vv] = [d.dot(&d), d.dot(&v), v.dot(&v)];
+            let [dr, vr] = [d.dot(&r), v.dot(&r)];
+            let det = dd * vv - dv * dv;
+            if det == Scalar::ZERO {
+                return None;
+            }
+
+            Point::from([(dr * vv - vr * dv) / det, (vr * dd - dr * dv) / det])
+        }
+        Path::Circle(circle) => conic_to_surface_coords(
+            circle.center(),
+            circle.a(),
+            circle.b(),
+            v,
+            point,
+        )?,
+        Path::Ellipse(ellipse) => conic_to_surface_coords(
+            ellipse.center(),
+            ellipse.a(),
+            ellipse.b(),
+            v,
+            point,
+        )?,
+    };
+
+    let point_global = surface.u.point_from_path_coords([point_surface.u])
+        + v * point_surface.v;
+
+    (point_global.distance_to(&point) <= tolerance.inner())
+        .then_some(point_surface)
+}
+
+fn conic_to_surface_coords(
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    v: Vector<3>,
+    point: Point<3>,
+) -> Option<Point<2>> {
+    let r = point - center;
+
+    // Solve `r = a * x + b * y + v * t`, using Cramer's rule.
+    let det = a.dot(&b.cross(&v));
+    if det == Scalar::ZERO {
+        return None;
+    }
+    let x = r.dot(&b.cross(&v)) / det;
+    let y = a.dot(&r.cross(&v)) / det;
+    let t = a.dot(&b.cross(&r)) / det;
+
+    let angle = Scalar::atan2(y, x);
+    let angle = if angle < Scalar::ZERO {
+        angle + Scalar::TAU
+    } else {
+        angle
+    };
+
+    Some(Point::from([angle, t]))
+}
+
+fn fit_line(samples: &[(Point<1>, Point<2>)]) -> Option<Path<2>> {
+    let [first, last] = [samples.first()?, samples.last()?];
+
+    if first.0 == last.0 || first.1 == last.1 {
+        return None;
+    }
+
+    Some(Path::Line(Line::from_points_with_line_coords([
+        *first, *last,
+    ])))
+}
+
+/// Fit a circle or ellipse through the samples
+///
+/// This assumes, that the curve coordinates are angles, as they are for
+/// circles and ellipses.
+fn fit_conic(
+    samples: &[(Point<1>, Point<2>)],
+    tolerance: Tolerance,
+) -> Option<Path<2>> {
+    let n = samples.len();
+    if n < 3 {
+        return None;
+    }
+
+    // Solve `point = center + a * cos(t) + b * sin(t)` for three samples. For
+    // a closed curve, the first and last sample coincide, so use samples from
+    // the first two thirds instead.
+    let [(t0, p0), (t1, p1), (t2, p2)] =
+        [0, n / 3, n * 2 / 3].map(|i| samples[i]);
+    let [(s0, c0), (s1, c1), (s2, c2)] = [t0, t1, t2].map(|t| t.t.sin_cos());
+
+    let det = |m: [[Scalar; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let rows = [
+        [Scalar::ONE, c0, s0],
+        [Scalar::ONE, c1, s1],
+        [Scalar::ONE, c2, s2],
+    ];
+    let d = det(rows);
+    if d == Scalar::ZERO {
+        return None;
+    }
+
+    let solve = |values: [Scalar; 3]| {
+        [0, 1, 2].map(|column| {
+            let mut m = rows;
+            for (row, value) in m.iter_mut().zip(values) {
+                row[column] = value;
+            }
+            det(m) / d
+        })
+    };
+    let [center_u, a_u, b_u] = solve([p0.u, p1.u, p2.u]);
+    let [center_v, a_v, b_v] = solve([p0.v, p1.v, p2.v]);
+
+    let center = Point::from([center_u, center_v]);
+    let a = Vector::from([a_u, a_v]);
+    let b = Vector::from([b_u, b_v]);
+
+    // The samples could describe a line, in which case `a` and `b` are
+    // parallel. Use the same criterion as `Ellipse::new`, to not trip its
+    // assertion.
+    if a.dot(&a) * b.dot(&b) - a.dot(&b) * a.dot(&b) <= Scalar::ZERO {
+        return None;
+    }
+
+    // If the samples describe a circle, construct it from `a` alone. `b` is
+    // subject to numerical inaccuracy, and `Circle::new` requires exact
+    // values.
+    let b_circle = Vector::from([-a.v, a.u]) * a.cross2d(&b).sign().to_scalar();
+    let is_circle = (b - b_circle).magnitude() <= tolerance.inner();
+
+    Some(if is_circle {
+        Path::Circle(Circle::new(center, a, b_circle))
+    } else {
+        Path::Ellipse(Ellipse::new(center, a, b))
+    })
+}
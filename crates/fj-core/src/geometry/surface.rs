@@ -1,10 +1,19 @@
 //! The geometry that defines a surface
 
-use fj_math::{Point, Scalar, Transform, Triangle, Vector};
+use fj_math::{Line, Point, Scalar, Transform, Triangle, Vector};
 
-use super::{traits::GenPolyline, Path, Tolerance};
+use crate::construction::Plane;
+
+use super::{
+    projection, surface_intersection, traits::GenPolyline, CurveBoundary, Path,
+    ProjectedCurve, Tolerance, UnsupportedIntersection,
+};
 
 /// The geometry that defines a surface
+///
+/// The v-axis must not lie within the curve of the u-axis. For a plane, this
+/// means `v` must not be parallel to the direction of the u-axis. See
+/// [`SurfaceGeom::is_well_formed`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct SurfaceGeom {
     /// The u-axis of the surface
@@ -15,11 +24,172 @@ pub struct SurfaceGeom {
 }
 
 impl SurfaceGeom {
+    /// # Construct a plane from an origin and a normal
+    ///
+    /// The u- and v-axes of the plane are unit vectors, and `u × v` points in
+    /// the direction of the normal. They are derived from the world axis that
+    /// is least aligned with the normal, so the same origin and normal always
+    /// result in the same plane.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the normal is zero.
+    pub fn plane_from_origin_and_normal(
+        origin: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+    ) -> Self {
+        let normal = normal.into();
+        assert!(
+            normal.magnitude() > Scalar::ZERO,
+            "Can't construct plane from zero normal"
+        );
+        let normal = normal.normalize();
+
+        // If multiple axes are equally unaligned, the first one wins. That
+        // keeps the choice deterministic.
+        let axis = [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()]
+            .into_iter()
+            .min_by_key(|axis| axis.dot(&normal).abs())
+            .expect("Array is not empty");
+
+        let u = axis.cross(&normal).normalize();
+        let v = normal.cross(&u);
+
+        Self {
+            u: Path::Line(Line::from_origin_and_direction(origin.into(), u)),
+            v,
+        }
+    }
+
     /// # Access the origin of the surface
     pub fn origin(&self) -> Point<3> {
         self.u.origin()
     }
 
+    /// # Move the origin of a plane, keeping its orientation
+    ///
+    /// The u- and v-axes keep their direction and length. Only the point that
+    /// has the surface coordinates `[0, 0]` changes. If `origin` does not lie
+    /// on the plane, it is projected onto it along the normal.
+    ///
+    /// As a result, the surface coordinates of every point on the plane are
+    /// shifted by the (negated) surface coordinates that `origin` had before.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the surface is not a plane.
+    #[must_use]
+    pub fn with_origin(self, origin: impl Into<Point<3>>) -> Self {
+        let Path::Line(line) = self.u else {
+            panic!("Moving the origin of a curved surface is not supported");
+        };
+        let origin = origin.into();
+
+        let normal = line.direction().cross(&self.v).normalize();
+        let origin = origin - normal * (origin - line.origin()).dot(&normal);
+
+        Self {
+            u: Path::Line(Line::from_origin_and_direction(
+                origin,
+                line.direction(),
+            )),
+            v: self.v,
+        }
+    }
+
+    /// # Convert a point in model coordinates to surface coordinates
+    ///
+    /// Returns `None`, if the point does not lie on the surface, within the
+    /// provided tolerance.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Point<2>> {
+        projection::point_to_surface_coords(
+            self,
+            point.into(),
+            tolerance.into(),
+        )
+    }
+
+    /// # Convert the surface into a [`Plane`], if it is planar
+    ///
+    /// The plane has the same origin as the surface, and its normal is the
+    /// normalized cross product of the u- and v-axes.
+    ///
+    /// Returns `None`, if the surface is curved, or if its basis is so
+    /// degenerate that it has no normal.
+    pub fn as_plane(&self) -> Option<Plane> {
+        let Path::Line(line) = self.u else {
+            return None;
+        };
+
+        let normal = line.direction().cross(&self.v);
+        if normal.magnitude() == Scalar::ZERO {
+            return None;
+        }
+
+        Some(Plane {
+            origin: line.origin(),
+            normal: normal.normalize(),
+        })
+    }
+
+    /// # Compute the normal of the surface at the provided point
+    ///
+    /// The normal is the cross product of the surface's derivatives along the
+    /// u- and v-axes, and is not normalized. Its magnitude is the area in model
+    /// coordinates, that a unit of area in surface coordinates covers at the
+    /// provided point.
+    pub fn normal_at(&self, point_surface: impl Into<Point<2>>) -> Vector<3> {
+        let (sin, cos) = point_surface.into().u.sin_cos();
+
+        let tangent = match self.u {
+            Path::Line(line) => line.direction(),
+            Path::Circle(circle) => circle.b() * cos - circle.a() * sin,
+            Path::Ellipse(ellipse) => ellipse.b() * cos - ellipse.a() * sin,
+        };
+
+        tangent.cross(&self.v)
+    }
+
+    /// # Indicate whether the surface is well-formed
+    ///
+    /// A surface is well-formed, if the tip of its v-axis has a distance from
+    /// the plane of the u-axis that is larger than the provided tolerance. For
+    /// a plane, this is the plane spanned by `u` and `v` itself, meaning the
+    /// distance of `v` from the line of `u`. For a curved surface, it is the
+    /// plane of the curve of the u-axis.
+    ///
+    /// If that is not the case, the surface is degenerate, or so close to it
+    /// that converting between surface and model coordinates is numerically
+    /// unstable.
+    pub fn is_well_formed(&self, tolerance: impl Into<Tolerance>) -> bool {
+        let tolerance = tolerance.into();
+
+        let distance = match self.u {
+            Path::Line(line) => {
+                let direction = line.direction();
+                if direction.magnitude() <= tolerance.inner() {
+                    return false;
+                }
+
+                direction.cross(&self.v).magnitude() / direction.magnitude()
+            }
+            Path::Circle(circle) => {
+                let normal = circle.a().cross(&circle.b()).normalize();
+                self.v.dot(&normal).abs()
+            }
+            Path::Ellipse(ellipse) => {
+                let normal = ellipse.a().cross(&ellipse.b()).normalize();
+                self.v.dot(&normal).abs()
+            }
+        };
+
+        distance > tolerance.inner()
+    }
+
     /// # Return the triangle at the provided point on the surface
     ///
     /// Select a triangle of the surface's triangle mesh representation, the one
@@ -98,7 +268,57 @@ impl SurfaceGeom {
         point - self.origin()
     }
 
+    /// Compute the intersection with another surface
+    ///
+    /// Supports the intersection of planes and cylinders. Returns an empty
+    /// `Vec`, if the surfaces don't intersect. Coincident surfaces don't
+    /// intersect at a curve, and also result in an empty `Vec`.
+    ///
+    /// The intersection of two cylinders is generally a curve that can't be
+    /// represented. Returns an error, if two cylinders are passed that might
+    /// touch, unless their axes are parallel, or their axes intersect and their
+    /// radii are equal. Returns an error for oblique and elliptic cylinders
+    /// too.
+    pub fn intersect(
+        &self,
+        other: &Self,
+    ) -> Result<Vec<Path<3>>, UnsupportedIntersection> {
+        surface_intersection::intersect(self, other)
+    }
+
+    /// Project a 3D curve into the coordinates of the surface
+    ///
+    /// The curve is sampled within the provided boundary, and the samples are
+    /// converted into surface coordinates. If the samples can be fitted by a
+    /// line, circle, or ellipse within the tolerance, that path is returned.
+    /// Otherwise, the samples are returned as a polyline.
+    ///
+    /// This is needed to define the local geometry of curves that result from
+    /// computations in 3D, like the intersection of two surfaces.
+    ///
+    /// Returns `None`, if any sample does not lie on the surface, within the
+    /// tolerance.
+    pub fn project_curve(
+        &self,
+        curve: &dyn GenPolyline<3>,
+        boundary: impl Into<CurveBoundary<Point<1>>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<ProjectedCurve> {
+        projection::project_curve(
+            self,
+            curve,
+            boundary.into(),
+            tolerance.into(),
+        )
+    }
+
     /// Transform the surface geometry
+    ///
+    /// Transforms the origin and direction of the u-axis, as well as the
+    /// v-axis. For curved surfaces, this transforms the frame that defines the
+    /// curve of the u-axis.
+    ///
+    /// An isometry can be converted into a [`Transform`], to be passed here.
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
         let Self { u, v } = self;
@@ -109,12 +329,92 @@ impl SurfaceGeom {
     }
 }
 
+#[cfg(test)]
+impl approx::AbsDiffEq for SurfaceGeom {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.u.abs_diff_eq(&other.u, epsilon)
+            && self.v.abs_diff_eq(&other.v, epsilon)
+    }
+}
+
+#[cfg(test)]
+impl approx::RelativeEq for SurfaceGeom {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.u.relative_eq(&other.u, epsilon, max_relative)
+            && self.v.relative_eq(&other.v, epsilon, max_relative)
+    }
+}
+
+impl From<Plane> for SurfaceGeom {
+    /// See [`SurfaceGeom::plane_from_origin_and_normal`]
+    fn from(plane: Plane) -> Self {
+        Self::plane_from_origin_and_normal(plane.origin, plane.normal)
+    }
+}
+
+/// The basis of a surface is degenerate
+///
+/// See [`SurfaceGeom::is_well_formed`].
+#[derive(Debug, thiserror::Error)]
+#[error("Surface basis is degenerate: {0:#?}")]
+pub struct DegenerateSurface(pub SurfaceGeom);
+
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::TAU;
+
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+    use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
     use pretty_assertions::assert_eq;
 
-    use crate::geometry::{Path, SurfaceGeom, Tolerance};
+    use crate::{
+        construction::Plane,
+        geometry::{Path, ProjectedCurve, SurfaceGeom, Tolerance},
+    };
+
+    #[test]
+    fn is_well_formed() {
+        let tolerance = Tolerance::from_scalar(1e-6).unwrap();
+
+        let plane = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::from([0., 1., 0.]),
+        };
+        assert!(plane.is_well_formed(tolerance));
+
+        let nearly_parallel = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::from([1., 1e-9, 0.]),
+        };
+        assert!(!nearly_parallel.is_well_formed(tolerance));
+
+        let cylinder = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::from([0., 0., 1.]),
+        };
+        assert!(cylinder.is_well_formed(tolerance));
+
+        let flat_cylinder = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::from([1., 1., 1e-9]),
+        };
+        assert!(!flat_cylinder.is_well_formed(tolerance));
+    }
 
     #[test]
     fn point_from_surface_coords() {
@@ -153,4 +453,149 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn plane_round_trip() {
+        let plane = Plane {
+            origin: Point::from([1., 2., 3.]),
+            normal: Vector::from([0., 0., 2.]),
+        };
+
+        let surface = SurfaceGeom::from(plane);
+        assert_eq!(
+            surface.as_plane(),
+            Some(Plane {
+                origin: plane.origin,
+                normal: Vector::unit_z(),
+            }),
+        );
+        assert_eq!(SurfaceGeom::from(surface.as_plane().unwrap()), surface);
+
+        let cylinder = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::unit_z(),
+        };
+        assert_eq!(cylinder.as_plane(), None);
+    }
+
+    #[test]
+    fn with_origin() {
+        let surface = SurfaceGeom {
+            u: Path::Line(Line::from_origin_and_direction(
+                Point::from([0., 0., 1.]),
+                Vector::from([2., 0., 0.]),
+            )),
+            v: Vector::from([0., 1., 0.]),
+        };
+        let point = Point::from([6., 4., 1.]);
+
+        assert_eq!(
+            surface.point_to_surface_coords(point, 0.001),
+            Some(Point::from([3., 4.])),
+        );
+
+        // The new origin is not on the plane, and gets projected onto it.
+        let moved = surface.with_origin([2., 1., 5.]);
+        assert_eq!(moved.origin(), Point::from([2., 1., 1.]));
+        assert_eq!(
+            moved.point_to_surface_coords(point, 0.001),
+            Some(Point::from([2., 3.])),
+        );
+    }
+
+    #[test]
+    fn project_curve() {
+        let surface = SurfaceGeom {
+            u: Path::Line(Line::from_origin_and_direction(
+                Point::from([0., 0., 1.]),
+                Vector::from([1., 0., 0.]),
+            )),
+            v: Vector::from([0., 1., 0.]),
+        };
+        let tolerance = Tolerance::from_scalar(1e-6).unwrap();
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 2., 1.]),
+            Vector::from([1., 1., 0.]),
+        );
+        let Some(ProjectedCurve::Path(path)) =
+            surface.project_curve(&line, [[0.], [1.]], tolerance)
+        else {
+            panic!("Expected line to be projected into a path");
+        };
+        assert_abs_diff_eq!(
+            path,
+            Path::Line(Line::from_origin_and_direction(
+                Point::from([1., 2.]),
+                Vector::from([1., 1.]),
+            )),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let circle = Circle::new([0., 0., 1.], [2., 0., 0.], [0., 2., 0.]);
+        let Some(ProjectedCurve::Path(path)) =
+            surface.project_curve(&circle, [[0.], [TAU]], tolerance)
+        else {
+            panic!("Expected circle to be projected into a path");
+        };
+        assert_abs_diff_eq!(
+            path,
+            Path::Circle(Circle::new([0., 0.], [2., 0.], [0., 2.])),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let not_on_surface = Line::from_origin_and_direction(
+            Point::from([0., 0., 1.]),
+            Vector::from([0., 0., 1.]),
+        );
+        assert_eq!(
+            surface.project_curve(&not_on_surface, [[0.], [1.]], tolerance),
+            None,
+        );
+    }
+
+    #[test]
+    fn transform() {
+        let surface = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::unit_y(),
+        };
+        let normal = |surface: &SurfaceGeom| {
+            let Path::Line(u) = surface.u else {
+                unreachable!("Surface is a plane");
+            };
+            u.direction().cross(&surface.v)
+        };
+
+        let rotation = Transform::rotation(Vector::unit_x() * Scalar::PI / 2.);
+        let transformed = surface.transform(&rotation);
+
+        assert_abs_diff_eq!(
+            normal(&transformed),
+            rotation.transform_vector(&normal(&surface)),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            normal(&transformed),
+            -Vector::unit_y(),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn approximate_equality() {
+        let surface = |offset: f64| SurfaceGeom {
+            u: Path::Line(Line::from_origin_and_direction(
+                Point::from([1., 0., offset]),
+                Vector::from([0., 2., 0.]),
+            )),
+            v: Vector::from([0., 0., 2. + offset]),
+        };
+
+        let a = surface(0.);
+        let b = surface(1e-9);
+
+        assert_abs_diff_eq!(a, b, epsilon = Scalar::from(1e-6));
+        assert_abs_diff_ne!(a, b, epsilon = Scalar::from(1e-12));
+    }
 }
@@ -0,0 +1,436 @@
+//! Intersection between the geometry of two surfaces
+
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
+
+use super::{Path, SurfaceGeom};
+
+/// The intersection of two surfaces is not supported
+///
+/// Returned by [`SurfaceGeom::intersect`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum UnsupportedIntersection {
+    /// One of the surfaces is an oblique cylinder
+    #[error("Intersecting oblique cylinders is not supported")]
+    ObliqueCylinder,
+
+    /// The cylinders intersect at a curve that can't be represented
+    ///
+    /// This is the case, if their axes are not parallel, unless the axes
+    /// intersect and the radii are equal.
+    #[error(
+        "Intersecting cylinders with non-parallel axes is only supported, if \
+        the axes intersect and the radii are equal"
+    )]
+    SkewCylinders,
+
+    /// One of the surfaces is an elliptic cylinder
+    #[error("Intersecting elliptic cylinders is not supported")]
+    EllipticCylinder,
+}
+
+pub fn intersect(
+    a: &SurfaceGeom,
+    b: &SurfaceGeom,
+) -> Result<Vec<Path<3>>, UnsupportedIntersection> {
+    let curves = match (Kind::of(a)?, Kind::of(b)?) {
+        (Kind::Plane(a), Kind::Plane(b)) => {
+            plane_plane(a, b).into_iter().collect()
+        }
+        (Kind::Plane(plane), Kind::Cylinder(cylinder))
+        | (Kind::Cylinder(cylinder), Kind::Plane(plane)) => {
+            plane_cylinder(plane, cylinder)
+        }
+        (Kind::Cylinder(a), Kind::Cylinder(b)) => cylinder_cylinder(a, b)?,
+    };
+
+    Ok(curves)
+}
+
+enum Kind {
+    Plane(Plane),
+    Cylinder(Cylinder),
+}
+
+impl Kind {
+    fn of(surface: &SurfaceGeom) -> Result<Self, UnsupportedIntersection> {
+        let kind = match surface.u {
+            Path::Line(line) => Self::Plane(Plane {
+                origin: line.origin(),
+                normal: line.direction().cross(&surface.v).normalize(),
+            }),
+            Path::Circle(circle) => Self::Cylinder(Cylinder {
+                circle,
+                axis: surface.v,
+            }),
+            Path::Ellipse(_) => {
+                return Err(UnsupportedIntersection::EllipticCylinder);
+            }
+        };
+
+        Ok(kind)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Plane {
+    origin: Point<3>,
+    normal: Vector<3>,
+}
+
+impl Plane {
+    fn distance_from_origin(&self) -> Scalar {
+        self.normal.dot(&self.origin.coords)
+    }
+}
+
+/// A cylinder, swept from a circle along an axis
+///
+/// The axis is not necessarily perpendicular to the circle.
+#[derive(Clone, Copy)]
+struct Cylinder {
+    circle: Circle<3>,
+    axis: Vector<3>,
+}
+
+impl Cylinder {
+    fn is_right(&self) -> bool {
+        let axis = self.axis.normalize();
+        let radius = self.circle.radius();
+
+        [self.circle.a(), self.circle.b()]
+            .into_iter()
+            .all(|v| v.dot(&axis).abs() <= radius * EPSILON)
+    }
+}
+
+fn plane_plane(a: Plane, b: Plane) -> Option<Path<3>> {
+    let direction = a.normal.cross(&b.normal);
+    if direction.magnitude() <= Scalar::from(EPSILON) {
+        // The planes are parallel. Either they don't intersect, or they are
+        // coincident, which doesn't result in a curve either.
+        return None;
+    }
+
+    let origin = (b.normal.cross(&direction) * a.distance_from_origin()
+        + direction.cross(&a.normal) * b.distance_from_origin())
+        / direction.dot(&direction);
+
+    Some(Path::Line(Line::from_origin_and_direction(
+        Point { coords: origin },
+        direction,
+    )))
+}
+
+fn plane_cylinder(plane: Plane, cylinder: Cylinder) -> Vec<Path<3>> {
+    let Cylinder { circle, axis } = cylinder;
+    let n = plane.normal;
+
+    // Points on the cylinder have the form `center + a cos t + b sin t + axis
+    // s`. Plug that into the plane equation, `n · x = d`.
+    let d = plane.distance_from_origin() - n.dot(&circle.center().coords);
+    let [n_a, n_b, n_axis] = [circle.a(), circle.b(), axis].map(|v| n.dot(&v));
+
+    if n_axis.abs() > axis.magnitude() * EPSILON {
+        // The plane is not parallel to the axis. Each line of the cylinder
+        // intersects it at exactly one point, resulting in a closed curve.
+        let center = circle.center() + axis * (d / n_axis);
+        let a = circle.a() - axis * (n_a / n_axis);
+        let b = circle.b() - axis * (n_b / n_axis);
+
+        return vec![ellipse_from_conjugate_diameters(center, a, b)];
+    }
+
+    // The plane is parallel to the axis. It intersects the cylinder at up to
+    // two lines, where `n_a cos t + n_b sin t = d`.
+    let amplitude = (n_a * n_a + n_b * n_b).sqrt();
+    let tolerance = circle.radius() * EPSILON;
+    if d.abs() > amplitude + tolerance {
+        return Vec::new();
+    }
+
+    let phase = n_b.atan2(n_a);
+    let offset = (d / amplitude).max(-1.).min(Scalar::ONE).acos();
+
+    let line_at = |t: Scalar| {
+        let origin = circle.point_from_circle_coords([t]);
+        Path::Line(Line::from_origin_and_direction(origin, axis))
+    };
+
+    if (d.abs() - amplitude).abs() <= tolerance {
+        // The plane touches the cylinder.
+        return vec![line_at(phase)];
+    }
+
+    vec![line_at(phase - offset), line_at(phase + offset)]
+}
+
+fn cylinder_cylinder(
+    a: Cylinder,
+    b: Cylinder,
+) -> Result<Vec<Path<3>>, UnsupportedIntersection> {
+    if !a.is_right() || !b.is_right() {
+        return Err(UnsupportedIntersection::ObliqueCylinder);
+    }
+
+    let axis_a = a.axis.normalize();
+    let axis_b = b.axis.normalize();
+
+    if axis_a.cross(&axis_b).magnitude() <= Scalar::from(EPSILON) {
+        return Ok(parallel_cylinders(a, b));
+    }
+
+    // The axes are not parallel. The intersection is generally a quartic
+    // curve, which we can't represent. But if the axes intersect and the radii
+    // are equal, it degenerates into two ellipses, which lie within the planes
+    // that bisect the axes.
+    let center_a = a.circle.center();
+    let center_b = b.circle.center();
+    let radius = a.circle.radius();
+
+    let normal = axis_a.cross(&axis_b).normalize();
+    let axes_distance = normal.dot(&(center_b - center_a)).abs();
+    let radii_differ = (radius - b.circle.radius()).abs() > radius * EPSILON;
+
+    if axes_distance > radius + b.circle.radius() + radius * EPSILON {
+        // The axes are too far apart for the cylinders to touch.
+        return Ok(Vec::new());
+    }
+    if axes_distance > radius * EPSILON || radii_differ {
+        return Err(UnsupportedIntersection::SkewCylinders);
+    }
+
+    // Find the point where the axes intersect, in the plane they span.
+    let t = (center_b - center_a).cross(&axis_b).dot(&normal)
+        / axis_a.cross(&axis_b).dot(&normal);
+    let origin = center_a + axis_a * t;
+
+    let curves = [axis_a - axis_b, axis_a + axis_b]
+        .into_iter()
+        .flat_map(|normal| {
+            let plane = Plane {
+                origin,
+                normal: normal.normalize(),
+            };
+            plane_cylinder(plane, a)
+        })
+        .collect();
+
+    Ok(curves)
+}
+
+fn parallel_cylinders(a: Cylinder, b: Cylinder) -> Vec<Path<3>> {
+    let axis = a.axis.normalize();
+    let [r_a, r_b] = [a.circle.radius(), b.circle.radius()];
+    let tolerance = r_a.max(r_b) * EPSILON;
+
+    // Project the center of `b` into the plane of the circle of `a`. From here
+    // on, this is the intersection of two circles in that plane.
+    let offset = b.circle.center() - a.circle.center();
+    let offset = offset - axis * offset.dot(&axis);
+    let distance = offset.magnitude();
+
+    if distance <= tolerance {
+        // The cylinders are coaxial. Either they don't intersect, or they are
+        // coincident, which doesn't result in a curve either.
+        return Vec::new();
+    }
+    if distance > r_a + r_b + tolerance
+        || distance < (r_a - r_b).abs() - tolerance
+    {
+        return Vec::new();
+    }
+
+    let direction = offset / distance;
+    let along = (distance * distance + r_a * r_a - r_b * r_b) / (distance * 2.);
+    let base = a.circle.center() + direction * along;
+
+    let line_at = |origin: Point<3>| {
+        Path::Line(Line::from_origin_and_direction(origin, a.axis))
+    };
+
+    let across = r_a * r_a - along * along;
+    if across <= tolerance * r_a {
+        // The cylinders touch.
+        return vec![line_at(base)];
+    }
+
+    let across = axis.cross(&direction) * across.sqrt();
+    vec![line_at(base - across), line_at(base + across)]
+}
+
+/// Build an ellipse from two conjugate semi-diameters
+///
+/// Converts them into the semi-major and semi-minor axes. Returns a circle, if
+/// those turn out to be of equal length.
+fn ellipse_from_conjugate_diameters(
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+) -> Path<3> {
+    let angle = (a.dot(&b) * 2.).atan2(a.dot(&a) - b.dot(&b)) / 2.;
+    let (sin, cos) = angle.sin_cos();
+
+    let major = a * cos + b * sin;
+    let minor = b * cos - a * sin;
+
+    if major.magnitude() - minor.magnitude() <= major.magnitude() * EPSILON {
+        let minor = minor.normalize() * major.magnitude();
+        return Path::Circle(Circle::new(center, major, minor));
+    }
+
+    Path::Ellipse(Ellipse::new(center, major, minor))
+}
+
+const EPSILON: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Circle, Ellipse, Point, Scalar, Vector};
+
+    use crate::geometry::{Path, SurfaceGeom};
+
+    use super::UnsupportedIntersection;
+
+    #[test]
+    fn plane_cylinder() {
+        let cylinder = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::unit_z(),
+        };
+        let tilted_plane = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::from([0., 1., 1.]),
+        };
+
+        let curves = tilted_plane.intersect(&cylinder).unwrap();
+        let [Path::Ellipse(ellipse)] = curves.as_slice() else {
+            panic!("Expected exactly one ellipse; got {curves:?}");
+        };
+
+        let epsilon = Scalar::from(1e-12);
+        let [major, minor] = ellipse.radii();
+        assert_abs_diff_eq!(
+            ellipse.center(),
+            Point::origin(),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(major, Scalar::from(SQRT_2), epsilon = epsilon);
+        assert_abs_diff_eq!(minor, Scalar::ONE, epsilon = epsilon);
+        assert_abs_diff_eq!(
+            ellipse.a().normalize().dot(&Vector::unit_x()).abs(),
+            Scalar::ZERO,
+            epsilon = epsilon
+        );
+    }
+
+    #[test]
+    fn parallel_cylinders() {
+        let cylinder = |x: f64| SurfaceGeom {
+            u: Path::circle_from_center_and_radius([x, 0., 0.], 1.),
+            v: Vector::unit_z(),
+        };
+
+        let curves = cylinder(0.).intersect(&cylinder(1.)).unwrap();
+
+        assert_eq!(curves.len(), 2);
+        for curve in curves {
+            let Path::Line(line) = curve else {
+                panic!("Expected line; got {curve:?}");
+            };
+            assert_abs_diff_eq!(
+                line.origin().x,
+                Scalar::from(0.5),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn perpendicular_cylinders_of_equal_radius() {
+        let along_z = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::unit_z(),
+        };
+        let along_x = SurfaceGeom {
+            u: Path::Circle(Circle::new(
+                Point::origin(),
+                Vector::unit_y(),
+                Vector::unit_z(),
+            )),
+            v: Vector::unit_x(),
+        };
+
+        let curves = along_z.intersect(&along_x).unwrap();
+
+        assert_eq!(curves.len(), 2);
+        for curve in curves {
+            let Path::Ellipse(ellipse) = curve else {
+                panic!("Expected ellipse; got {curve:?}");
+            };
+            let [major, minor] = ellipse.radii();
+            assert_abs_diff_eq!(
+                major,
+                Scalar::from(SQRT_2),
+                epsilon = Scalar::from(1e-12)
+            );
+            assert_abs_diff_eq!(
+                minor,
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn skew_cylinders() {
+        let along_z = SurfaceGeom {
+            u: Path::circle_from_radius(1.),
+            v: Vector::unit_z(),
+        };
+        let along_x = |y: f64, radius: f64| SurfaceGeom {
+            u: Path::Circle(Circle::new(
+                Point::from([0., y, 0.]),
+                Vector::unit_y() * radius,
+                Vector::unit_z() * radius,
+            )),
+            v: Vector::unit_x(),
+        };
+
+        // The axes are too far apart for the cylinders to touch.
+        assert_eq!(along_z.intersect(&along_x(3., 1.)), Ok(Vec::new()));
+
+        // The cylinders intersect at a curve that can't be represented.
+        assert_eq!(
+            along_z.intersect(&along_x(0.5, 1.)),
+            Err(UnsupportedIntersection::SkewCylinders)
+        );
+        assert_eq!(
+            along_z.intersect(&along_x(0., 0.5)),
+            Err(UnsupportedIntersection::SkewCylinders)
+        );
+    }
+
+    #[test]
+    fn elliptic_cylinder() {
+        let elliptic_cylinder = SurfaceGeom {
+            u: Path::Ellipse(Ellipse::new(
+                Point::origin(),
+                Vector::unit_x() * 2.,
+                Vector::unit_y(),
+            )),
+            v: Vector::unit_z(),
+        };
+        let plane = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::unit_y(),
+        };
+
+        assert_eq!(
+            plane.intersect(&elliptic_cylinder),
+            Err(UnsupportedIntersection::EllipticCylinder)
+        );
+    }
+}
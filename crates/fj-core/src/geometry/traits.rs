@@ -35,6 +35,12 @@ pub trait GenPolyline<const D: usize> {
     /// # Access the origin of the curve
     fn origin(&self) -> Point<D>;
 
+    /// # Compute the exact point on the curve at the provided parameter
+    ///
+    /// Unlike [`GenPolyline::line_segment_at`], this doesn't approximate the
+    /// curve, and is therefore independent of any tolerance.
+    fn point_at(&self, point_curve: Point<1>) -> Point<D>;
+
     /// # Compute a line segment to approximate the curve at this point
     ///
     /// ## Degenerate Case
@@ -49,11 +55,302 @@ pub trait GenPolyline<const D: usize> {
     ) -> LineSegment<D>;
 
     /// # Generate a polyline within the provided boundary
+    ///
+    /// Uses the default [`PolylineOptions`]. See
+    /// [`GenPolyline::generate_polyline_with_options`].
     fn generate_polyline(
         &self,
         boundary: CurveBoundary<Point<1>>,
         tolerance: Tolerance,
-    ) -> Vec<Point<1>>;
+    ) -> Vec<Point<1>> {
+        self.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions::default(),
+        )
+    }
+
+    /// # Generate a polyline within the provided boundary, using options
+    ///
+    /// The default implementation subdivides the curve until it is
+    /// approximated within the tolerance. See [`subdivide`].
+    fn generate_polyline_with_options(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+        options: PolylineOptions,
+    ) -> Vec<Point<1>> {
+        subdivide(self, boundary, tolerance, options)
+    }
+}
+
+/// # Options for [`GenPolyline::generate_polyline_with_options`]
+///
+/// These only affect curves that are approximated by generating points between
+/// the boundary points, like circles. The polyline of a line is fully defined
+/// by its boundary, which is always returned as-is.
+#[derive(Clone, Copy, Debug)]
+pub struct PolylineOptions {
+    /// # Include the end of the boundary in the polyline
+    ///
+    /// By default, neither point of the boundary is part of the polyline. This
+    /// is the right choice for callers that know the boundary anyway, and want
+    /// to build an open polyline from it.
+    ///
+    /// If this is `true`, the end of the boundary is appended, closing the
+    /// polyline. For a boundary that covers the full period of a closed curve,
+    /// this means the seam point shows up exactly once, at the end.
+    ///
+    /// Polylines that are generated with a custom
+    /// [`PolylineOptions::distribution`] always include both boundary points.
+    pub include_endpoint: bool,
+
+    /// # The minimum number of segments to approximate a full closed curve
+    ///
+    /// The number of segments is primarily determined by the tolerance. For
+    /// very small curves, or very coarse tolerances, that can result in an
+    /// approximation that doesn't look like the curve at all. This sets a
+    /// lower bound, while the tolerance can still add more segments.
+    ///
+    /// Defaults to `3`, the lowest number that results in an approximation
+    /// that encloses an area. Values lower than that are treated as `3`.
+    ///
+    /// Note that [`GenPolyline::line_segment_at`] always uses the default. If
+    /// a polyline is generated with a different value, it might not line up
+    /// with the line segments returned from there.
+    pub min_segments: usize,
+
+    /// # The maximum number of segments
+    ///
+    /// For closed curves, this refers to the full curve, like `min_segments`.
+    /// For curves that are approximated by [`subdivide`], it refers to the
+    /// segments within the boundary.
+    ///
+    /// This guards against tolerances that are unreasonably small for the
+    /// size of a curve, as well as against curve geometry that never meets the
+    /// tolerance, due to a bug. Either would otherwise result in a huge number
+    /// of segments, or in endless subdivision. If the limit is reached, the
+    /// polyline generated so far is returned, and a warning is emitted.
+    ///
+    /// Defaults to `2^20`. The minimum takes precedence, if it is larger.
+    /// Curve approximation takes this from
+    /// [`CurveApproxCache::with_max_segments`].
+    ///
+    /// [`CurveApproxCache::with_max_segments`]: crate::algorithms::approx::CurveApproxCache::with_max_segments
+    pub max_segments: usize,
+
+    /// # The distribution of points along the curve
+    ///
+    /// Defaults to [`ParamDistribution::Uniform`]. If a custom distribution is
+    /// used, `min_segments` and `max_segments` don't apply.
+    ///
+    /// Like for `min_segments`, a polyline that is generated with a custom
+    /// distribution does not line up with the line segments returned from
+    /// [`GenPolyline::line_segment_at`].
+    pub distribution: ParamDistribution,
+}
+
+impl Default for PolylineOptions {
+    fn default() -> Self {
+        Self {
+            include_endpoint: false,
+            min_segments: 3,
+            max_segments: 1 << 20,
+            distribution: ParamDistribution::Uniform,
+        }
+    }
+}
+
+/// # The distribution of points along a curve
+///
+/// See [`PolylineOptions::distribution`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ParamDistribution {
+    /// # Space the points uniformly, as close as the tolerance requires
+    #[default]
+    Uniform,
+
+    /// # Place the points at custom curve coordinates
+    ///
+    /// The function is called with the boundary, and returns the curve
+    /// coordinates of the points. This can be used to place more points where
+    /// the shape of the curve matters, like on the flanks of a gear tooth.
+    ///
+    /// This is a function pointer, not a closure, to keep [`PolylineOptions`]
+    /// `Copy`.
+    ///
+    /// Points that are not strictly within the boundary are ignored. Both
+    /// boundary points are always included, at the start and end of the
+    /// polyline, so it covers the full boundary.
+    /// [`PolylineOptions::include_endpoint`] has no effect.
+    Custom(fn(CurveBoundary<Point<1>>) -> Vec<Point<1>>),
+}
+
+/// # Generate a polyline by subdividing the curve
+///
+/// This is the generic driver behind the default implementation of
+/// [`GenPolyline::generate_polyline_with_options`]. It splits the curve into
+/// segments, and splits each segment in half, until the middle of the curve
+/// within every segment is no further than the tolerance from the middle of
+/// the segment.
+///
+/// The segments start out as ranges of unit length in curve coordinates, and
+/// are split the same way, regardless of the boundary. This makes the result
+/// deterministic (see [module documentation]), unless subdivision is stopped
+/// early.
+///
+/// Subdivision stops, once the number of segments within the boundary would
+/// exceed [`PolylineOptions::max_segments`], or once a segment is too short to
+/// be split any further. The polyline generated so far is returned, and a
+/// warning is emitted.
+///
+/// [module documentation]: self
+pub fn subdivide<const D: usize>(
+    curve: &(impl GenPolyline<D> + ?Sized),
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+    options: PolylineOptions,
+) -> Vec<Point<1>> {
+    let [min, max] = boundary.normalize().inner.map(|point| point.t);
+    let max_segments = options.max_segments.max(1);
+
+    let mut segments = Vec::new();
+    let mut start = min.floor();
+    while start < max && segments.len() < max_segments {
+        segments.push([start, start + 1.]);
+        start += 1.;
+    }
+
+    loop {
+        let mut subdivided = Vec::with_capacity(segments.len() * 2);
+        let mut is_converged = true;
+        let mut limit_reached = false;
+
+        for (i, &[a, b]) in segments.iter().enumerate() {
+            let middle = (a + b) / 2.;
+
+            let can_be_split = a < middle && middle < b;
+            let is_within_tolerance = {
+                let [a, b, middle] =
+                    [a, b, middle].map(|t| curve.point_at(Point::from([t])));
+                (middle - (a + (b - a) / 2.)).magnitude() <= tolerance.inner()
+            };
+            if is_within_tolerance {
+                subdivided.push([a, b]);
+                continue;
+            }
+
+            is_converged = false;
+
+            let num_remaining = segments.len() - i - 1;
+            if !can_be_split
+                || subdivided.len() + 2 + num_remaining > max_segments
+            {
+                limit_reached = true;
+                subdivided.push([a, b]);
+                continue;
+            }
+
+            subdivided.push([a, middle]);
+            subdivided.push([middle, b]);
+        }
+
+        segments = subdivided;
+
+        if limit_reached {
+            tracing::warn!(
+                "Curve approximation did not meet tolerance within {} \
+                segments. Stopping subdivision.",
+                segments.len(),
+            );
+            break;
+        }
+        if is_converged {
+            break;
+        }
+    }
+
+    let mut points = segments
+        .into_iter()
+        .map(|[start, _]| start)
+        .filter(|&t| min < t && t < max)
+        .map(|t| Point::from([t]))
+        .collect::<Vec<_>>();
+
+    let [start, end] = boundary.inner;
+    if start > end {
+        points.reverse();
+    }
+    if options.include_endpoint {
+        points.push(end);
+    }
+
+    points
+}
+
+/// # Check that the polyline of a curve doesn't depend on how it is queried
+///
+/// This is a helper for testing implementations of [`GenPolyline`], to make
+/// sure they honor the determinism requirement (see [module documentation]).
+/// It generates polylines for the provided boundary, its reverse, and several
+/// sub-ranges of it, in different orders, and checks that the points within
+/// each boundary are consistent with the points of the full polyline.
+///
+/// Only the points strictly within each boundary are compared, as whether the
+/// boundary points themselves are included is up to the implementation.
+///
+/// ## Panics
+///
+/// Panics, if any inconsistency is found.
+///
+/// [module documentation]: self
+pub fn assert_polyline_deterministic<const D: usize>(
+    curve: &impl GenPolyline<D>,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) {
+    let within = |boundary: CurveBoundary<Point<1>>, points: &[Point<1>]| {
+        let [min, max] = boundary.normalize().inner;
+        points
+            .iter()
+            .copied()
+            .filter(|point| min < *point && *point < max)
+            .collect::<Vec<_>>()
+    };
+
+    let full = curve.generate_polyline(boundary, tolerance);
+    assert_eq!(
+        full,
+        curve.generate_polyline(boundary, tolerance),
+        "Expected repeated queries to generate the same polyline",
+    );
+
+    let mut reversed = within(
+        boundary,
+        &curve.generate_polyline(boundary.reverse(), tolerance),
+    );
+    reversed.reverse();
+    assert_eq!(
+        within(boundary, &full),
+        reversed,
+        "Expected reversed boundary to generate the same points, in reverse",
+    );
+
+    for n in [2, 3, 5] {
+        // Query the sub-ranges back to front, to make sure the order doesn't
+        // matter.
+        for sub_boundary in boundary.subdivide(n).into_iter().rev() {
+            assert_eq!(
+                within(sub_boundary, &full),
+                within(
+                    sub_boundary,
+                    &curve.generate_polyline(sub_boundary, tolerance),
+                ),
+                "Expected polyline of sub-range `{sub_boundary:?}` to match \
+                polyline of full boundary",
+            );
+        }
+    }
 }
 
 // This implementation is temporary, to ease the transition towards a curve
@@ -62,10 +359,19 @@ impl<const D: usize> GenPolyline<D> for Path<D> {
     fn origin(&self) -> Point<D> {
         match self {
             Self::Circle(circle) => circle.origin(),
+            Self::Ellipse(ellipse) => ellipse.origin(),
             Self::Line(line) => line.origin(),
         }
     }
 
+    fn point_at(&self, point_curve: Point<1>) -> Point<D> {
+        match self {
+            Self::Circle(circle) => circle.point_at(point_curve),
+            Self::Ellipse(ellipse) => ellipse.point_at(point_curve),
+            Self::Line(line) => line.point_at(point_curve),
+        }
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
@@ -75,20 +381,147 @@ impl<const D: usize> GenPolyline<D> for Path<D> {
             Self::Circle(circle) => {
                 circle.line_segment_at(point_curve, tolerance)
             }
+            Self::Ellipse(ellipse) => {
+                ellipse.line_segment_at(point_curve, tolerance)
+            }
             Self::Line(line) => line.line_segment_at(point_curve, tolerance),
         }
     }
 
-    fn generate_polyline(
+    fn generate_polyline_with_options(
         &self,
         boundary: CurveBoundary<Point<1>>,
         tolerance: Tolerance,
+        options: PolylineOptions,
     ) -> Vec<Point<1>> {
         match self {
-            Self::Circle(circle) => {
-                circle.generate_polyline(boundary, tolerance)
-            }
-            Self::Line(line) => line.generate_polyline(boundary, tolerance),
+            Self::Circle(circle) => circle
+                .generate_polyline_with_options(boundary, tolerance, options),
+            Self::Ellipse(ellipse) => ellipse
+                .generate_polyline_with_options(boundary, tolerance, options),
+            Self::Line(line) => line
+                .generate_polyline_with_options(boundary, tolerance, options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Line, LineSegment, Point};
+
+    use crate::geometry::{CurveBoundary, Tolerance};
+
+    use super::{assert_polyline_deterministic, GenPolyline, PolylineOptions};
+
+    #[test]
+    fn polyline_of_circle_is_deterministic() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        assert_polyline_deterministic(
+            &circle,
+            CurveBoundary::from([[0.1], [5.3]]),
+            tolerance,
+        );
+        assert_polyline_deterministic(
+            &circle,
+            CurveBoundary::from([[-2.2], [0.7]]),
+            tolerance,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn polyline_of_line_is_deterministic() -> anyhow::Result<()> {
+        let line = Line::from_points([[0., 0., 0.], [1., 2., 3.]]).0;
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        assert_polyline_deterministic(
+            &line,
+            CurveBoundary::from([[0.], [1.]]),
+            tolerance,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn polyline_of_subdivided_curve_is_deterministic() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        assert_polyline_deterministic(
+            &Parabola,
+            CurveBoundary::from([[-1.3], [2.6]]),
+            tolerance,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn subdivision_stops_at_max_segments() -> anyhow::Result<()> {
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+        let tolerance = Tolerance::from_scalar(0.1)?;
+
+        let points = NeverConverges.generate_polyline_with_options(
+            boundary,
+            tolerance,
+            PolylineOptions {
+                include_endpoint: true,
+                max_segments: 100,
+                ..PolylineOptions::default()
+            },
+        );
+        assert_eq!(points.len(), 100);
+        assert_eq!(points.last(), Some(&Point::from([1.])));
+
+        Ok(())
+    }
+
+    /// A curve that is approximated by the default subdivision
+    struct Parabola;
+
+    impl GenPolyline<2> for Parabola {
+        fn origin(&self) -> Point<2> {
+            Point::origin()
+        }
+
+        fn point_at(&self, point_curve: Point<1>) -> Point<2> {
+            let t = point_curve.t;
+            Point::from([t, t * t])
+        }
+
+        fn line_segment_at(&self, _: Point<1>, _: Tolerance) -> LineSegment<2> {
+            unimplemented!()
+        }
+    }
+
+    /// A curve whose approximation never meets the tolerance
+    ///
+    /// Every time a segment is split in half, the point in the middle is
+    /// placed on the opposite side of the points that came before it.
+    struct NeverConverges;
+
+    impl GenPolyline<2> for NeverConverges {
+        fn origin(&self) -> Point<2> {
+            Point::origin()
+        }
+
+        fn point_at(&self, point_curve: Point<1>) -> Point<2> {
+            let t = point_curve.t.into_f64();
+
+            // The number of times the unit range has to be split in half, to
+            // reach this point.
+            let depth = (0..64)
+                .find(|&depth| (t * 2f64.powi(depth)).fract() == 0.)
+                .unwrap_or(64);
+
+            Point::from([t, f64::from(depth % 2)])
+        }
+
+        fn line_segment_at(&self, _: Point<1>, _: Tolerance) -> LineSegment<2> {
+            unimplemented!()
         }
     }
 }
@@ -1,11 +1,15 @@
 //! Layer infrastructure for [`Geometry`]
 
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Vector};
+
 use crate::{
     geometry::{
         CurveGeom2, Geometry, LocalCurveGeom, LocalVertexGeom, SurfaceGeom,
     },
     storage::Handle,
-    topology::{Curve, Surface, Vertex},
+    topology::{AnyObject, Curve, Stored, Surface, Vertex},
 };
 
 use super::{Command, Event, Layer};
@@ -61,6 +65,50 @@ impl Layer<Geometry> {
         self.process(DefineSurface { surface, geometry }, &mut events);
     }
 
+    /// # Define the provided surface as a plane with the given normal
+    ///
+    /// See [`SurfaceGeom::plane_from_origin_and_normal`] for how the u- and
+    /// v-axes of the plane are chosen.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the normal is zero.
+    ///
+    /// Panics, if the surface is a special pre-defined plane, like the basis
+    /// planes (xy-, xz-, or yz-plane).
+    pub fn define_plane_from_normal(
+        &mut self,
+        surface: Handle<Surface>,
+        origin: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+    ) {
+        self.define_surface(
+            surface,
+            SurfaceGeom::plane_from_origin_and_normal(origin, normal),
+        );
+    }
+
+    /// # Replace the geometry of the provided surface
+    ///
+    /// This is useful for editing a surface after it has been defined, for
+    /// example in response to a changed parameter. Anything that is defined
+    /// on the surface picks up the new geometry.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the surface is a special pre-defined plane, like the basis
+    /// planes (xy-, xz-, or yz-plane), or 2D space.
+    ///
+    /// Panics, if the geometry of the surface has not been defined yet.
+    pub fn redefine_surface(
+        &mut self,
+        surface: Handle<Surface>,
+        geometry: SurfaceGeom,
+    ) {
+        let mut events = Vec::new();
+        self.process(RedefineSurface { surface, geometry }, &mut events);
+    }
+
     /// Define the geometry of the provided vertex
     pub fn define_vertex(
         &mut self,
@@ -78,6 +126,28 @@ impl Layer<Geometry> {
             &mut events,
         );
     }
+
+    /// # Remove the geometry of objects that are no longer reachable
+    ///
+    /// Objects are never removed from the topology stores, so geometry that
+    /// was defined for objects that have since been replaced by an update
+    /// operation accumulates over time. This removes the geometry of all
+    /// curves, surfaces, and vertices that are not in the provided set, which
+    /// can be collected from the live objects using [`ReachableObjects`].
+    ///
+    /// Local definitions that refer to a removed surface or curve are removed
+    /// too. The basis planes, and definitions in 2D space, are always kept.
+    ///
+    /// [`ReachableObjects`]: crate::queries::ReachableObjects
+    pub fn prune(&mut self, reachable: &BTreeSet<AnyObject<Stored>>) {
+        let mut events = Vec::new();
+        self.process(
+            PruneGeometry {
+                reachable: reachable.clone(),
+            },
+            &mut events,
+        );
+    }
 }
 
 /// Define the geometry of a curve
@@ -169,6 +239,31 @@ impl Event<Geometry> for DefineSurface {
     }
 }
 
+/// Replace the geometry of a surface
+pub struct RedefineSurface {
+    surface: Handle<Surface>,
+    geometry: SurfaceGeom,
+}
+
+impl Command<Geometry> for RedefineSurface {
+    type Result = ();
+    type Event = Self;
+
+    fn decide(
+        self,
+        _: &Geometry,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        events.push(self);
+    }
+}
+
+impl Event<Geometry> for RedefineSurface {
+    fn evolve(&self, state: &mut Geometry) {
+        state.redefine_surface_inner(self.surface.clone(), self.geometry);
+    }
+}
+
 /// Define the geometry of a curve
 pub struct DefineVertex {
     vertex: Handle<Vertex>,
@@ -198,3 +293,167 @@ impl Event<Geometry> for DefineVertex {
         );
     }
 }
+
+/// Remove the geometry of objects that are no longer reachable
+pub struct PruneGeometry {
+    reachable: BTreeSet<AnyObject<Stored>>,
+}
+
+impl Command<Geometry> for PruneGeometry {
+    type Result = ();
+    type Event = Self;
+
+    fn decide(
+        self,
+        _: &Geometry,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        events.push(self);
+    }
+}
+
+impl Event<Geometry> for PruneGeometry {
+    fn evolve(&self, state: &mut Geometry) {
+        state.prune_inner(&self.reachable);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::triangulate::Triangulate,
+        fixtures,
+        geometry::{Path, SurfaceGeom},
+        operations::{build::BuildSketch, insert::Insert, sweep::SweepSketch},
+        queries::ReachableObjects,
+        topology::{Sketch, Solid, Surface},
+        Core,
+    };
+
+    #[test]
+    fn redefine_surface() {
+        let mut core = Core::new();
+
+        let surface = Surface::new().insert(&mut core);
+        core.layers.geometry.define_surface(
+            surface.clone(),
+            SurfaceGeom {
+                u: Path::x_axis(),
+                v: Vector::unit_y(),
+            },
+        );
+
+        let redefined = SurfaceGeom {
+            u: Path::x_axis(),
+            v: Vector::from([0., 1., 1.]),
+        };
+        core.layers
+            .geometry
+            .redefine_surface(surface.clone(), redefined);
+
+        assert_eq!(core.layers.geometry.of_surface(&surface), &redefined);
+    }
+
+    #[test]
+    fn define_plane_from_normal() {
+        let mut core = Core::new();
+
+        let normal = Vector::from([1., 2., 3.]);
+
+        let surface = Surface::new().insert(&mut core);
+        core.layers.geometry.define_plane_from_normal(
+            surface.clone(),
+            [1., 1., 1.],
+            normal,
+        );
+
+        let geometry = core.layers.geometry.of_surface(&surface);
+        let Path::Line(u) = geometry.u else {
+            unreachable!("Surface is a plane");
+        };
+
+        assert_eq!(geometry.origin(), Point::from([1., 1., 1.]));
+        assert_abs_diff_eq!(
+            u.direction().cross(&geometry.v),
+            normal.normalize(),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_eq!(
+            geometry,
+            &SurfaceGeom::plane_from_origin_and_normal([1., 1., 1.], normal),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn redefine_basis_plane() {
+        let mut core = Core::new();
+
+        let xy_plane = core.layers.topology.surfaces.xy_plane();
+        let geometry = *core.layers.geometry.xy_plane();
+        core.layers.geometry.redefine_surface(xy_plane, geometry);
+    }
+
+    #[test]
+    fn prune() {
+        fn build_cube(size: f64, core: &mut Core) -> Solid {
+            fixtures::cuboid([0., 0., -size], [size, size, 0.], core)
+        }
+
+        // The geometry of a single cube, without any previous versions.
+        let live_counts = {
+            let mut core = Core::new();
+            let cube = build_cube(1., &mut core);
+            core.layers.geometry.prune(&cube.reachable_objects());
+            core.layers.geometry.counts()
+        };
+
+        let mut core = Core::new();
+        let mut cube = build_cube(1., &mut core);
+        for i in 2..=10 {
+            cube = build_cube(i as f64, &mut core);
+        }
+
+        let counts = core.layers.geometry.counts();
+        assert!(counts.curves > live_counts.curves);
+        assert!(counts.surfaces > live_counts.surfaces);
+        assert!(counts.vertices > live_counts.vertices);
+
+        core.layers.geometry.prune(&cube.reachable_objects());
+        assert_eq!(core.layers.geometry.counts(), live_counts);
+
+        // The geometry of the live cube is still complete.
+        let tolerance = core.tolerance();
+        let mesh = (&cube, tolerance).triangulate(&mut core);
+        assert_eq!(mesh.triangles().count(), 12);
+
+        // The basis planes are always kept.
+        let _ = core.layers.geometry.xy_plane();
+        let _ = core.layers.geometry.xz_plane();
+        let _ = core.layers.geometry.yz_plane();
+    }
+
+    #[test]
+    fn prune_keeps_geometry_of_sketches() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::circle([0., 0.], 1., &mut core);
+        core.layers.geometry.prune(&sketch.reachable_objects());
+
+        let solid = sketch.sweep_sketch(
+            core.layers.topology.surfaces.xy_plane(),
+            Vector::from([0., 0., 1.]),
+            &mut core,
+        );
+        let tolerance = core.tolerance();
+        let mesh = (&solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    geometry::Geometry,
+    geometry::{Geometry, GeometryConfig},
     presentation::Presentation,
     topology::Topology,
     validation::{Validation, ValidationConfig},
@@ -46,8 +46,13 @@ pub struct Layers {
 impl Layers {
     /// Construct an instance of `Layers`
     pub fn new() -> Self {
+        Self::with_geometry_config(GeometryConfig::default())
+    }
+
+    /// Construct an instance of `Layers`, using the provided geometry config
+    pub fn with_geometry_config(config: GeometryConfig) -> Self {
         let topology = Topology::new();
-        let geometry = Geometry::new(&topology);
+        let geometry = Geometry::new(&topology, config);
 
         Self {
             topology: Layer::new(topology),
@@ -57,6 +62,25 @@ impl Layers {
         }
     }
 
+    /// Create a copy of this instance, that can be changed independently
+    ///
+    /// The topology stores are shared (see [`Topology::share`]). Since objects
+    /// are immutable, this doesn't allow one instance to affect the other.
+    /// Geometry and presentation data are copied (see [`Geometry::fork`]).
+    ///
+    /// The fork uses the same validation configuration, but starts without any
+    /// validation errors. Those stay with the original.
+    pub fn fork(&self) -> Self {
+        Self {
+            topology: Layer::new(self.topology.share()),
+            geometry: Layer::new(self.geometry.fork()),
+            validation: Layer::new(Validation::with_validation_config(
+                self.validation.config.clone(),
+            )),
+            presentation: Layer::new(Presentation::clone(&self.presentation)),
+        }
+    }
+
     /// Construct an instance of `Layers`, using the provided configuration
     pub fn with_validation_config(config: ValidationConfig) -> Self {
         Self {
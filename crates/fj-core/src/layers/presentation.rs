@@ -1,6 +1,6 @@
 //! Layer infrastructure for [`Presentation`]
 
-use fj_interop::Color;
+use fj_interop::{Color, MaterialId};
 
 use crate::{
     presentation::Presentation,
@@ -17,6 +17,16 @@ impl Layer<Presentation> {
         self.process(SetColor { region, color }, &mut events);
     }
 
+    /// Set the material of a region
+    pub fn set_material(
+        &mut self,
+        region: Handle<Region>,
+        material: MaterialId,
+    ) {
+        let mut events = Vec::new();
+        self.process(SetMaterial { region, material }, &mut events);
+    }
+
     /// Mark an object as being derived from another
     pub fn derive_object(
         &mut self,
@@ -56,6 +66,34 @@ impl Event<Presentation> for SetColor {
     }
 }
 
+/// Set the material of a region
+pub struct SetMaterial {
+    /// The region to set the material for
+    region: Handle<Region>,
+
+    /// The material to set
+    material: MaterialId,
+}
+
+impl Command<Presentation> for SetMaterial {
+    type Result = ();
+    type Event = Self;
+
+    fn decide(
+        self,
+        _: &Presentation,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        events.push(self);
+    }
+}
+
+impl Event<Presentation> for SetMaterial {
+    fn evolve(&self, state: &mut Presentation) {
+        state.material.insert(self.region.clone(), self.material);
+    }
+}
+
 /// Handle an object being derived from another
 pub struct DeriveObject {
     /// The original object
@@ -67,7 +105,7 @@ pub struct DeriveObject {
 
 impl Command<Presentation> for DeriveObject {
     type Result = ();
-    type Event = SetColor;
+    type Event = PresentationEvent;
 
     fn decide(
         self,
@@ -78,11 +116,17 @@ impl Command<Presentation> for DeriveObject {
             (self.original, self.derived)
         {
             if let Some(color) = state.color.get(&original).cloned() {
-                events.push(SetColor {
-                    region: derived,
+                events.push(PresentationEvent::SetColor {
+                    region: derived.clone(),
                     color,
                 });
             }
+            if let Some(material) = state.material.get(&original).cloned() {
+                events.push(PresentationEvent::SetMaterial {
+                    region: derived,
+                    material,
+                });
+            }
         }
     }
 }
@@ -98,4 +142,26 @@ pub enum PresentationEvent {
         /// The color being set
         color: Color,
     },
+
+    /// The material of a region is being set
+    SetMaterial {
+        /// The region the material is being set for
+        region: Handle<Region>,
+
+        /// The material being set
+        material: MaterialId,
+    },
+}
+
+impl Event<Presentation> for PresentationEvent {
+    fn evolve(&self, state: &mut Presentation) {
+        match self {
+            Self::SetColor { region, color } => {
+                state.color.insert(region.clone(), *color);
+            }
+            Self::SetMaterial { region, material } => {
+                state.material.insert(region.clone(), *material);
+            }
+        }
+    }
 }
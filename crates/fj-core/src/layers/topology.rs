@@ -49,6 +49,7 @@ impl Command<Topology> for InsertObject {
 
 impl Event<Topology> for InsertObject {
     fn evolve(&self, state: &mut Topology) {
-        self.object.clone().insert(state);
+        let object = self.object.clone().insert(state);
+        state.record_insertion(object);
     }
 }
@@ -2,17 +2,52 @@
 
 use crate::{
     geometry::Geometry,
-    topology::{AnyObject, Stored},
-    validation::{Validation, ValidationError, ValidationErrors},
+    topology::{AnyObject, Shell, Stored},
+    validation::{
+        checks::SweptShellIsInconsistent, Severity, Validation,
+        ValidationCheck, ValidationError, ValidationErrorCode,
+        ValidationErrors, ValidationIssues, ValidationSummary, WarningPolicy,
+    },
 };
 
 use super::{Command, Event, Layer};
 
 impl Layer<Validation> {
     /// Take all errors stored in the validation layer
+    ///
+    /// Whether warnings are taken too, depends on the warning policy from the
+    /// validation configuration. If warnings are acceptable, they are left in
+    /// place, and don't cause this method to return an error. They can be
+    /// taken using [`Layer::take_issues`].
     pub fn take_errors(&mut self) -> Result<(), ValidationErrors> {
         self.process(TakeErrors, &mut Vec::new())
     }
+
+    /// Take all errors and warnings stored in the validation layer
+    pub fn take_issues(&mut self) -> ValidationIssues {
+        self.process(TakeIssues, &mut Vec::new())
+    }
+
+    /// Validate a shell that was created by a sweep
+    ///
+    /// Runs the validation checks that only apply to swept shells, which
+    /// can't be identified as such by regular validation. See
+    /// [`SweptShellIsInconsistent`].
+    pub fn validate_swept_shell(&mut self, shell: &Shell, geometry: &Geometry) {
+        self.process(ValidateSweptShell { shell, geometry }, &mut Vec::new());
+    }
+
+    /// Summarize the errors stored in the validation layer
+    ///
+    /// Counts the stored errors by severity, without taking them. The summary
+    /// uses the warning policy from the validation configuration.
+    pub fn summary(&self) -> ValidationSummary {
+        ValidationSummary {
+            errors: self.errors.len(),
+            warnings: self.warnings.len(),
+            warning_policy: self.config.warning_policy,
+        }
+    }
 }
 
 /// Validate an object
@@ -26,23 +61,114 @@ pub struct ValidateObject<'r> {
 
 impl Command<Validation> for ValidateObject<'_> {
     type Result = ();
-    type Event = ValidationFailed;
+    type Event = ObjectValidated;
 
     fn decide(self, state: &Validation, events: &mut Vec<Self::Event>) {
         let mut errors = Vec::new();
+
+        #[cfg(not(feature = "validation-timings"))]
         self.object
             .validate(&state.config, &mut errors, self.geometry);
 
-        for err in errors {
-            if state.config.panic_on_error {
-                panic!("{:#?}", err);
-            }
+        #[cfg(feature = "validation-timings")]
+        {
+            let timings = self.object.validate_timed(
+                &state.config,
+                &mut errors,
+                self.geometry,
+            );
+            events.push(ObjectValidated::Timed(timings));
+        }
+
+        report_errors(errors, state, events);
+    }
+}
+
+/// An object was validated
+///
+/// Event produced by `Layer<Validation>`.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum ObjectValidated {
+    /// Validation of the object failed
+    Failed(ValidationFailed),
+
+    /// Time was spent in validation checks
+    ///
+    /// See [`Validation::timings`].
+    #[cfg(feature = "validation-timings")]
+    Timed(crate::validation::ValidationTimings),
+}
+
+impl From<ValidationFailed> for ObjectValidated {
+    fn from(failed: ValidationFailed) -> Self {
+        Self::Failed(failed)
+    }
+}
 
-            events.push(ValidationFailed { err });
+impl Event<Validation> for ObjectValidated {
+    fn evolve(&self, state: &mut Validation) {
+        match self {
+            Self::Failed(failed) => failed.evolve(state),
+            #[cfg(feature = "validation-timings")]
+            Self::Timed(timings) => state.timings.merge(timings),
         }
     }
 }
 
+/// Validate a shell that was created by a sweep
+///
+/// See [`Layer::validate_swept_shell`].
+pub struct ValidateSweptShell<'r> {
+    /// The shell to validate
+    pub shell: &'r Shell,
+
+    /// Reference to `Geometry`, which is required for validation
+    pub geometry: &'r Geometry,
+}
+
+impl Command<Validation> for ValidateSweptShell<'_> {
+    type Result = ();
+    type Event = ValidationFailed;
+
+    fn decide(self, state: &Validation, events: &mut Vec<Self::Event>) {
+        let code = ValidationErrorCode::SweptShellIsInconsistent;
+        if !state.config.error_codes.includes(code) {
+            return;
+        }
+
+        let errors = SweptShellIsInconsistent::check(
+            self.shell,
+            self.geometry,
+            &state.config,
+        )
+        .map(Into::into)
+        .collect();
+
+        report_errors(errors, state, events);
+    }
+}
+
+fn report_errors<E: From<ValidationFailed>>(
+    errors: Vec<ValidationError>,
+    state: &Validation,
+    events: &mut Vec<E>,
+) {
+    for err in errors {
+        if !state.config.error_codes.includes(err.code()) {
+            continue;
+        }
+
+        let severity = state.config.severity_of(&err);
+
+        if state.config.panic_on_error && severity == Severity::Error {
+            panic!("{:#?}", err);
+        }
+
+        events.push(ValidationFailed { err, severity }.into());
+    }
+}
+
 /// Take all errors stored in the validation layer
 ///
 /// Serves both as a command for and event produced by `Layer<Validation>`.
@@ -57,7 +183,10 @@ impl Command<Validation> for TakeErrors {
         state: &Validation,
         events: &mut Vec<Self::Event>,
     ) -> Self::Result {
-        let errors = ValidationErrors(state.errors.to_vec());
+        let mut errors = ValidationErrors(state.errors.to_vec());
+        if state.config.warning_policy == WarningPolicy::Deny {
+            errors.0.extend(state.warnings.iter().cloned());
+        }
 
         events.push(self);
 
@@ -72,6 +201,42 @@ impl Command<Validation> for TakeErrors {
 impl Event<Validation> for TakeErrors {
     fn evolve(&self, state: &mut Validation) {
         state.errors.clear();
+
+        if state.config.warning_policy == WarningPolicy::Deny {
+            state.warnings.clear();
+        }
+    }
+}
+
+/// Take all errors and warnings stored in the validation layer
+///
+/// Serves both as a command for and event produced by `Layer<Validation>`.
+pub struct TakeIssues;
+
+impl Command<Validation> for TakeIssues {
+    type Result = ValidationIssues;
+    type Event = Self;
+
+    fn decide(
+        self,
+        state: &Validation,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        let issues = ValidationIssues {
+            errors: state.errors.to_vec(),
+            warnings: state.warnings.to_vec(),
+        };
+
+        events.push(self);
+
+        issues
+    }
+}
+
+impl Event<Validation> for TakeIssues {
+    fn evolve(&self, state: &mut Validation) {
+        state.errors.clear();
+        state.warnings.clear();
     }
 }
 
@@ -82,10 +247,153 @@ impl Event<Validation> for TakeErrors {
 pub struct ValidationFailed {
     /// The validation error
     pub err: ValidationError,
+
+    /// The severity of the validation error
+    pub severity: Severity,
 }
 
 impl Event<Validation> for ValidationFailed {
     fn evolve(&self, state: &mut Validation) {
-        state.errors.push(self.err.clone());
+        let errors = match self.severity {
+            Severity::Error => &mut state.errors,
+            Severity::Warning => &mut state.warnings,
+        };
+
+        errors.push(self.err.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            build::{BuildCycle, BuildFace, BuildShell},
+            insert::Insert,
+            update::{UpdateFace, UpdateRegion, UpdateShell},
+        },
+        topology::{Cycle, Face, Shell},
+        validation::{
+            ValidationConfig, ValidationErrorCode, ValidationErrorCodeFilter,
+            WarningPolicy,
+        },
+        Core,
+    };
+
+    #[test]
+    fn deny_error_code() {
+        let mut core = Core::with_validation_config(ValidationConfig {
+            error_codes: ValidationErrorCodeFilter::Deny(
+                [ValidationErrorCode::HalfEdgeHasNoSibling].into(),
+            ),
+            ..ValidationConfig::default()
+        });
+
+        // Has half-edges without siblings.
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let _ = tetrahedron
+            .shell
+            .remove_face(&tetrahedron.abc.face)
+            .insert(&mut core);
+
+        // Has no boundary.
+        let _ = Face::circle(
+            core.layers.topology.surfaces.xy_plane(),
+            [0., 0.],
+            1.,
+            &mut core,
+        )
+        .update_region(
+            |region, core| region.update_exterior(|_, _| Cycle::empty(), core),
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let codes = core
+            .layers
+            .validation
+            .take_errors()
+            .expect_err("Expected validation errors")
+            .0
+            .into_iter()
+            .map(|err| err.code())
+            .collect::<Vec<_>>();
+
+        assert!(codes.contains(&ValidationErrorCode::FaceHasNoBoundary));
+        assert!(!codes.contains(&ValidationErrorCode::HalfEdgeHasNoSibling));
+    }
+
+    #[test]
+    fn warnings_only() {
+        let mut core = Core::with_validation_config(ValidationConfig {
+            warning_codes: [ValidationErrorCode::HalfEdgeHasNoSibling].into(),
+            ..ValidationConfig::default()
+        });
+
+        // Has half-edges without siblings.
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let _ = tetrahedron
+            .shell
+            .remove_face(&tetrahedron.abc.face)
+            .insert(&mut core);
+
+        assert!(core.layers.validation.take_errors().is_ok());
+
+        let issues = core.layers.validation.take_issues();
+        assert!(issues.errors.is_empty());
+        assert!(!issues.warnings.is_empty());
+        assert!(
+            issues
+                .warnings
+                .iter()
+                .all(|err| err.code()
+                    == ValidationErrorCode::HalfEdgeHasNoSibling)
+        );
+    }
+
+    #[test]
+    fn deny_warnings() {
+        let mut core = Core::with_validation_config(ValidationConfig {
+            warning_codes: [ValidationErrorCode::HalfEdgeHasNoSibling].into(),
+            warning_policy: WarningPolicy::Deny,
+            ..ValidationConfig::default()
+        });
+
+        // Has half-edges without siblings.
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let _ = tetrahedron
+            .shell
+            .remove_face(&tetrahedron.abc.face)
+            .insert(&mut core);
+
+        assert!(core.layers.validation.take_errors().is_err());
+        assert!(core.layers.validation.take_issues().warnings.is_empty());
+    }
+
+    #[cfg(feature = "validation-timings")]
+    #[test]
+    fn timings_are_recorded_for_validated_objects() {
+        let mut core = Core::new();
+
+        let _ = Face::circle(
+            core.layers.topology.surfaces.xy_plane(),
+            [0., 0.],
+            1.,
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let check = std::any::type_name::<
+            crate::validation::checks::FaceHasNoBoundary,
+        >();
+        assert!(core.layers.validation.timings.get(check).is_some());
     }
 }
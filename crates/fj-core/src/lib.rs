@@ -106,6 +106,8 @@
 #![allow(clippy::mutable_key_type)]
 
 pub mod algorithms;
+pub mod attributes;
+pub mod construction;
 pub mod geometry;
 pub mod layers;
 pub mod operations;
@@ -117,5 +119,7 @@ pub mod validate;
 pub mod validation;
 
 mod core;
+#[cfg(test)]
+mod fixtures;
 
-pub use self::core::Core;
+pub use self::core::{Core, CoreStats};
@@ -0,0 +1,641 @@
+//! # Boolean operations on solids
+//!
+//! See [`IntersectSolids`].
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{face::approx_face, ApproxCache},
+        cancel::{CancelToken, Cancelled},
+    },
+    geometry::{Geometry, Path, Tolerance},
+    operations::build::{BuildError, BuildSolid},
+    storage::Handle,
+    topology::{Solid, Surface},
+    Core,
+};
+
+/// Compute the intersection of two [`Solid`]s
+///
+/// Not to be confused with [`Intersect`], which computes intersections between
+/// geometric primitives.
+///
+/// [`Intersect`]: crate::algorithms::intersect::Intersect
+pub trait IntersectSolids {
+    /// Compute the volume that this solid and another have in common
+    ///
+    /// Returns an empty solid, if the solids don't overlap, or only touch.
+    ///
+    /// Uses a [`BooleanConfig`] that is derived from the tolerance of `core`.
+    /// See [`IntersectSolids::intersect_with_config`].
+    ///
+    /// ## Limitations
+    ///
+    /// Only convex solids that are bounded by planar faces are supported. For
+    /// any other input, this returns [`BooleanError::NotConvex`] or
+    /// [`BooleanError::NonPlanarFace`]. Solids with more than one shell, and
+    /// faces with holes, are considered not convex.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The intersection is computed by clipping the faces of one solid against
+    /// the planes of the other's faces, and closing the resulting holes with
+    /// new faces.
+    fn intersect(
+        &self,
+        other: &Solid,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        let config = BooleanConfig::from_tolerance(core.tolerance());
+        self.intersect_with_config(other, &config, core)
+    }
+
+    /// Compute the intersection, using the provided configuration
+    ///
+    /// Works like [`IntersectSolids::intersect`], but uses the provided
+    /// configuration instead of the default one.
+    ///
+    /// Checks [`BooleanConfig::cancel`] between steps, and returns
+    /// [`BooleanError::Cancelled`], if cancellation has been requested.
+    fn intersect_with_config(
+        &self,
+        other: &Solid,
+        config: &BooleanConfig,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError>;
+}
+
+impl IntersectSolids for Solid {
+    fn intersect_with_config(
+        &self,
+        other: &Solid,
+        config: &BooleanConfig,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        let (Some(a), Some(b)) = (
+            ConvexPolyhedron::from_solid(self, config, core)?,
+            ConvexPolyhedron::from_solid(other, config, core)?,
+        ) else {
+            return Ok(Solid::empty());
+        };
+
+        let mut polygons = a.polygons;
+        for plane in &b.planes {
+            config.cancel.check()?;
+            polygons = clip(polygons, plane, config);
+        }
+        config.cancel.check()?;
+
+        let tolerance = config.tolerance;
+        let polygons = weld(polygons, tolerance);
+        if volume(&polygons) <= tolerance * tolerance * tolerance {
+            return Ok(Solid::empty());
+        }
+
+        let mut faces = polygons
+            .iter()
+            .map(|polygon| {
+                let mut points = polygon.clone();
+                points.sort();
+                points
+            })
+            .collect::<Vec<_>>();
+        faces.sort();
+        if faces.windows(2).any(|faces| faces[0] == faces[1]) {
+            return Err(BooleanError::CoincidentFaces);
+        }
+
+        Ok(Solid::from_faces(&polygons, core)?)
+    }
+}
+
+/// Configuration for boolean operations
+///
+/// The tolerances used by boolean operations are independent of the tolerance
+/// of the [`Core`], as robust results often require different values. This
+/// includes the approximation of the input solids, which uses
+/// [`BooleanConfig::tolerance`].
+#[derive(Clone, Debug)]
+pub struct BooleanConfig {
+    /// The maximum distance of a point from a surface, to be considered on it
+    ///
+    /// Points that are closer to each other than this are merged.
+    pub tolerance: Scalar,
+
+    /// The maximum distance between two planar faces, to be considered
+    /// coincident
+    ///
+    /// Coincident faces are merged into one. This value should not be smaller
+    /// than [`BooleanConfig::tolerance`]. If faces are closer to each other
+    /// than `tolerance`, but further apart than this value, the result would
+    /// contain both of them, which is reported as
+    /// [`BooleanError::CoincidentFaces`].
+    pub coplanar_tolerance: Scalar,
+
+    /// A token that can be used to cancel the operation
+    ///
+    /// Boolean operations check this token between steps. By default, a token
+    /// is used that is never cancelled.
+    pub cancel: CancelToken,
+}
+
+impl BooleanConfig {
+    /// Compute a boolean config from a tolerance value
+    ///
+    /// Uses the same value for both tolerances.
+    pub fn from_tolerance(tolerance: impl Into<Tolerance>) -> Self {
+        let tolerance = tolerance.into().inner();
+
+        Self {
+            tolerance,
+            coplanar_tolerance: tolerance,
+            cancel: CancelToken::new(),
+        }
+    }
+}
+
+impl Default for BooleanConfig {
+    fn default() -> Self {
+        Self::from_tolerance(0.001)
+    }
+}
+
+/// Error computing a boolean operation
+///
+/// Returned by [`IntersectSolids::intersect`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BooleanError {
+    /// A face of an input solid is not planar
+    #[error("Boolean operations only support planar faces")]
+    NonPlanarFace,
+
+    /// An input solid is not convex
+    #[error("Boolean operations only support convex solids")]
+    NotConvex,
+
+    /// The result contains the same face twice
+    ///
+    /// See [`BooleanConfig::coplanar_tolerance`].
+    #[error("Result of boolean operation contains coincident faces")]
+    CoincidentFaces,
+
+    /// The result could not be built
+    #[error("Failed to build result of boolean operation")]
+    Build(#[from] BuildError),
+
+    /// The operation was cancelled
+    ///
+    /// See [`BooleanConfig::cancel`].
+    #[error(transparent)]
+    Cancelled(#[from] Cancelled),
+}
+
+struct ConvexPolyhedron {
+    polygons: Vec<Vec<Point<3>>>,
+    planes: Vec<Plane>,
+}
+
+impl ConvexPolyhedron {
+    /// Extract the faces of the solid as polygons
+    ///
+    /// Returns `None`, if the solid is empty.
+    fn from_solid(
+        solid: &Solid,
+        config: &BooleanConfig,
+        core: &Core,
+    ) -> Result<Option<Self>, BooleanError> {
+        let geometry = &core.layers.geometry;
+
+        let shell = match solid.shells().len() {
+            0 => return Ok(None),
+            1 => solid.shells().only(),
+            _ => return Err(BooleanError::NotConvex),
+        };
+
+        let mut cache = ApproxCache::default();
+        let mut polygons = Vec::new();
+
+        for face in shell.faces() {
+            config.cancel.check()?;
+
+            if !is_plane(face.surface(), geometry) {
+                return Err(BooleanError::NonPlanarFace);
+            }
+            if !face.region().interiors().is_empty() {
+                return Err(BooleanError::NotConvex);
+            }
+
+            let approx = approx_face(
+                face.clone(),
+                config.tolerance,
+                &mut cache,
+                geometry,
+            );
+            let mut points = approx
+                .exterior
+                .points()
+                .into_iter()
+                .map(|point| point.global_form)
+                .collect::<Vec<_>>();
+
+            // The approximation is closed, meaning the first point is repeated
+            // at the end.
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+
+            polygons.push(points);
+        }
+
+        let vertices = polygons.iter().flatten().copied().collect::<Vec<_>>();
+        let center = centroid(&vertices);
+
+        // Faces that are defined on the back side of their surface are wound
+        // the other way. Orient all polygons, so they are counter-clockwise
+        // when viewed from outside, which is the direction away from the
+        // center of a convex solid.
+        let mut planes = Vec::new();
+        for polygon in &mut polygons {
+            let origin = centroid(polygon);
+            let mut normal = newell_normal(polygon);
+
+            if normal.dot(&(origin - center)) < Scalar::ZERO {
+                polygon.reverse();
+                normal = -normal;
+            }
+
+            planes.push(Plane {
+                origin,
+                normal: normal.normalize(),
+            });
+        }
+
+        for plane in &planes {
+            if vertices
+                .iter()
+                .any(|&vertex| plane.distance_to(vertex) > config.tolerance)
+            {
+                return Err(BooleanError::NotConvex);
+            }
+        }
+
+        Ok(Some(Self { polygons, planes }))
+    }
+}
+
+struct Plane {
+    origin: Point<3>,
+    normal: Vector<3>,
+}
+
+impl Plane {
+    /// The signed distance of the point from the plane
+    ///
+    /// Positive on the outside, where the normal points.
+    fn distance_to(&self, point: Point<3>) -> Scalar {
+        (point - self.origin).dot(&self.normal)
+    }
+}
+
+fn is_plane(surface: &Handle<Surface>, geometry: &Geometry) -> bool {
+    matches!(geometry.of_surface(surface).u, Path::Line(_))
+}
+
+/// Clip the polygons against the plane, keeping what's on the inside
+///
+/// Closes the resulting hole with a new polygon in the plane.
+fn clip(
+    polygons: Vec<Vec<Point<3>>>,
+    plane: &Plane,
+    config: &BooleanConfig,
+) -> Vec<Vec<Point<3>>> {
+    let tolerance = config.tolerance;
+
+    let mut clipped = Vec::new();
+    let mut section = Vec::new();
+    let mut has_coplanar_polygon = false;
+
+    for polygon in polygons {
+        let distances = polygon
+            .iter()
+            .map(|&point| plane.distance_to(point))
+            .collect::<Vec<_>>();
+
+        let is_coplanar = distances
+            .iter()
+            .all(|distance| distance.abs() <= config.coplanar_tolerance);
+        if is_coplanar {
+            // A polygon that faces the other way touches the other solid
+            // from the outside. It doesn't bound the intersection.
+            if newell_normal(&polygon).dot(&plane.normal) > Scalar::ZERO {
+                has_coplanar_polygon = true;
+                clipped.push(polygon);
+            }
+            continue;
+        }
+
+        let mut points = Vec::new();
+        for i in 0..polygon.len() {
+            let j = (i + 1) % polygon.len();
+            let [a, b] = [polygon[i], polygon[j]];
+            let [da, db] = [distances[i], distances[j]];
+
+            if da <= tolerance {
+                points.push(a);
+            }
+            if da.abs() <= tolerance {
+                section.push(a);
+            }
+
+            let crosses = (da < -tolerance && db > tolerance)
+                || (da > tolerance && db < -tolerance);
+            if crosses {
+                // Each edge is shared by two polygons, which traverse it in
+                // opposite directions. Compute the intersection in a canonical
+                // order, so both get the same result.
+                let [(a, da), (b, db)] = if a < b {
+                    [(a, da), (b, db)]
+                } else {
+                    [(b, db), (a, da)]
+                };
+                let point = a + (b - a) * (da / (da - db));
+
+                points.push(point);
+                section.push(point);
+            }
+        }
+
+        if points.len() >= 3 {
+            clipped.push(points);
+        }
+    }
+
+    if !has_coplanar_polygon {
+        if let Some(cap) = cap(section, plane, tolerance) {
+            clipped.push(cap);
+        }
+    }
+
+    clipped
+}
+
+/// Build a polygon from points in the plane
+///
+/// The polygon is counter-clockwise, when viewed from the direction that the
+/// plane normal points to.
+fn cap(
+    points: Vec<Point<3>>,
+    plane: &Plane,
+    tolerance: Scalar,
+) -> Option<Vec<Point<3>>> {
+    let mut unique: Vec<Point<3>> = Vec::new();
+    for point in points {
+        if unique
+            .iter()
+            .all(|other| other.distance_to(&point) > tolerance)
+        {
+            unique.push(point);
+        }
+    }
+    if unique.len() < 3 {
+        return None;
+    }
+
+    let center = centroid(&unique);
+    let u = (unique[0] - center).normalize();
+    let v = plane.normal.cross(&u);
+
+    let angle = |point: &Point<3>| {
+        let offset = point - center;
+        Scalar::atan2(offset.dot(&v), offset.dot(&u))
+    };
+    unique.sort_by_key(angle);
+
+    Some(unique)
+}
+
+/// Merge points that are within the tolerance of each other
+///
+/// Polygons that end up with less than three points are removed.
+fn weld(polygons: Vec<Vec<Point<3>>>, tolerance: Scalar) -> Vec<Vec<Point<3>>> {
+    let mut unique: Vec<Point<3>> = Vec::new();
+
+    polygons
+        .into_iter()
+        .filter_map(|polygon| {
+            let mut welded: Vec<Point<3>> = Vec::new();
+
+            for point in polygon {
+                let point = match unique
+                    .iter()
+                    .find(|other| other.distance_to(&point) <= tolerance)
+                {
+                    Some(other) => *other,
+                    None => {
+                        unique.push(point);
+                        point
+                    }
+                };
+
+                if welded.last() != Some(&point) {
+                    welded.push(point);
+                }
+            }
+            if welded.len() > 1 && welded.first() == welded.last() {
+                welded.pop();
+            }
+
+            (welded.len() >= 3).then_some(welded)
+        })
+        .collect()
+}
+
+/// Compute the volume enclosed by the polygons
+fn volume(polygons: &[Vec<Point<3>>]) -> Scalar {
+    let mut volume = Scalar::ZERO;
+
+    for polygon in polygons {
+        let [origin, rest @ ..] = polygon.as_slice() else {
+            continue;
+        };
+
+        for points in rest.windows(2) {
+            let [a, b] = [points[0], points[1]].map(|point| point.coords);
+            volume += origin.coords.dot(&a.cross(&b));
+        }
+    }
+
+    volume / 6.
+}
+
+fn centroid(points: &[Point<3>]) -> Point<3> {
+    let sum = points
+        .iter()
+        .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point.coords);
+    Point {
+        coords: sum / Scalar::from_u64(points.len() as u64),
+    }
+}
+
+/// Compute the normal of a polygon, using Newell's method
+fn newell_normal(polygon: &[Point<3>]) -> Vector<3> {
+    let Some(&origin) = polygon.first() else {
+        return Vector::from([0., 0., 0.]);
+    };
+
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .fold(Vector::from([0., 0., 0.]), |normal, (a, b)| {
+            normal + (a - origin).cross(&(b - origin))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{approx::Approx, cancel::Cancelled},
+        fixtures,
+        operations::build::BuildRegion,
+        topology::Region,
+        validate::Validate,
+        Core,
+    };
+
+    use super::{BooleanConfig, BooleanError, IntersectSolids};
+
+    #[test]
+    fn overlapping_boxes() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = fixtures::cuboid([0., 0., -2.], [2., 2., 0.], &mut core);
+        let b = fixtures::cuboid([1., 1., -1.], [3., 3., 1.], &mut core);
+
+        let intersection = a.intersect(&b, &mut core)?;
+        intersection.validate_and_return_first_error(&core.layers.geometry)?;
+
+        let shell = intersection.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        let vertices = (&intersection)
+            .approx(core.tolerance(), &core.layers.geometry)
+            .iter()
+            .flat_map(|face| face.points())
+            .map(|point| point.global_form)
+            .collect::<BTreeSet<_>>();
+        let expected = cuboid_corners([1., 1., -1.], [2., 2., 0.])
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(vertices, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn disjoint_boxes() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = fixtures::cuboid([0., 0., 0.], [1., 1., 1.], &mut core);
+        let b = fixtures::cuboid([2., 0., 0.], [3., 1., 1.], &mut core);
+        let touching = fixtures::cuboid([1., 0., 0.], [2., 1., 1.], &mut core);
+
+        assert!(a.intersect(&b, &mut core)?.shells().is_empty());
+        assert!(a.intersect(&touching, &mut core)?.shells().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn boxes_with_coplanar_faces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The top faces of the boxes are almost, but not exactly, coplanar.
+        let a = fixtures::cuboid([0., 0., 0.], [1., 1., 1.], &mut core);
+        let b =
+            fixtures::cuboid([-1., -1., 0.5], [2., 2., 1. + 1e-7], &mut core);
+
+        let merged = a.intersect_with_config(
+            &b,
+            &BooleanConfig {
+                tolerance: Scalar::from(1e-6),
+                coplanar_tolerance: Scalar::from(1e-6),
+                ..BooleanConfig::default()
+            },
+            &mut core,
+        )?;
+        merged.validate_and_return_first_error(&core.layers.geometry)?;
+        assert_eq!(merged.shells().only().faces().len(), 6);
+
+        let duplicated = a.intersect_with_config(
+            &b,
+            &BooleanConfig {
+                tolerance: Scalar::from(1e-6),
+                coplanar_tolerance: Scalar::from(1e-9),
+                ..BooleanConfig::default()
+            },
+            &mut core,
+        );
+        assert_eq!(duplicated.map(|_| ()), Err(BooleanError::CoincidentFaces));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_solids() {
+        let mut core = Core::new();
+
+        let cube = fixtures::cuboid([0., 0., -1.], [1., 1., 0.], &mut core);
+
+        let cylinder = fixtures::cylinder(0.5, 1., &mut core);
+        let result = cube.intersect(&cylinder, &mut core);
+        assert_eq!(result.map(|_| ()), Err(BooleanError::NonPlanarFace));
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let l_shape = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            surface,
+            &mut core,
+        );
+        let l_shape = fixtures::sweep_down(l_shape, 0., 1., &mut core);
+        let result = cube.intersect(&l_shape, &mut core);
+        assert_eq!(result.map(|_| ()), Err(BooleanError::NotConvex));
+    }
+
+    #[test]
+    fn cancelled() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = fixtures::cuboid([0., 0., 0.], [2., 2., 2.], &mut core);
+        let b = fixtures::cuboid([1., 1., 1.], [3., 3., 3.], &mut core);
+
+        let config = BooleanConfig::from_tolerance(core.tolerance());
+        config.cancel.cancel();
+
+        let result = a.intersect_with_config(&b, &config, &mut core);
+        assert_eq!(result.map(|_| ()), Err(BooleanError::Cancelled(Cancelled)));
+
+        Ok(())
+    }
+
+    fn cuboid_corners(min: [f64; 3], max: [f64; 3]) -> [Point<3>; 8] {
+        let [x0, y0, z0] = min;
+        let [x1, y1, z1] = max;
+
+        [
+            [x0, y0, z0],
+            [x1, y0, z0],
+            [x1, y1, z0],
+            [x0, y1, z0],
+            [x0, y0, z1],
+            [x1, y0, z1],
+            [x1, y1, z1],
+            [x0, y1, z1],
+        ]
+        .map(Point::from)
+    }
+}
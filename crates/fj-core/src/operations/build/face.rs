@@ -39,12 +39,18 @@ pub trait BuildFace {
     }
 
     /// Build a triangle
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the points are collinear. See
+    /// [`BuildSurface::plane_from_points`].
     fn triangle(
         points: [impl Into<Point<3>>; 3],
         core: &mut Core,
     ) -> Polygon<3> {
         let (surface, points_surface) =
-            Surface::plane_from_points(points, core);
+            Surface::plane_from_points(points, core)
+                .expect("Can't build triangle from collinear points");
 
         let face = Face::polygon(surface, points_surface, core);
 
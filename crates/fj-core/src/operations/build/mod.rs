@@ -26,6 +26,7 @@ mod shell;
 mod sketch;
 mod solid;
 mod surface;
+mod wire;
 
 pub use self::{
     curve::BuildCurve,
@@ -33,8 +34,9 @@ pub use self::{
     face::{BuildFace, Polygon},
     half_edge::BuildHalfEdge,
     region::BuildRegion,
-    shell::{BuildShell, TetrahedronShell},
+    shell::{BuildShell, ShellBuilder, TetrahedronShell},
     sketch::BuildSketch,
-    solid::{BuildSolid, Tetrahedron},
+    solid::{BuildError, BuildSolid, Tetrahedron},
     surface::BuildSurface,
+    wire::BuildWire,
 };
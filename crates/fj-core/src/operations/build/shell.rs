@@ -1,12 +1,12 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use fj_interop::ext::ArrayExt;
-use fj_math::Point;
+use fj_math::{Point, Vector};
 
 use crate::{
-    geometry::{CurveBoundary, LocalVertexGeom},
+    geometry::{CurveBoundary, LocalVertexGeom, Path, Tolerance},
     operations::{
-        build::{BuildFace, BuildHalfEdge, BuildSurface, Polygon},
+        build::{BuildError, BuildFace, BuildHalfEdge, BuildSurface, Polygon},
         geometry::UpdateCurveGeometry,
         insert::{Insert, IsInserted, IsInsertedNo, IsInsertedYes},
         join::JoinCycle,
@@ -15,6 +15,7 @@ use crate::{
             UpdateCycle, UpdateFace, UpdateHalfEdge, UpdateRegion, UpdateShell,
         },
     },
+    storage::Handle,
     topology::{Curve, Face, HalfEdge, Shell, Surface, Vertex},
     Core,
 };
@@ -31,6 +32,10 @@ pub trait BuildShell {
     }
 
     /// Build a polyhedron by specifying its vertices and indices
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the vertices of any triangle are collinear.
     fn from_vertices_and_indices(
         vertices: impl IntoIterator<Item = impl Into<Point<3>>>,
         indices: impl IntoIterator<Item = [usize; 3]>,
@@ -58,7 +63,8 @@ pub trait BuildShell {
                 let (surface, _) = Surface::plane_from_points(
                     [a_pos, b_pos, c_pos].map(Clone::clone),
                     core,
-                );
+                )
+                .expect("Can't build triangle from collinear points");
 
                 let curves_and_boundaries =
                     [[a, b], [b, c], [c, a]].map(|vertices| {
@@ -398,3 +404,293 @@ pub struct TetrahedronShell<I: IsInserted = IsInsertedNo> {
     /// The face formed by the points `c`, `b`, and `d`.
     pub cbd: Polygon<3, IsInsertedYes>,
 }
+
+/// Build a [`Shell`] face by face
+///
+/// Each face is provided as a loop of points. Points of different faces that
+/// are within the tolerance of each other are welded into a single vertex, and
+/// edges between the same welded vertices are shared between faces. This
+/// makes sure, that adjacent faces share their topology, without having to
+/// keep track of vertices and edges manually.
+///
+/// See [`BuildSolid::from_faces`], for building a closed solid in one go.
+///
+/// The builder can be cloned, to restore an earlier state, if a series of
+/// faces must be added together or not at all.
+///
+/// [`BuildSolid::from_faces`]: super::BuildSolid::from_faces
+#[derive(Clone)]
+pub struct ShellBuilder {
+    tolerance: Tolerance,
+    vertices: Vec<(Point<3>, Handle<Vertex>)>,
+    edges: BTreeMap<CurveBoundary<Vertex>, Handle<Curve>>,
+    shared_edges: BTreeSet<CurveBoundary<Vertex>>,
+    faces: Vec<Face>,
+}
+
+impl ShellBuilder {
+    /// Construct a builder that welds points within the provided tolerance
+    pub fn new(tolerance: impl Into<Tolerance>) -> Self {
+        Self {
+            tolerance: tolerance.into(),
+            vertices: Vec::new(),
+            edges: BTreeMap::new(),
+            shared_edges: BTreeSet::new(),
+            faces: Vec::new(),
+        }
+    }
+
+    /// Add a planar face, defined by a loop of points
+    ///
+    /// The points must be in counter-clockwise order, when viewed from the
+    /// front side of the face.
+    ///
+    /// Returns an error, if the face is degenerate or not planar, or if one of
+    /// its edges can't be shared with the edge it coincides with. The latter is
+    /// the case, if the existing edge runs in the same direction, or if it is
+    /// already shared by two faces. If an error is returned, the builder is left
+    /// unchanged.
+    pub fn add_face(
+        &mut self,
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+        core: &mut Core,
+    ) -> Result<&mut Self, BuildError> {
+        let index = self.faces.len();
+        let tolerance = self.tolerance.inner();
+
+        let mut new_vertices = Vec::new();
+        let mut face_vertices = Vec::new();
+        for point in points {
+            let point = point.into();
+
+            let existing = self
+                .vertices
+                .iter()
+                .chain(&new_vertices)
+                .find(|(position, _)| position.distance_to(&point) <= tolerance)
+                .cloned();
+            let vertex = existing.unwrap_or_else(|| {
+                let vertex = (point, Vertex::new().insert(core));
+                new_vertices.push(vertex.clone());
+                vertex
+            });
+
+            face_vertices.push(vertex);
+        }
+
+        let num_points = face_vertices.len();
+        let (points, vertices): (Vec<_>, Vec<_>) =
+            face_vertices.into_iter().unzip();
+
+        let [origin, next, ..] = points.as_slice() else {
+            return Err(BuildError::DegenerateFace { index });
+        };
+        let origin = *origin;
+
+        // Newell's method, which works for non-convex polygons too.
+        let normal = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .fold(Vector::from([0., 0., 0.]), |normal, (a, b)| {
+                normal + (a - origin).cross(&(b - origin))
+            });
+        let u = *next - origin;
+        if num_points < 3
+            || normal.magnitude().is_zero()
+            || u.magnitude().is_zero()
+        {
+            return Err(BuildError::DegenerateFace { index });
+        }
+
+        let normal = normal.normalize();
+        let u = u.normalize();
+        let v = normal.cross(&u);
+
+        let mut points_surface = Vec::new();
+        for point in &points {
+            let offset = point - origin;
+            if offset.dot(&normal).abs() > tolerance {
+                return Err(BuildError::NonPlanarFace { index });
+            }
+
+            points_surface.push(Point::from([offset.dot(&u), offset.dot(&v)]));
+        }
+
+        let edges = (0..num_points)
+            .map(|i| {
+                let j = (i + 1) % num_points;
+                CurveBoundary::<Vertex>::from([
+                    vertices[i].clone(),
+                    vertices[j].clone(),
+                ])
+            })
+            .collect::<Vec<_>>();
+        for edge in &edges {
+            let reversed = edge.clone().reverse();
+
+            if edge.inner[0] == edge.inner[1] {
+                return Err(BuildError::DegenerateFace { index });
+            }
+            if self.edges.contains_key(edge)
+                || self.shared_edges.contains(&reversed)
+            {
+                return Err(BuildError::MismatchedEdge { index });
+            }
+        }
+
+        self.vertices.extend(new_vertices);
+
+        let (u, _) = Path::line_from_points([origin, origin + u]);
+        let surface = Surface::from_uv(u, v, core);
+
+        let half_edges = edges
+            .into_iter()
+            .enumerate()
+            .map(|(i, edge)| {
+                let j = (i + 1) % num_points;
+
+                // The first half-edge on an edge creates the curve. Its sibling
+                // reuses it, with the boundary reversed.
+                let reversed = edge.clone().reverse();
+                let (curve, boundary) = match self.edges.get(&reversed) {
+                    Some(curve) => {
+                        let curve = curve.clone();
+                        self.shared_edges.insert(reversed);
+                        (curve, CurveBoundary::default().reverse())
+                    }
+                    None => {
+                        let curve = Curve::new().insert(core);
+                        self.edges.insert(edge.clone(), curve.clone());
+                        (curve, CurveBoundary::default())
+                    }
+                };
+
+                let curve = curve.make_line_on_surface(
+                    [points_surface[i], points_surface[j]],
+                    boundary,
+                    surface.clone(),
+                    &mut core.layers.geometry,
+                );
+
+                let [vertex, vertex_next] = edge.inner;
+                for (vertex, position) in [
+                    (&vertex, boundary.inner[0]),
+                    (&vertex_next, boundary.inner[1]),
+                ] {
+                    core.layers.geometry.define_vertex(
+                        vertex.clone(),
+                        curve.clone(),
+                        LocalVertexGeom { position },
+                    );
+                }
+
+                HalfEdge::unjoined(core)
+                    .update_start_vertex(|_, _| vertex.clone(), core)
+                    .update_curve(|_, _| curve.clone(), core)
+                    .insert(core)
+            })
+            .collect::<Vec<_>>();
+
+        let face = Face::unbound(surface, core).update_region(
+            |region, core| {
+                region.update_exterior(
+                    |cycle, core| cycle.add_half_edges(half_edges, core),
+                    core,
+                )
+            },
+            core,
+        );
+        self.faces.push(face);
+
+        Ok(self)
+    }
+
+    /// Build the shell from the faces that have been added
+    ///
+    /// The shell is not necessarily closed. Use [`BoundaryEdgesOfShell`] to
+    /// check that, if required.
+    ///
+    /// [`BoundaryEdgesOfShell`]: crate::queries::BoundaryEdgesOfShell
+    pub fn build(self, core: &mut Core) -> Shell {
+        Shell::empty().add_faces(self.faces, core)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Point;
+
+    use crate::{
+        operations::build::BuildError, queries::BoundaryEdgesOfShell, Core,
+    };
+
+    use super::ShellBuilder;
+
+    #[test]
+    fn shell_builder_cube() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The corners of each face are slightly off, but within the tolerance
+        // used for welding.
+        let corner = |[x, y, z]: [f64; 3], face: usize| {
+            let offset = 1e-5 * face as f64;
+            Point::from([x + offset, y - offset, z + offset])
+        };
+        let faces = [
+            [[0., 0., 0.], [0., 1., 0.], [1., 1., 0.], [1., 0., 0.]],
+            [[0., 0., 1.], [1., 0., 1.], [1., 1., 1.], [0., 1., 1.]],
+            [[0., 0., 0.], [1., 0., 0.], [1., 0., 1.], [0., 0., 1.]],
+            [[0., 1., 0.], [0., 1., 1.], [1., 1., 1.], [1., 1., 0.]],
+            [[0., 0., 0.], [0., 0., 1.], [0., 1., 1.], [0., 1., 0.]],
+            [[1., 0., 0.], [1., 1., 0.], [1., 1., 1.], [1., 0., 1.]],
+        ];
+
+        let mut builder = ShellBuilder::new(0.001);
+        for (index, face) in faces.into_iter().enumerate() {
+            builder
+                .add_face(face.map(|point| corner(point, index)), &mut core)?;
+        }
+        let shell = builder.build(&mut core);
+
+        let half_edges = shell
+            .faces()
+            .iter()
+            .flat_map(|face| face.region().exterior().half_edges().iter())
+            .collect::<Vec<_>>();
+        let curves = half_edges
+            .iter()
+            .map(|half_edge| half_edge.curve().clone())
+            .collect::<BTreeSet<_>>();
+        let vertices = half_edges
+            .iter()
+            .map(|half_edge| half_edge.start_vertex().clone())
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(half_edges.len(), 24);
+        assert_eq!(curves.len(), 12);
+        assert_eq!(vertices.len(), 8);
+        assert!(shell.is_closed());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shell_builder_mismatched_edge() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let triangle = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+
+        let mut builder = ShellBuilder::new(0.001);
+        builder.add_face(triangle, &mut core)?;
+
+        // The same face again would share all its edges in the same direction.
+        assert_eq!(
+            builder.add_face(triangle, &mut core).map(|_| ()),
+            Err(BuildError::MismatchedEdge { index: 1 }),
+        );
+
+        Ok(())
+    }
+}
@@ -2,7 +2,8 @@ use fj_math::{Point, Scalar};
 
 use crate::{
     operations::update::UpdateSketch,
-    topology::{Region, Sketch, Topology},
+    storage::Handle,
+    topology::{Region, Sketch, Surface, Topology},
     Core,
 };
 
@@ -25,14 +26,22 @@ pub trait BuildSketch {
         radius: impl Into<Scalar>,
         core: &mut Core,
     ) -> Sketch {
-        let sketch = Sketch::empty(&core.layers.topology);
-        sketch.add_regions(
-            [Region::circle(
-                center,
-                radius,
-                sketch.surface().clone(),
-                core,
-            )],
+        let surface = core.layers.topology.surfaces.space_2d();
+        Sketch::circle_on(center, radius, &surface, core)
+    }
+
+    /// Build a circle on the provided surface
+    ///
+    /// The geometry of the circle's curve is defined on `surface`, and the
+    /// resulting sketch uses `surface` as its surface.
+    fn circle_on(
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Sketch {
+        Sketch::new(surface.clone(), []).add_regions(
+            [Region::circle(center, radius, surface.clone(), core)],
             core,
         )
     }
@@ -53,3 +62,29 @@ pub trait BuildSketch {
 }
 
 impl BuildSketch for Sketch {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{topology::Sketch, Core};
+
+    use super::BuildSketch;
+
+    #[test]
+    fn circle_on() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.yz_plane();
+        let sketch = Sketch::circle_on([0., 0.], 1., &surface, &mut core);
+
+        assert_eq!(sketch.surface(), &surface);
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        for half_edge in sketch.regions().only().exterior().half_edges() {
+            let curve =
+                core.layers.geometry.of_curve(half_edge.curve()).unwrap();
+
+            assert!(curve.local_on(&surface).is_some());
+            assert!(curve.local_on(&space_2d).is_none());
+        }
+    }
+}
@@ -2,10 +2,11 @@ use fj_math::Point;
 
 use crate::{
     operations::{
-        build::{BuildShell, TetrahedronShell},
+        build::{BuildShell, ShellBuilder, TetrahedronShell},
         insert::{Insert, IsInsertedYes},
         update::UpdateSolid,
     },
+    queries::BoundaryEdgesOfShell,
     topology::{Shell, Solid},
     Core,
 };
@@ -33,10 +34,73 @@ pub trait BuildSolid {
 
         Tetrahedron { solid, shell }
     }
+
+    /// Build a solid from planar polygons
+    ///
+    /// Each polygon is provided as a loop of points, and becomes one face of
+    /// the solid. The points of each polygon must be in counter-clockwise
+    /// order, when viewed from outside of the solid.
+    ///
+    /// Vertices and edges of different faces are shared, if their positions
+    /// are identical, within the tolerance of `core`. The resulting shell must
+    /// be closed.
+    ///
+    /// See [`ShellBuilder`], for building a shell face by face.
+    fn from_faces(
+        faces: &[Vec<Point<3>>],
+        core: &mut Core,
+    ) -> Result<Solid, BuildError> {
+        let mut builder = ShellBuilder::new(core.tolerance());
+        for points in faces {
+            builder.add_face(points.iter().copied(), core)?;
+        }
+
+        let shell = builder.build(core);
+        if !shell.is_closed() {
+            return Err(BuildError::NotClosed);
+        }
+
+        let shell = shell.insert(core);
+        Ok(Solid::empty().add_shells([shell], core))
+    }
 }
 
 impl BuildSolid for Solid {}
 
+/// Error building a [`Solid`]
+///
+/// Returned by [`BuildSolid::from_faces`] and [`ShellBuilder::add_face`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BuildError {
+    /// A face has fewer than three distinct points, or no area
+    #[error("Face at index {index} is degenerate")]
+    DegenerateFace {
+        /// The index of the face
+        index: usize,
+    },
+
+    /// The points of a face don't lie on a plane
+    #[error("Face at index {index} is not planar")]
+    NonPlanarFace {
+        /// The index of the face
+        index: usize,
+    },
+
+    /// An edge of a face can't be shared with the edge it coincides with
+    ///
+    /// This is the case, if the existing edge runs in the same direction, or if
+    /// it is already shared by two other faces.
+    #[error("Edge of face at index {index} doesn't match existing edge")]
+    MismatchedEdge {
+        /// The index of the face
+        index: usize,
+    },
+
+    /// The faces don't form a closed, manifold shell
+    #[error("Faces don't form a closed shell")]
+    NotClosed,
+}
+
 /// A tetrahedron
 ///
 /// Returned by [`BuildSolid::tetrahedron`].
@@ -47,3 +111,33 @@ pub struct Tetrahedron {
     /// The shell of the tetrahedron
     pub shell: TetrahedronShell<IsInsertedYes>,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{topology::Solid, Core};
+
+    use super::{BuildError, BuildSolid};
+
+    #[test]
+    fn from_faces_tetrahedron() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+        let faces = [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+            .map(|face| face.to_vec());
+
+        let solid = Solid::from_faces(&faces, &mut core)?;
+        assert_eq!(solid.shells().only().faces().len(), 4);
+
+        assert_eq!(
+            Solid::from_faces(&faces[..3], &mut core).map(|_| ()),
+            Err(BuildError::NotClosed),
+        );
+
+        Ok(())
+    }
+}
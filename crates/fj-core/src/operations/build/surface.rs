@@ -1,7 +1,7 @@
 use fj_math::{Point, Scalar, Vector};
 
 use crate::{
-    geometry::{Path, SurfaceGeom},
+    geometry::{DegenerateSurface, Path, SurfaceGeom},
     operations::insert::Insert,
     storage::Handle,
     topology::Surface,
@@ -15,10 +15,19 @@ use crate::{
 /// [module-level documentation]: super
 pub trait BuildSurface {
     /// Build a surface from the provided geometry
+    ///
+    /// Logs a warning, if the geometry is not well-formed (see
+    /// [`SurfaceGeom::is_well_formed`]).
     fn from_geometry(
         surface_geom: SurfaceGeom,
         core: &mut Core,
     ) -> Handle<Surface> {
+        if !surface_geom.is_well_formed(core.tolerance()) {
+            tracing::warn!(
+                "Building surface from degenerate geometry: {surface_geom:?}"
+            );
+        }
+
         let surface = Surface::new().insert(core);
 
         core.layers
@@ -44,16 +53,25 @@ pub trait BuildSurface {
     }
 
     /// Build a plane from the provided points
+    ///
+    /// Returns an error, if the points are collinear, or so close to it that
+    /// the resulting plane would not be well-formed (see
+    /// [`SurfaceGeom::is_well_formed`]).
     fn plane_from_points(
         points: [impl Into<Point<3>>; 3],
         core: &mut Core,
-    ) -> (Handle<Surface>, [Point<2>; 3]) {
+    ) -> Result<(Handle<Surface>, [Point<2>; 3]), DegenerateSurface> {
         let [a, b, c] = points.map(Into::into);
 
         let (u, u_line) = Path::line_from_points([a, b]);
         let v = c - a;
 
-        let surface = Surface::from_uv(u, v, core);
+        let surface_geom = SurfaceGeom { u, v };
+        if !surface_geom.is_well_formed(core.tolerance()) {
+            return Err(DegenerateSurface(surface_geom));
+        }
+
+        let surface = Surface::from_geometry(surface_geom, core);
 
         let points_surface = {
             let [a, b] =
@@ -63,7 +81,7 @@ pub trait BuildSurface {
             [a, b, c]
         };
 
-        (surface, points_surface)
+        Ok((surface, points_surface))
     }
 }
 
@@ -0,0 +1,93 @@
+use fj_math::Point;
+use itertools::Itertools;
+
+use crate::{
+    geometry::{CurveBoundary, LocalVertexGeom},
+    operations::{build::BuildHalfEdge, insert::Insert},
+    storage::Handle,
+    topology::{HalfEdge, Surface, Vertex, Wire},
+    Core,
+};
+
+/// Build a [`Wire`]
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+pub trait BuildWire {
+    /// # Build a wire from half-edges and associated curve boundaries
+    ///
+    /// Each half-edge ends where the next one starts. A new vertex is created
+    /// for the end of the last half-edge.
+    fn from_half_edges_and_boundaries<I>(
+        half_edges_and_boundaries: I,
+        core: &mut Core,
+    ) -> Wire
+    where
+        I: IntoIterator<Item = (Handle<HalfEdge>, CurveBoundary<Point<1>>)>,
+    {
+        let half_edges_and_boundaries =
+            half_edges_and_boundaries.into_iter().collect::<Vec<_>>();
+
+        let end_vertex = Vertex::new().insert(core);
+        let end_vertices = half_edges_and_boundaries
+            .iter()
+            .skip(1)
+            .map(|(half_edge, _)| half_edge.start_vertex().clone())
+            .chain([end_vertex.clone()])
+            .collect::<Vec<_>>();
+
+        let half_edges = half_edges_and_boundaries
+            .into_iter()
+            .zip(end_vertices)
+            .map(|((half_edge, boundary), end_vertex)| {
+                let [start, end] = boundary.inner;
+
+                core.layers.geometry.define_vertex(
+                    half_edge.start_vertex().clone(),
+                    half_edge.curve().clone(),
+                    LocalVertexGeom { position: start },
+                );
+                core.layers.geometry.define_vertex(
+                    end_vertex,
+                    half_edge.curve().clone(),
+                    LocalVertexGeom { position: end },
+                );
+
+                half_edge
+            })
+            .collect::<Vec<_>>();
+
+        Wire::new(half_edges, end_vertex)
+    }
+
+    /// # Build a polyline
+    ///
+    /// Each pair of neighboring points is connected by a line segment. Unlike
+    /// with [`BuildCycle::polygon`], the last point is not connected back to
+    /// the first one.
+    ///
+    /// [`BuildCycle::polygon`]: crate::operations::build::BuildCycle::polygon
+    fn polyline<P, Ps>(
+        points: Ps,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Wire
+    where
+        P: Into<Point<2>>,
+        Ps: IntoIterator<Item = P>,
+    {
+        let half_edges_and_boundaries = points
+            .into_iter()
+            .map(Into::into)
+            .tuple_windows()
+            .map(|(start, end)| {
+                HalfEdge::line_segment([start, end], surface.clone(), core)
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_half_edges_and_boundaries(half_edges_and_boundaries, core)
+    }
+}
+
+impl BuildWire for Wire {}
@@ -1,17 +1,31 @@
 //! Add holes to shapes
 
-use fj_math::{Point, Scalar, Vector};
+use std::{collections::BTreeMap, iter};
+
+use fj_math::{LineSegment, Point, Polygon, Scalar, Vector};
 
 use crate::{
+    algorithms::{
+        approx::{face::approx_face, ApproxCache},
+        intersect::intersect_ray_face,
+    },
+    geometry::{traits::GenPolyline, Geometry, Tolerance},
     storage::Handle,
-    topology::{Cycle, Face, Region, Shell},
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
+        Vertex,
+    },
     Core,
 };
 
 use super::{
     build::{BuildCycle, BuildRegion},
+    derive::DeriveFrom,
+    geometry::UpdateCurveGeometry,
+    insert::Insert,
+    reverse::Reverse,
     sweep::{SweepCache, SweepRegion},
-    update::{UpdateFace, UpdateRegion, UpdateShell},
+    update::{UpdateFace, UpdateRegion, UpdateShell, UpdateSolid},
 };
 
 /// Add a hole to a [`Shell`]
@@ -47,30 +61,10 @@ impl AddHole for Shell {
             radius,
             location.face.surface().clone(),
             core,
-        );
-        let hole = Region::empty(core)
-            .update_exterior(|_, _| entry.clone(), core)
-            .sweep_region(
-                location.face.surface().clone(),
-                None,
-                path,
-                &mut SweepCache::default(),
-                core,
-            )
-            .all_faces()
-            .collect::<Vec<_>>();
-
-        self.update_face(
-            location.face,
-            |face, core| {
-                [face.update_region(
-                    |region, core| region.add_interiors([entry], core),
-                    core,
-                )]
-            },
-            core,
         )
-        .add_faces(hole, core)
+        .insert(core);
+
+        add_blind_pocket(self, location.face, entry, path.into(), core)
     }
 
     fn add_through_hole(
@@ -86,7 +80,8 @@ impl AddHole for Shell {
             radius,
             entry_location.face.surface().clone(),
             core,
-        );
+        )
+        .insert(core);
 
         let path = {
             let point = |location: &HoleLocation| {
@@ -105,22 +100,552 @@ impl AddHole for Shell {
             exit_point - entry_point
         };
 
-        let swept_region = Region::empty(core)
-            .update_exterior(|_, _| entry.clone(), core)
-            .sweep_region(
-                entry_location.face.surface().clone(),
-                None,
+        add_through_pocket(
+            self,
+            [entry_location.face, exit_location.face],
+            entry,
+            path,
+            core,
+        )
+    }
+}
+
+/// Defines the location of a hole
+pub struct HoleLocation<'r> {
+    /// The face that the hole is in
+    pub face: &'r Handle<Face>,
+
+    /// The position of the hole within the face, in surface coordinates
+    pub position: Point<2>,
+}
+
+/// Cut a swept profile out of a [`Solid`]
+pub trait CutExtrude: Sized {
+    /// Extrude the profile into the solid, and remove the extruded volume
+    ///
+    /// The regions of `profile` are placed on `surface`, which must be the
+    /// surface of a planar face of the solid, and swept into the solid, along
+    /// the inverse normal of that face. This creates a pocket, or a hole, for
+    /// each region.
+    ///
+    /// ## Implementation Note
+    ///
+    /// This is not yet based on a general boolean difference, and only
+    /// supports cuts that enter the solid through a single face, and, for
+    /// [`CutDepth::ThroughAll`], leave it through a single face parallel to
+    /// that. The regions of the profile must lie within the face they are cut
+    /// into, and must not have interiors. Cuts that don't meet these
+    /// requirements are rejected with a [`CutExtrudeError`].
+    fn cut_extrude(
+        &self,
+        profile: &Sketch,
+        surface: &Handle<Surface>,
+        depth: CutDepth,
+        core: &mut Core,
+    ) -> Result<Self, CutExtrudeError>;
+}
+
+impl CutExtrude for Solid {
+    fn cut_extrude(
+        &self,
+        profile: &Sketch,
+        surface: &Handle<Surface>,
+        depth: CutDepth,
+        core: &mut Core,
+    ) -> Result<Self, CutExtrudeError> {
+        let (shell, entry_face) = self
+            .shells()
+            .iter()
+            .find_map(|shell| {
+                shell
+                    .faces()
+                    .iter()
+                    .find(|face| face.surface() == surface)
+                    .map(|face| (shell, face))
+            })
+            .ok_or(CutExtrudeError::NoFaceOnSurface)?;
+
+        let geometry = &core.layers.geometry;
+        let (Some(normal), Some(entry_plane)) = (
+            entry_face.normal(geometry),
+            geometry.of_surface(surface).as_plane(),
+        ) else {
+            return Err(CutExtrudeError::NonPlanarFace);
+        };
+
+        if profile
+            .regions()
+            .iter()
+            .any(|region| !region.interiors().is_empty())
+        {
+            return Err(CutExtrudeError::ProfileWithInteriors);
+        }
+
+        // The faces on the opposite side of the solid face away from the
+        // entry face, and lie behind it.
+        let tolerance = core.tolerance().inner();
+        let opposite_faces = shell
+            .faces()
+            .iter()
+            .filter_map(|face| {
+                let face_normal = face.normal(geometry)?;
+                if (face_normal + normal).magnitude() > tolerance {
+                    return None;
+                }
+
+                let plane = geometry.of_surface(face.surface()).as_plane()?;
+                let distance = (entry_plane.origin - plane.origin).dot(&normal);
+
+                (distance > tolerance).then_some((face, distance))
+            })
+            .collect::<Vec<_>>();
+
+        let (distance, exit_face) = match depth {
+            CutDepth::Blind(distance) => {
+                if distance <= Scalar::ZERO {
+                    return Err(CutExtrudeError::InvalidDepth);
+                }
+
+                (distance, None)
+            }
+            CutDepth::ThroughAll => {
+                // Of all the opposite faces, pick the one that is furthest
+                // away, to make sure the hole goes through everything.
+                let (exit_face, distance) = opposite_faces
+                    .into_iter()
+                    .max_by_key(|(_, distance)| *distance)
+                    .ok_or(CutExtrudeError::NonParallelExitFace)?;
+
+                (distance, Some(exit_face))
+            }
+        };
+        let path = -normal * distance;
+
+        check_cut(
+            CheckCut {
+                shell,
+                profile,
+                entry_face,
+                exit_face,
+                normal,
+                distance,
+            },
+            core,
+        )?;
+
+        let mut shell_updated = Shell::clone(shell);
+        for region in profile.regions() {
+            let entry = place_cycle(region.exterior(), profile, surface, core);
+
+            // Holes must be wound the other way than the exterior of their
+            // face.
+            let geometry = &core.layers.geometry;
+            let entry = if entry.winding(geometry, surface)
+                == entry_face.region().exterior().winding(geometry, surface)
+            {
+                entry.reverse(core).insert(core)
+            } else {
+                entry
+            };
+
+            let entry_face = find_face_on_surface(&shell_updated, surface);
+            shell_updated = match exit_face {
+                None => add_blind_pocket(
+                    &shell_updated,
+                    &entry_face,
+                    entry,
+                    path,
+                    core,
+                ),
+                Some(exit_face) => {
+                    let exit_face = find_face_on_surface(
+                        &shell_updated,
+                        exit_face.surface(),
+                    );
+                    add_through_pocket(
+                        &shell_updated,
+                        [&entry_face, &exit_face],
+                        entry,
+                        path,
+                        core,
+                    )
+                }
+            };
+        }
+
+        Ok(self.update_shell(shell, |_, _| [shell_updated], core))
+    }
+}
+
+/// How deep to cut, for [`CutExtrude::cut_extrude`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CutDepth {
+    /// Cut a pocket of the provided depth
+    ///
+    /// The depth must be positive, and smaller than the thickness of the
+    /// solid.
+    Blind(Scalar),
+
+    /// Cut through the whole solid
+    ThroughAll,
+}
+
+/// Error cutting a profile out of a solid
+///
+/// Returned by [`CutExtrude::cut_extrude`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum CutExtrudeError {
+    /// The solid has no face on the provided surface
+    #[error("Solid has no face on the provided surface")]
+    NoFaceOnSurface,
+
+    /// The face on the provided surface is not planar
+    #[error("Cut-extrude only supports planar faces")]
+    NonPlanarFace,
+
+    /// The depth of a blind cut is not positive
+    #[error("Depth of cut must be positive")]
+    InvalidDepth,
+
+    /// A blind cut would reach another face of the solid
+    #[error("Depth of blind cut must be smaller than the thickness of solid")]
+    DepthExceedsSolid,
+
+    /// There is no face parallel to the entry face, where a through cut could
+    /// exit the solid
+    #[error("Through cut requires an exit face parallel to the entry face")]
+    NonParallelExitFace,
+
+    /// A through cut would cross another face, before reaching its exit face
+    #[error("Through cut would cross a face before reaching the exit face")]
+    CutCrossesFace,
+
+    /// A region of the profile has interiors
+    #[error("Cut-extrude doesn't support profiles with interiors")]
+    ProfileWithInteriors,
+
+    /// A region of the profile doesn't lie within the face it is cut into
+    #[error("Profile must lie within the face it is cut into")]
+    ProfileOutsideFace,
+}
+
+struct CheckCut<'r> {
+    shell: &'r Shell,
+    profile: &'r Sketch,
+    entry_face: &'r Handle<Face>,
+    exit_face: Option<&'r Handle<Face>>,
+    normal: Vector<3>,
+    distance: Scalar,
+}
+
+/// Make sure the cut only enters and leaves the solid where it is supposed to
+///
+/// Each region of the profile must lie within the entry face. From every point
+/// of the approximated regions, the cut must not cross any face of the shell,
+/// other than the exit face, within its depth.
+fn check_cut(
+    CheckCut {
+        shell,
+        profile,
+        entry_face,
+        exit_face,
+        normal,
+        distance,
+    }: CheckCut,
+    core: &Core,
+) -> Result<(), CutExtrudeError> {
+    let geometry = &core.layers.geometry;
+    let tolerance = core.tolerance();
+
+    let mut cache = ApproxCache::default();
+    let faces = shell
+        .faces()
+        .iter()
+        .map(|face| {
+            let approx =
+                approx_face(face.clone(), tolerance, &mut cache, geometry);
+            let polygons = [&approx.exterior]
+                .into_iter()
+                .chain(&approx.interiors)
+                .map(|cycle| cycle.polygon())
+                .collect::<Vec<_>>();
+
+            (face, polygons)
+        })
+        .collect::<Vec<_>>();
+
+    let entry_polygons = faces
+        .iter()
+        .find(|(face, _)| face.id() == entry_face.id())
+        .map(|(_, polygons)| polygons.as_slice())
+        .unwrap_or_default();
+    let entry_surface = geometry.of_surface(entry_face.surface());
+
+    for region in profile.regions() {
+        let region = approx_profile_cycle(
+            region.exterior(),
+            profile,
+            tolerance,
+            geometry,
+        );
+
+        if !polygon_is_within(&region, entry_polygons, tolerance.inner()) {
+            return Err(CutExtrudeError::ProfileOutsideFace);
+        }
+
+        for &point in region.points() {
+            let origin =
+                entry_surface.point_from_surface_coords(point, tolerance);
+
+            let crosses_face = faces
+                .iter()
+                .filter(|(face, _)| {
+                    face.id() != entry_face.id()
+                        && Some(face.id()) != exit_face.map(|face| face.id())
+                })
+                .flat_map(|(face, polygons)| {
+                    intersect_ray_face(
+                        geometry.of_surface(face.surface()),
+                        polygons,
+                        origin,
+                        -normal,
+                        tolerance.inner(),
+                    )
+                })
+                .any(|d| {
+                    d > tolerance.inner() && d < distance + tolerance.inner()
+                });
+
+            if crosses_face {
+                return Err(match exit_face {
+                    None => CutExtrudeError::DepthExceedsSolid,
+                    Some(_) => CutExtrudeError::CutCrossesFace,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Approximate a cycle of a sketch, in the coordinates of its surface
+///
+/// The surface of a sketch has no geometry, so this can't use the regular
+/// approximation code, which works with the geometry in 3D.
+fn approx_profile_cycle(
+    cycle: &Cycle,
+    sketch: &Sketch,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Polygon<2> {
+    let points = cycle.half_edges().pairs().flat_map(|(half_edge, next)| {
+        let path = geometry
+            .of_curve(half_edge.curve())
+            .and_then(|curve| curve.local_on(sketch.surface()))
+            .expect("Expected geometry of curve to be defined on sketch")
+            .path;
+        let boundary = half_edge.curve_boundary(next.start_vertex(), geometry);
+        let [start, _] = boundary.inner;
+
+        iter::once(start)
+            .chain(path.generate_polyline(boundary, tolerance))
+            .map(move |point| path.point_from_path_coords(point))
+    });
+
+    Polygon::from_points(points)
+}
+
+/// Determine whether a polygon lies within the area bounded by other polygons
+///
+/// The bounding polygons are treated as one, using the even-odd rule. Touching
+/// their boundary, within `tolerance`, counts as leaving the area.
+fn polygon_is_within(
+    polygon: &Polygon<2>,
+    area: &[Polygon<2>],
+    tolerance: Scalar,
+) -> bool {
+    let is_in_area = |point: Point<2>| {
+        let is_on_boundary = area
+            .iter()
+            .any(|polygon| polygon.is_on_boundary(point, tolerance));
+        let winding_number = area
+            .iter()
+            .map(|polygon| polygon.winding_number(point))
+            .sum::<i32>();
+
+        !is_on_boundary && winding_number % 2 != 0
+    };
+    let is_in_polygon = |point: Point<2>| {
+        polygon.is_on_boundary(point, tolerance)
+            || polygon.winding_number(point) != 0
+    };
+    let edges_cross = |other: &Polygon<2>| {
+        polygon.edges().into_iter().any(|a| {
+            other.edges().into_iter().any(|b| {
+                let side = |edge: LineSegment<2>, point: Point<2>| {
+                    let [start, end] = edge.points;
+                    (end - start).cross2d(&(point - start))
+                };
+                let [a0, a1] = a.points;
+                let [b0, b1] = b.points;
+
+                side(a, b0) * side(a, b1) < Scalar::ZERO
+                    && side(b, a0) * side(b, a1) < Scalar::ZERO
+            })
+        })
+    };
+
+    // All of the polygon must be in the area, and no boundary of the area,
+    // like a hole, may be within the polygon.
+    polygon.points().iter().all(|&point| is_in_area(point))
+        && area
+            .iter()
+            .flat_map(|other| other.points())
+            .all(|&point| !is_in_polygon(point))
+        && !area.iter().any(edges_cross)
+}
+
+/// Place a copy of a cycle of a sketch onto the provided surface
+///
+/// Creates new half-edges, curves, and vertices, and defines their geometry on
+/// `surface`, using the same local geometry as on the sketch's surface. The
+/// sketch itself, and the geometry of its objects, are left untouched. This
+/// means the same sketch can be used for multiple cuts.
+fn place_cycle(
+    cycle: &Handle<Cycle>,
+    sketch: &Sketch,
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) -> Handle<Cycle> {
+    let mut vertices = BTreeMap::new();
+    let mut placed_vertex = |vertex: &Handle<Vertex>, core: &mut Core| {
+        vertices
+            .entry(vertex.id())
+            .or_insert_with(|| {
+                Vertex::new().insert(core).derive_from(vertex, core)
+            })
+            .clone()
+    };
+
+    let half_edges = cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next)| {
+            let curve = half_edge.curve();
+            let path = core
+                .layers
+                .geometry
+                .of_curve(curve)
+                .unwrap()
+                .local_on(sketch.surface())
+                .unwrap()
+                .path;
+
+            let placed_curve = Curve::new().insert(core).make_path_on_surface(
                 path,
-                &mut SweepCache::default(),
-                core,
+                surface.clone(),
+                &mut core.layers.geometry,
             );
 
-        let hole = swept_region.side_faces.into_iter().collect::<Vec<_>>();
+            // Vertex positions are defined in curve coordinates, so they can
+            // be copied over as they are.
+            for vertex in [half_edge.start_vertex(), next.start_vertex()] {
+                let geometry = core
+                    .layers
+                    .geometry
+                    .of_vertex(vertex)
+                    .unwrap()
+                    .local_on(curve)
+                    .unwrap()
+                    .clone();
+                let placed_vertex = placed_vertex(vertex, core);
+
+                core.layers.geometry.define_vertex(
+                    placed_vertex,
+                    placed_curve.clone(),
+                    geometry,
+                );
+            }
+
+            let start_vertex = placed_vertex(half_edge.start_vertex(), core);
+            HalfEdge::new(placed_curve, start_vertex)
+                .insert(core)
+                .derive_from(half_edge, core)
+        })
+        .collect::<Vec<_>>();
 
-        let exit = swept_region.top_face.region().exterior();
+    Cycle::new(half_edges).insert(core).derive_from(cycle, core)
+}
+
+fn find_face_on_surface(
+    shell: &Shell,
+    surface: &Handle<Surface>,
+) -> Handle<Face> {
+    shell
+        .faces()
+        .iter()
+        .find(|face| face.surface() == surface)
+        .expect("Updated shell still has a face on the surface")
+        .clone()
+}
+
+fn add_blind_pocket(
+    shell: &Shell,
+    face: &Handle<Face>,
+    entry: Handle<Cycle>,
+    path: Vector<3>,
+    core: &mut Core,
+) -> Shell {
+    let hole = Region::empty(core)
+        .update_exterior(|_, _| entry.clone(), core)
+        .sweep_region(
+            face.surface().clone(),
+            None,
+            path,
+            &mut SweepCache::default(),
+            core,
+        )
+        .all_faces()
+        .collect::<Vec<_>>();
 
-        self.update_face(
-            entry_location.face,
+    shell
+        .update_face(
+            face,
+            |face, core| {
+                [face.update_region(
+                    |region, core| region.add_interiors([entry], core),
+                    core,
+                )]
+            },
+            core,
+        )
+        .add_faces(hole, core)
+}
+
+fn add_through_pocket(
+    shell: &Shell,
+    [entry_face, exit_face]: [&Handle<Face>; 2],
+    entry: Handle<Cycle>,
+    path: Vector<3>,
+    core: &mut Core,
+) -> Shell {
+    let swept_region = Region::empty(core)
+        .update_exterior(|_, _| entry.clone(), core)
+        .sweep_region(
+            entry_face.surface().clone(),
+            None,
+            path,
+            &mut SweepCache::default(),
+            core,
+        );
+
+    let hole = swept_region.side_faces.into_iter().collect::<Vec<_>>();
+
+    let exit = swept_region.top_face.region().exterior();
+
+    shell
+        .update_face(
+            entry_face,
             |face, core| {
                 [face.update_region(
                     |region, core| region.add_interiors([entry], core),
@@ -130,7 +655,7 @@ impl AddHole for Shell {
             core,
         )
         .update_face(
-            exit_location.face,
+            exit_face,
             |face, core| {
                 [face.update_region(
                     |region, core| {
@@ -144,7 +669,7 @@ impl AddHole for Shell {
                                 .unwrap();
                             core.layers.geometry.define_curve(
                                 half_edge.curve().clone(),
-                                exit_location.face.surface().clone(),
+                                exit_face.surface().clone(),
                                 geometry.clone(),
                             );
                         }
@@ -156,14 +681,336 @@ impl AddHole for Shell {
             core,
         )
         .add_faces(hole, core)
-    }
 }
 
-/// Defines the location of a hole
-pub struct HoleLocation<'r> {
-    /// The face that the hole is in
-    pub face: &'r Handle<Face>,
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Point, Scalar};
 
-    /// The position of the hole within the face, in surface coordinates
-    pub position: Point<2>,
+    use crate::{
+        fixtures,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            reverse::Reverse,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        queries::EulerCharacteristicOfShell,
+        storage::Handle,
+        topology::{Cycle, Region, Sketch, Solid, Surface},
+        Core,
+    };
+
+    use super::{CutDepth, CutExtrude, CutExtrudeError};
+
+    fn cube(core: &mut Core) -> Solid {
+        fixtures::cuboid([0., 0., -2.], [2., 2., 0.], core)
+    }
+
+    fn circle(core: &mut Core) -> Sketch {
+        let surface = core.layers.topology.surfaces.space_2d();
+        Sketch::empty(&core.layers.topology)
+            .add_regions([Region::circle([1., 1.], 0.5, surface, core)], core)
+    }
+
+    #[test]
+    fn cut_extrude_through_all() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let circle = circle(&mut core);
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result = cube.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::ThroughAll,
+            &mut core,
+        )?;
+
+        let shell = result.shells().only();
+        assert_eq!(shell.genus(), Some(1));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn cut_extrude_through_all_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let square = {
+            let surface = core.layers.topology.surfaces.space_2d();
+            Sketch::empty(&core.layers.topology).add_regions(
+                [Region::polygon(
+                    [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]],
+                    surface,
+                    &mut core,
+                )],
+                &mut core,
+            )
+        };
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result = cube.cut_extrude(
+            &square,
+            &surface,
+            CutDepth::ThroughAll,
+            &mut core,
+        )?;
+
+        // The faces of the hole must point into it, or the volume enclosed by
+        // the mesh would be off.
+        let mesh = result.mesh_exact(&core.layers.geometry)?;
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points.map(|point| point.coords);
+                a.dot(&b.cross(&c)) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+        assert_abs_diff_eq!(
+            volume,
+            Scalar::from(6.),
+            epsilon = Scalar::from(1e-9),
+        );
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn cut_extrude_blind() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let circle = circle(&mut core);
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result = cube.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::Blind(Scalar::ONE),
+            &mut core,
+        )?;
+
+        let shell = result.shells().only();
+        assert_eq!(shell.genus(), Some(0));
+        assert!(shell.faces().len() > cube.shells().only().faces().len());
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn cut_extrude_blind_too_deep() {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let circle = circle(&mut core);
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result = cube.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::Blind(Scalar::from(2.)),
+            &mut core,
+        );
+
+        assert_eq!(result.err(), Some(CutExtrudeError::DepthExceedsSolid));
+    }
+
+    #[test]
+    fn cut_extrude_profile_outside_face() {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let circle = {
+            let surface = core.layers.topology.surfaces.space_2d();
+            Sketch::empty(&core.layers.topology).add_regions(
+                [Region::circle([1.8, 1.], 0.5, surface, &mut core)],
+                &mut core,
+            )
+        };
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result = cube.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::ThroughAll,
+            &mut core,
+        );
+
+        assert_eq!(result.err(), Some(CutExtrudeError::ProfileOutsideFace));
+    }
+
+    #[test]
+    fn cut_extrude_profile_with_interiors() {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let ring = {
+            let surface = core.layers.topology.surfaces.space_2d();
+            let region =
+                Region::circle([1., 1.], 0.5, surface.clone(), &mut core)
+                    .add_interiors(
+                        [Cycle::circle([1., 1.], 0.2, surface, &mut core)
+                            .reverse(&mut core)],
+                        &mut core,
+                    );
+            Sketch::empty(&core.layers.topology)
+                .add_regions([region], &mut core)
+        };
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let result =
+            cube.cut_extrude(&ring, &surface, CutDepth::ThroughAll, &mut core);
+
+        assert_eq!(result.err(), Some(CutExtrudeError::ProfileWithInteriors));
+    }
+
+    #[test]
+    fn cut_extrude_through_non_parallel_face() {
+        let mut core = Core::new();
+
+        // None of the faces is parallel to the floor of the house, so a cut
+        // from there can't go through.
+        let house = house(&mut core);
+        let (surface, circle) =
+            circle_on_face(&house, [1., 0., -0.5], 0.1, &mut core);
+
+        let result = house.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::ThroughAll,
+            &mut core,
+        );
+
+        assert_eq!(result.err(), Some(CutExtrudeError::NonParallelExitFace));
+    }
+
+    #[test]
+    fn cut_extrude_blind_through_non_parallel_face() {
+        let mut core = Core::new();
+
+        // Close to its side, the roof of the house is lower than the depth of
+        // the cut.
+        let house = house(&mut core);
+        let (surface, circle) =
+            circle_on_face(&house, [1.7, 0., -0.5], 0.05, &mut core);
+
+        let result = house.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::Blind(Scalar::from(1.5)),
+            &mut core,
+        );
+
+        assert_eq!(result.err(), Some(CutExtrudeError::DepthExceedsSolid));
+    }
+
+    #[test]
+    fn cut_extrude_through_all_crosses_face() {
+        let mut core = Core::new();
+
+        // The L-shape has two faces opposite its long side. The cut exits
+        // through the nearer one, before it reaches the one furthest away.
+        let surface = core.layers.topology.surfaces.space_2d();
+        let l_shape = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            surface,
+            &mut core,
+        );
+        let l_shape = fixtures::sweep_down(l_shape, 0., 1., &mut core);
+        let (surface, circle) =
+            circle_on_face(&l_shape, [0., 1.5, -0.5], 0.2, &mut core);
+
+        let result = l_shape.cut_extrude(
+            &circle,
+            &surface,
+            CutDepth::ThroughAll,
+            &mut core,
+        );
+
+        assert_eq!(result.err(), Some(CutExtrudeError::CutCrossesFace));
+    }
+
+    #[test]
+    fn cut_extrude_leaves_profile_untouched() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let circle = circle(&mut core);
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        // The same profile can be used for multiple cuts.
+        for _ in 0..2 {
+            let cube = cube(&mut core);
+            cube.cut_extrude(
+                &circle,
+                &surface,
+                CutDepth::ThroughAll,
+                &mut core,
+            )?;
+        }
+
+        for half_edge in circle
+            .regions()
+            .iter()
+            .flat_map(|region| region.exterior().half_edges())
+        {
+            let curve = core.layers.geometry.of_curve(half_edge.curve());
+            assert!(curve.unwrap().local_on(&surface).is_none());
+        }
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    /// Build a prism with a cross-section like a house, with a pointy roof
+    fn house(core: &mut Core) -> Solid {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let house = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 2.], [0., 1.]],
+            surface,
+            core,
+        );
+        fixtures::sweep_down(house, 0., 1., core)
+    }
+
+    /// Build a circle on the surface of the face that contains `center`
+    ///
+    /// The radius is in the coordinates of that surface.
+    fn circle_on_face(
+        solid: &Solid,
+        center: impl Into<Point<3>>,
+        radius: f64,
+        core: &mut Core,
+    ) -> (Handle<Surface>, Sketch) {
+        let center = center.into();
+        let tolerance = core.tolerance();
+
+        let (surface, center) = solid
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .find_map(|face| {
+                let geometry = core.layers.geometry.of_surface(face.surface());
+                geometry.as_plane()?;
+
+                let center =
+                    geometry.point_to_surface_coords(center, tolerance)?;
+                Some((face.surface().clone(), center))
+            })
+            .expect("Expected a planar face to contain the center");
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        let circle = Sketch::empty(&core.layers.topology).add_regions(
+            [Region::circle(center, radius, space_2d, core)],
+            core,
+        );
+
+        (surface, circle)
+    }
 }
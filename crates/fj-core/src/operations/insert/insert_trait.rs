@@ -3,7 +3,7 @@ use crate::{
     storage::Handle,
     topology::{
         Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
-        Vertex,
+        Vertex, Wire,
     },
     Core,
 };
@@ -63,6 +63,7 @@ impl_insert!(
     Solid, solids;
     Surface, surfaces;
     Vertex, vertices;
+    Wire, wires;
 );
 
 // Implement `Insert` for `Handle` as a no-op. This is useful for code that
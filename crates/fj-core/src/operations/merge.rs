@@ -1,9 +1,17 @@
 //! # Operations to merge objects
 //!
-//! See [`Merge`], which is currently the only trait in this module, for more
-//! information.
+//! See [`Merge`] and [`MergeFaces`].
 
-use crate::{topology::Solid, Core};
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{LocalCurveGeom, Path, SurfaceGeom},
+    operations::{derive::DeriveFrom, insert::Insert},
+    queries::BoundingVerticesOfHalfEdge,
+    storage::Handle,
+    topology::{Cycle, Face, HalfEdge, Region, Solid, Surface},
+    Core,
+};
 
 use super::update::UpdateSolid;
 
@@ -19,3 +27,395 @@ impl Merge for Solid {
         self.add_shells(other.shells().iter().cloned(), core)
     }
 }
+
+/// Merge two adjacent [`Face`]s into one
+pub trait MergeFaces {
+    /// Merge this face with another, removing the edge they share
+    ///
+    /// The faces must be coplanar (see [`Face::is_coplanar_with`]), and their
+    /// exterior cycles must share exactly one edge. That
+    /// edge is removed, and the rest of both exterior cycles are stitched
+    /// together into the exterior of the merged face. The interior cycles of
+    /// both faces are kept.
+    ///
+    /// The vertices of the removed edge are kept, even if the edges adjacent to
+    /// them are collinear after the merge.
+    ///
+    /// The merged face is defined on the surface of this face. If the other
+    /// face is defined on a different surface, its curves are defined on this
+    /// face's surface too.
+    fn merge_face(
+        &self,
+        other: &Handle<Face>,
+        core: &mut Core,
+    ) -> Result<Handle<Face>, MergeFacesError>;
+}
+
+impl MergeFaces for Handle<Face> {
+    fn merge_face(
+        &self,
+        other: &Handle<Face>,
+        core: &mut Core,
+    ) -> Result<Handle<Face>, MergeFacesError> {
+        if self.surface() != other.surface()
+            && !self.is_coplanar_with(
+                other,
+                &core.layers.geometry,
+                core.tolerance(),
+            )
+        {
+            return Err(MergeFacesError::NotCoplanar);
+        }
+
+        let [exterior_a, exterior_b] =
+            [self, other].map(|face| face.region().exterior());
+
+        let mut shared_edges = Vec::new();
+        for a in exterior_a.half_edges() {
+            for b in exterior_b.half_edges() {
+                if are_siblings(a, exterior_a, b, exterior_b) {
+                    shared_edges.push((a, b));
+                }
+            }
+        }
+
+        let (shared_a, shared_b) = match shared_edges.as_slice() {
+            [] => return Err(MergeFacesError::NotAdjacent),
+            [shared] => *shared,
+            _ => return Err(MergeFacesError::MultipleSharedEdges),
+        };
+
+        let half_edges = [(exterior_a, shared_a), (exterior_b, shared_b)]
+            .into_iter()
+            .flat_map(|(cycle, shared)| {
+                cycle
+                    .half_edges()
+                    .iter()
+                    .cycle()
+                    .skip_while(move |half_edge| *half_edge != shared)
+                    .skip(1)
+                    .take(cycle.half_edges().len() - 1)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if self.surface() != other.surface() {
+            define_curves_on_surface(other, self.surface(), core);
+        }
+
+        let exterior = Cycle::new(half_edges)
+            .insert(core)
+            .derive_from(exterior_a, core);
+        let interiors = [self, other]
+            .into_iter()
+            .flat_map(|face| face.region().interiors().iter().cloned())
+            .collect::<Vec<_>>();
+        let region = Region::new(exterior, interiors)
+            .insert(core)
+            .derive_from(self.region(), core);
+
+        Ok(Face::new(self.surface().clone(), region)
+            .insert(core)
+            .derive_from(self, core))
+    }
+}
+
+/// Define the curves of a face on another, coplanar surface
+///
+/// Curves that already have a definition on the surface are left alone.
+fn define_curves_on_surface(
+    face: &Face,
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) {
+    let geometry = &core.layers.geometry;
+    let map = PlaneToPlane::new(
+        geometry.of_surface(face.surface()),
+        geometry.of_surface(surface),
+    );
+
+    let definitions = face
+        .region()
+        .all_cycles()
+        .flat_map(|cycle| cycle.half_edges().iter())
+        .filter_map(|half_edge| {
+            let curve = geometry.of_curve(half_edge.curve())?;
+            if curve.local_on(surface).is_some() {
+                return None;
+            }
+            let local = curve.local_on(face.surface())?;
+
+            Some((half_edge.curve().clone(), map.path(local.path)))
+        })
+        .collect::<Vec<_>>();
+
+    for (curve, path) in definitions {
+        core.layers.geometry.define_curve(
+            curve,
+            surface.clone(),
+            LocalCurveGeom { path },
+        );
+    }
+}
+
+/// Maps surface coordinates of one plane to those of a coplanar one
+///
+/// Both surfaces are planes, so the mapping is affine. Curve coordinates are
+/// preserved, which means the vertices on a mapped curve stay where they are.
+struct PlaneToPlane {
+    origin: Point<2>,
+    u: Vector<2>,
+    v: Vector<2>,
+}
+
+impl PlaneToPlane {
+    fn new(from: &SurfaceGeom, to: &SurfaceGeom) -> Self {
+        let (Path::Line(from_u), Path::Line(to_u)) = (from.u, to.u) else {
+            unreachable!("Coplanar surfaces are planes");
+        };
+
+        // Solve for the coordinates along `to`'s axes via the normal
+        // equations. If the planes are slightly tilted against each other,
+        // this projects onto `to`.
+        let [a, b] = [to_u.direction(), to.v];
+        let [aa, ab, bb] = [a.dot(&a), a.dot(&b), b.dot(&b)];
+        let det = aa * bb - ab * ab;
+        let coords = |vector: Vector<3>| {
+            let [pa, pb] = [a.dot(&vector), b.dot(&vector)];
+            Vector::from([(bb * pa - ab * pb) / det, (aa * pb - ab * pa) / det])
+        };
+
+        Self {
+            origin: Point::origin() + coords(from_u.origin() - to_u.origin()),
+            u: coords(from_u.direction()),
+            v: coords(from.v),
+        }
+    }
+
+    fn vector(&self, vector: Vector<2>) -> Vector<2> {
+        self.u * vector.u + self.v * vector.v
+    }
+
+    fn point(&self, point: Point<2>) -> Point<2> {
+        self.origin + self.vector(point.coords)
+    }
+
+    fn path(&self, path: Path<2>) -> Path<2> {
+        match path {
+            Path::Line(line) => Path::Line(Line::from_origin_and_direction(
+                self.point(line.origin()),
+                self.vector(line.direction()),
+            )),
+            Path::Circle(circle) => self.ellipse(circle.into()),
+            Path::Ellipse(ellipse) => self.ellipse(ellipse),
+        }
+    }
+
+    fn ellipse(&self, ellipse: Ellipse<2>) -> Path<2> {
+        let center = self.point(ellipse.center());
+        let [a, b] = [ellipse.a(), ellipse.b()].map(|axis| self.vector(axis));
+
+        let mapped = Ellipse::new(center, a, b);
+        if !mapped.is_circle() {
+            return Path::Ellipse(mapped);
+        }
+
+        // Within the epsilon of `is_circle`, this is a circle. Rebuild `b`
+        // from `a`, so it satisfies the exact requirements of `Circle::new`,
+        // without changing the direction the circle runs in.
+        let perpendicular = Vector::from([-a.v, a.u]);
+        let b = if a.u * b.v - a.v * b.u < Scalar::ZERO {
+            -perpendicular
+        } else {
+            perpendicular
+        };
+        Path::Circle(Circle::new(center, a, b))
+    }
+}
+
+fn are_siblings(
+    a: &Handle<HalfEdge>,
+    cycle_a: &Cycle,
+    b: &Handle<HalfEdge>,
+    cycle_b: &Cycle,
+) -> bool {
+    if a.curve().id() != b.curve().id() {
+        return false;
+    }
+
+    let vertices_a = cycle_a
+        .bounding_vertices_of_half_edge(a)
+        .expect("`a` came from `cycle_a`, must exist there");
+    let vertices_b = cycle_b
+        .bounding_vertices_of_half_edge(b)
+        .expect("`b` came from `cycle_b`, must exist there");
+
+    vertices_a == vertices_b.reverse()
+}
+
+/// Error merging faces
+///
+/// Returned by [`MergeFaces::merge_face`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum MergeFacesError {
+    /// The faces are not coplanar
+    ///
+    /// See [`Face::is_coplanar_with`].
+    #[error("Can't merge faces that are not coplanar")]
+    NotCoplanar,
+
+    /// The exterior cycles of the faces don't share an edge
+    #[error("Can't merge faces that are not adjacent")]
+    NotAdjacent,
+
+    /// The exterior cycles of the faces share more than one edge
+    #[error("Can't merge faces that share more than one edge")]
+    MultipleSharedEdges,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            build::{BuildFace, BuildSurface},
+            insert::Insert,
+            split::SplitFaceAlongLine,
+        },
+        topology::{Face, Surface},
+        Core,
+    };
+
+    use super::{define_curves_on_surface, MergeFaces, MergeFacesError};
+
+    #[test]
+    fn merge_rectangles_into_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let [a, b] = square.split_along_line([1., 0.], [1., 2.], &mut core);
+
+        let merged = a.merge_face(&b, &mut core)?;
+
+        assert_eq!(
+            merged.uv_bounds(&core.layers.geometry),
+            square.uv_bounds(&core.layers.geometry),
+        );
+
+        // The only half-edges that didn't make it into the merged face, are
+        // the two that make up the shared edge.
+        let merged_half_edges = merged.region().exterior().half_edges();
+        let removed = [&a, &b]
+            .into_iter()
+            .flat_map(|face| face.region().exterior().half_edges())
+            .filter(|half_edge| !merged_half_edges.contains(half_edge))
+            .collect::<Vec<_>>();
+        assert_eq!(merged_half_edges.len(), 6);
+        assert_eq!(removed.len(), 2);
+        assert_eq!(removed[0].curve(), removed[1].curve());
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_faces_on_different_coplanar_surfaces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let [a, b] = square.split_along_line([1., 0.], [1., 2.], &mut core);
+
+        // Move `a` to a surface that lies in the same plane, but has a
+        // different origin and axes, and a normal pointing the other way.
+        let (surface, _) = Surface::plane_from_points(
+            [[1., 0., 0.], [1., 1., 0.], [2., 0., 0.]],
+            &mut core,
+        )?;
+        define_curves_on_surface(&a, &surface, &mut core);
+        let a =
+            Face::new(surface.clone(), a.region().clone()).insert(&mut core);
+
+        let merged = a.merge_face(&b, &mut core)?;
+        assert_eq!(merged.surface(), &surface);
+        assert_eq!(merged.region().exterior().half_edges().len(), 6);
+
+        // The curves of `b` now have definitions on both surfaces, and those
+        // must agree in 3D.
+        let geometry = &core.layers.geometry;
+        for half_edge in b.region().exterior().half_edges() {
+            let curve = geometry
+                .of_curve(half_edge.curve())
+                .expect("Curve is defined");
+
+            let [from_b, from_merged] =
+                [b.surface(), &surface].map(|surface| {
+                    let path = curve
+                        .local_on(surface)
+                        .expect("Curve is defined on surface")
+                        .path;
+                    [0., 1.].map(|t| {
+                        geometry.of_surface(surface).point_from_surface_coords(
+                            path.point_from_path_coords([t]),
+                            core.tolerance(),
+                        )
+                    })
+                });
+            assert_eq!(from_b, from_merged);
+        }
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_faces_that_are_not_coplanar() {
+        let mut core = Core::new();
+
+        let a = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let b = Face::polygon(
+            core.layers.topology.surfaces.xz_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        assert_eq!(
+            a.merge_face(&b, &mut core),
+            Err(MergeFacesError::NotCoplanar)
+        );
+    }
+
+    #[test]
+    fn merge_faces_that_are_not_adjacent() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let [a, b] = [[0., 0.], [2., 0.]].map(|[u, v]| {
+            Face::polygon(
+                surface.clone(),
+                [[u, v], [u + 1., v], [u + 1., v + 1.], [u, v + 1.]],
+                &mut core,
+            )
+            .insert(&mut core)
+        });
+
+        assert_eq!(
+            a.merge_face(&b, &mut core),
+            Err(MergeFacesError::NotAdjacent)
+        );
+    }
+}
@@ -38,6 +38,7 @@
 //! assume that the code in question is outdated. Feel free to open an issue or
 //! send a pull request!
 
+pub mod boolean;
 pub mod build;
 pub mod derive;
 pub mod geometry;
@@ -46,6 +47,7 @@ pub mod insert;
 pub mod join;
 pub mod merge;
 pub mod presentation;
+pub mod repair;
 pub mod replace;
 pub mod reverse;
 pub mod split;
@@ -1,6 +1,6 @@
 //! Operations to control the presentation of objects
 
-use fj_interop::Color;
+use fj_interop::{Color, MaterialId};
 
 use crate::{storage::Handle, topology::Region, Core};
 
@@ -29,3 +29,29 @@ impl SetColor for Handle<Region> {
             .set_color(self.clone(), color.into());
     }
 }
+
+/// Get the material of an object
+pub trait GetMaterial {
+    /// Get the material of the object
+    fn get_material(&self, core: &mut Core) -> Option<MaterialId>;
+}
+
+impl GetMaterial for Handle<Region> {
+    fn get_material(&self, core: &mut Core) -> Option<MaterialId> {
+        core.layers.presentation.material.get(self).copied()
+    }
+}
+
+/// Set the material of an object
+pub trait SetMaterial {
+    /// Set the material of the object
+    fn set_material(&self, material: MaterialId, core: &mut Core);
+}
+
+impl SetMaterial for Handle<Region> {
+    fn set_material(&self, material: MaterialId, core: &mut Core) {
+        core.layers
+            .presentation
+            .set_material(self.clone(), material);
+    }
+}
@@ -0,0 +1,557 @@
+//! # Operations to repair invalid objects
+//!
+//! See [`RepairRegion`] and [`RepairShell`].
+
+use std::collections::{BTreeMap, VecDeque};
+
+use fj_math::Point;
+
+use crate::{
+    geometry::{CurveBoundary, Tolerance},
+    queries::{BoundaryEdgesOfShell, BoundingVerticesOfHalfEdge},
+    storage::Handle,
+    topology::{HalfEdge, Region, Shell, Surface, Vertex},
+    Core,
+};
+
+use super::{
+    derive::DeriveFrom,
+    geometry::UpdateCurveGeometry,
+    insert::Insert,
+    replace::{ReplaceCurve, ReplaceVertex},
+    reverse::Reverse,
+};
+
+/// Repair a [`Region`]
+pub trait RepairRegion {
+    /// Reverse all interior cycles whose winding matches the exterior's
+    ///
+    /// The interior cycles of a region must have the opposite winding of its
+    /// exterior cycle (see [`InteriorCycleHasInvalidWinding`]). This operation
+    /// reverses every interior cycle that doesn't, turning it into a valid hole.
+    /// Interior cycles that already have the correct winding are left as they
+    /// are, as are cycles without any half-edges, whose winding can't be
+    /// determined.
+    ///
+    /// Windings are determined in the coordinates of the provided surface.
+    ///
+    /// [`InteriorCycleHasInvalidWinding`]: crate::validation::checks::InteriorCycleHasInvalidWinding
+    #[must_use]
+    fn fix_interior_windings(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Self;
+}
+
+impl RepairRegion for Region {
+    fn fix_interior_windings(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Self {
+        if self.exterior().half_edges().is_empty() {
+            return self.clone();
+        }
+
+        let exterior_winding =
+            self.exterior().winding(&core.layers.geometry, surface);
+
+        let interiors = self
+            .interiors()
+            .iter()
+            .map(|interior| {
+                if interior.half_edges().is_empty()
+                    || interior.winding(&core.layers.geometry, surface)
+                        != exterior_winding
+                {
+                    return interior.clone();
+                }
+
+                interior
+                    .reverse(core)
+                    .insert(core)
+                    .derive_from(interior, core)
+            })
+            .collect::<Vec<_>>();
+
+        Region::new(self.exterior().clone(), interiors)
+    }
+}
+
+/// Repair a [`Shell`]
+pub trait RepairShell {
+    /// Close small gaps between the boundary edges of the shell
+    ///
+    /// Looks for vertices that bound boundary edges of the shell (see
+    /// [`BoundaryEdgesOfShell`]), and whose positions are within `tolerance` of
+    /// each other. Each such pair is merged into a single vertex, until no more
+    /// merges are possible.
+    ///
+    /// Afterwards, boundary edges that are bounded by the same vertices are
+    /// joined, by making them reference the same curve. The geometry of those
+    /// edges is assumed to be a straight line.
+    ///
+    /// Vertices that bound the same edge are never merged, as that would
+    /// collapse the edge.
+    #[must_use]
+    fn stitch_gaps(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> StitchedGaps;
+
+    /// Reverse all faces whose orientation disagrees with their neighbors
+    ///
+    /// Two faces that share an edge are oriented consistently, if their
+    /// half-edges traverse that edge in opposite directions. Starting from the
+    /// first face of the shell, which is assumed to be oriented correctly, this
+    /// operation visits all faces that are connected to it via shared edges,
+    /// and reverses each one that is not consistent with the face it was
+    /// reached from.
+    ///
+    /// If the shell consists of multiple disconnected parts, the first face of
+    /// each part is assumed to be oriented correctly. Edges that are shared by
+    /// more than two faces don't connect faces for the purpose of this
+    /// operation.
+    ///
+    /// This complements [`FaceWindingNormalMismatch`], which detects faces
+    /// that point towards the inside of a shell.
+    ///
+    /// [`FaceWindingNormalMismatch`]: crate::validation::checks::FaceWindingNormalMismatch
+    #[must_use]
+    fn fix_orientations(&self, core: &mut Core) -> Self;
+}
+
+impl RepairShell for Shell {
+    fn stitch_gaps(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> StitchedGaps {
+        let tolerance = tolerance.into();
+
+        let mut shell = self.clone();
+        let mut num_merges = 0;
+
+        while let Some((vertex, merged)) =
+            find_vertices_to_merge(&shell, tolerance, core)
+        {
+            // The merged vertex is replaced on all of its curves, so the vertex
+            // that replaces it must be defined there too.
+            let definitions = core
+                .layers
+                .geometry
+                .of_vertex(&merged)
+                .map(|vertex_geom| vertex_geom.definitions.clone())
+                .unwrap_or_default();
+            for (curve, local_vertex) in definitions {
+                core.layers.geometry.define_vertex(
+                    vertex.clone(),
+                    curve,
+                    local_vertex,
+                );
+            }
+
+            shell = shell.replace_vertex(&merged, vertex, core).into_inner();
+            num_merges += 1;
+        }
+
+        while let Some((half_edge, other)) = find_edges_to_join(&shell) {
+            let curve = half_edge.curve().clone();
+            let replaced = other.curve();
+
+            // The replaced curve is only referenced by `other`, which runs from
+            // `end` to `start`. Define `curve` on the surfaces `replaced` is
+            // defined on, as a line through those vertices.
+            let [start, end] = shell
+                .bounding_vertices_of_half_edge(&half_edge)
+                .expect("Boundary edge must be part of shell")
+                .inner;
+            let definitions = core
+                .layers
+                .geometry
+                .of_curve(replaced)
+                .map(|curve_geom| curve_geom.definitions.clone())
+                .unwrap_or_default();
+            for (surface, local_curve) in definitions {
+                let position_on = |vertex: &Handle<Vertex>, curve| {
+                    core.layers
+                        .geometry
+                        .of_vertex(vertex)
+                        .and_then(|vertex_geom| vertex_geom.local_on(curve))
+                        .expect("Vertex must be defined on curve it bounds")
+                        .position
+                };
+
+                let points_surface = [&end, &start].map(|vertex| {
+                    local_curve
+                        .path
+                        .point_from_path_coords(position_on(vertex, replaced))
+                });
+                let points_curve = CurveBoundary::from(
+                    [&end, &start].map(|vertex| position_on(vertex, &curve)),
+                );
+
+                curve.clone().make_line_on_surface(
+                    points_surface,
+                    points_curve,
+                    surface,
+                    &mut core.layers.geometry,
+                );
+            }
+
+            shell = shell.replace_curve(replaced, curve, core).into_inner();
+        }
+
+        StitchedGaps { shell, num_merges }
+    }
+
+    fn fix_orientations(&self, core: &mut Core) -> Self {
+        let faces = self.faces().iter().collect::<Vec<_>>();
+
+        // The faces that share each edge, and whether they traverse it in the
+        // direction of its normalized boundary.
+        let mut edges = BTreeMap::<_, Vec<_>>::new();
+        for (index, face) in faces.iter().enumerate() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let vertices =
+                        cycle.bounding_vertices_of_half_edge(half_edge).expect(
+                            "`half_edge` came from `cycle`, must exist there",
+                        );
+                    let normalized = vertices.clone().normalize();
+
+                    edges
+                        .entry((half_edge.curve().clone(), normalized.clone()))
+                        .or_default()
+                        .push((index, vertices == normalized));
+                }
+            }
+        }
+
+        let mut neighbors = vec![Vec::new(); faces.len()];
+        for faces_of_edge in edges.into_values() {
+            let [(a, direction_a), (b, direction_b)] = faces_of_edge.as_slice()
+            else {
+                continue;
+            };
+
+            let consistent = direction_a != direction_b;
+            neighbors[*a].push((*b, consistent));
+            neighbors[*b].push((*a, consistent));
+        }
+
+        let mut reverse = vec![None; faces.len()];
+        for seed in 0..faces.len() {
+            if reverse[seed].is_some() {
+                continue;
+            }
+            reverse[seed] = Some(false);
+
+            let mut queue = VecDeque::from([seed]);
+            while let Some(index) = queue.pop_front() {
+                let reverse_face = reverse[index]
+                    .expect("Faces are only queued after being visited");
+
+                for &(neighbor, consistent) in &neighbors[index] {
+                    if reverse[neighbor].is_some() {
+                        continue;
+                    }
+
+                    reverse[neighbor] = Some(if consistent {
+                        reverse_face
+                    } else {
+                        !reverse_face
+                    });
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let faces = faces
+            .into_iter()
+            .zip(reverse)
+            .map(|(face, reverse)| {
+                if reverse == Some(true) {
+                    face.reverse(core).insert(core).derive_from(face, core)
+                } else {
+                    face.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Shell::new(faces)
+    }
+}
+
+/// A shell with gaps stitched
+///
+/// Returned by [`RepairShell::stitch_gaps`].
+pub struct StitchedGaps {
+    /// The repaired shell
+    pub shell: Shell,
+
+    /// The number of vertices that were merged into another vertex
+    pub num_merges: usize,
+}
+
+fn find_vertices_to_merge(
+    shell: &Shell,
+    tolerance: Tolerance,
+    core: &Core,
+) -> Option<(Handle<Vertex>, Handle<Vertex>)> {
+    let edges = shell
+        .boundary_edges()
+        .into_iter()
+        .map(|half_edge| {
+            shell
+                .bounding_vertices_of_half_edge(&half_edge)
+                .expect("Boundary edge must be part of shell")
+                .normalize()
+        })
+        .collect::<Vec<_>>();
+
+    let mut vertices = Vec::<(Handle<Vertex>, Point<3>)>::new();
+    for vertex in edges.iter().flat_map(|edge| edge.inner.iter()) {
+        if vertices.iter().any(|(v, _)| v == vertex) {
+            continue;
+        }
+
+        let Some(position) = core
+            .layers
+            .geometry
+            .vertex_position(vertex, core.tolerance())
+        else {
+            continue;
+        };
+
+        vertices.push((vertex.clone(), position));
+    }
+
+    for (i, (a, position_a)) in vertices.iter().enumerate() {
+        for (b, position_b) in &vertices[i + 1..] {
+            if position_a.distance_to(position_b) > tolerance.inner() {
+                continue;
+            }
+
+            let edge = CurveBoundary::from([a.clone(), b.clone()]).normalize();
+            if edges.contains(&edge) {
+                continue;
+            }
+
+            return Some((a.clone(), b.clone()));
+        }
+    }
+
+    None
+}
+
+fn find_edges_to_join(
+    shell: &Shell,
+) -> Option<(Handle<HalfEdge>, Handle<HalfEdge>)> {
+    let edges = shell
+        .boundary_edges()
+        .into_iter()
+        .map(|half_edge| {
+            let vertices = shell
+                .bounding_vertices_of_half_edge(&half_edge)
+                .expect("Boundary edge must be part of shell");
+            (half_edge, vertices)
+        })
+        .collect::<Vec<_>>();
+
+    for (i, (a, vertices_a)) in edges.iter().enumerate() {
+        for (b, vertices_b) in &edges[i + 1..] {
+            if a.curve().id() != b.curve().id()
+                && *vertices_a == vertices_b.clone().reverse()
+            {
+                return Some((a.clone(), b.clone()));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        fixtures,
+        geometry::Tolerance,
+        operations::{
+            build::{BuildCycle, BuildFace, BuildShell},
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateFace, UpdateRegion, UpdateShell},
+        },
+        queries::BoundaryEdgesOfShell,
+        topology::{Cycle, Face, Shell, Solid},
+        validate::Validate,
+        validation::{
+            checks::{
+                FaceWindingNormalMismatch, InteriorCycleHasInvalidWinding,
+            },
+            ValidationCheck,
+        },
+        Core,
+    };
+
+    use super::{RepairRegion, RepairShell};
+
+    #[test]
+    fn fix_interior_windings() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let invalid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [3., 0.], [0., 3.]],
+            &mut core,
+        )
+        .update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[1., 1.], [2., 1.], [1., 2.]],
+                        surface.clone(),
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        );
+        InteriorCycleHasInvalidWinding::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        let repaired = invalid.update_region(
+            |region, core| region.fix_interior_windings(&surface, core),
+            &mut core,
+        );
+        repaired.validate_and_return_first_error(&core.layers.geometry)?;
+
+        assert_eq!(
+            repaired.region().exterior().id(),
+            invalid.region().exterior().id(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fix_interior_windings_leaves_valid_cycles_untouched() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [3., 0.], [0., 3.]],
+            &mut core,
+        )
+        .update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[1., 1.], [1., 2.], [2., 1.]],
+                        surface.clone(),
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        );
+
+        let region = valid.region().fix_interior_windings(&surface, &mut core);
+
+        assert_eq!(
+            region.interiors().first().id(),
+            valid.region().interiors().first().id(),
+        );
+    }
+
+    #[test]
+    fn stitch_gaps() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+        let top = bottom.map(|[x, y, _]| [x, y, 1.]);
+
+        // The top face doesn't share the vertices of the side faces, but uses
+        // its own, slightly offset, ones.
+        let top_offset = top.map(|[x, y, z]| [x + 1e-4, y, z]);
+
+        let vertices = [bottom, top, top_offset]
+            .into_iter()
+            .flatten()
+            .map(Point::from);
+        let indices = [
+            [0, 2, 1],
+            [0, 3, 2],
+            [8, 9, 10],
+            [8, 10, 11],
+            [0, 1, 5],
+            [0, 5, 4],
+            [1, 2, 6],
+            [1, 6, 5],
+            [2, 3, 7],
+            [2, 7, 6],
+            [3, 0, 4],
+            [3, 4, 7],
+        ];
+
+        let gapped =
+            Shell::from_vertices_and_indices(vertices, indices, &mut core);
+        assert!(!gapped.is_closed());
+
+        let stitched =
+            gapped.stitch_gaps(Tolerance::from_scalar(1e-3)?, &mut core);
+        assert!(stitched.shell.is_closed());
+        assert_eq!(stitched.num_merges, 4);
+        stitched
+            .shell
+            .validate_and_return_first_error(&core.layers.geometry)?;
+
+        let unchanged =
+            gapped.stitch_gaps(Tolerance::from_scalar(1e-5)?, &mut core);
+        assert!(!unchanged.shell.is_closed());
+        assert_eq!(unchanged.num_merges, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fix_orientations() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        let shell = cube.shells().only();
+        let flipped = shell.update_face(
+            shell.faces().nth(2).unwrap(),
+            |face, core| [face.reverse(core)],
+            &mut core,
+        );
+        FaceWindingNormalMismatch::check_and_expect_one_error(
+            &Solid::new([flipped.clone().insert(&mut core)]),
+            &core.layers.geometry,
+        );
+
+        let repaired = flipped.fix_orientations(&mut core).insert(&mut core);
+        FaceWindingNormalMismatch::check_and_return_first_error(
+            &Solid::new([repaired.clone()]),
+            &core.layers.geometry,
+        )?;
+        assert!(repaired.is_closed());
+
+        // Ignore validation errors from inserting the flipped shell.
+        let _ = core.layers.validation.take_errors();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+use fj_math::{FillRule, Point, Polygon};
+
+use crate::{
+    geometry::{Geometry, LocalVertexGeom, Path},
+    operations::{
+        build::BuildHalfEdge, derive::DeriveFrom, insert::Insert,
+        split::SplitHalfEdge, update::UpdateHalfEdge,
+    },
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Surface, Vertex},
+    Core,
+};
+
+/// Split a [`Face`] into two, along a line
+///
+/// This operates on a standalone face. If the face is part of a shell, the
+/// siblings of any split half-edges won't be updated. Use [`SplitFace`] for
+/// that case.
+///
+/// [`SplitFace`]: super::SplitFace
+pub trait SplitFaceAlongLine {
+    /// Split the face into two, along the line between the provided points
+    ///
+    /// Both points are in surface coordinates, and must be located on the
+    /// exterior cycle of the face. If a point is located within a half-edge,
+    /// that half-edge is split there. If it is located at a vertex, that vertex
+    /// is used.
+    ///
+    /// Interior cycles are assigned to the new face that contains them.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if either of the points is not located on a straight half-edge
+    /// of the exterior cycle, or if both points are located at the same
+    /// position.
+    #[must_use]
+    fn split_along_line(
+        &self,
+        from: impl Into<Point<2>>,
+        to: impl Into<Point<2>>,
+        core: &mut Core,
+    ) -> [Handle<Face>; 2];
+}
+
+impl SplitFaceAlongLine for Handle<Face> {
+    fn split_along_line(
+        &self,
+        from: impl Into<Point<2>>,
+        to: impl Into<Point<2>>,
+        core: &mut Core,
+    ) -> [Handle<Face>; 2] {
+        let [from, to] = [from.into(), to.into()];
+        let surface = self.surface();
+        let exterior = self.region().exterior();
+
+        let mut half_edges = exterior.half_edges().iter().cloned().collect();
+        let [from_vertex, to_vertex] = [from, to]
+            .map(|point| split_at_point(&mut half_edges, point, surface, core));
+        assert_ne!(
+            from_vertex.id(),
+            to_vertex.id(),
+            "Can't split face along a line of zero length"
+        );
+
+        // Build the edge that's going to divide the new faces.
+        let dividing_half_edge_from_to = {
+            let (half_edge, boundary) =
+                HalfEdge::line_segment([from, to], surface.clone(), core);
+
+            for (vertex, position) in
+                [&from_vertex, &to_vertex].into_iter().zip(boundary.inner)
+            {
+                core.layers.geometry.define_vertex(
+                    vertex.clone(),
+                    half_edge.curve().clone(),
+                    LocalVertexGeom { position },
+                );
+            }
+
+            half_edge
+                .update_start_vertex(|_, _| from_vertex.clone(), core)
+                .insert(core)
+        };
+        let dividing_half_edge_to_from = HalfEdge::from_sibling(
+            &dividing_half_edge_from_to,
+            to_vertex.clone(),
+            core,
+        );
+
+        let index_of = |vertex: &Handle<Vertex>| {
+            half_edges
+                .iter()
+                .position(|half_edge| half_edge.start_vertex() == vertex)
+                .expect("Vertex has been found in or added to the cycle")
+        };
+        let [from_index, to_index] = [&from_vertex, &to_vertex].map(index_of);
+
+        let half_edges_between = |start: usize, end: usize| {
+            half_edges
+                .iter()
+                .cycle()
+                .skip(start)
+                .take((end + half_edges.len() - start) % half_edges.len())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let exteriors = [
+            (
+                half_edges_between(from_index, to_index),
+                dividing_half_edge_to_from,
+            ),
+            (
+                half_edges_between(to_index, from_index),
+                dividing_half_edge_from_to,
+            ),
+        ]
+        .map(|(mut half_edges, dividing_half_edge)| {
+            half_edges.push(dividing_half_edge);
+            half_edges
+        });
+
+        let polygons = exteriors.each_ref().map(|half_edges| {
+            Polygon::from_points(half_edges.iter().map(|half_edge| {
+                position_on_surface(
+                    half_edge.curve(),
+                    half_edge.start_vertex(),
+                    surface,
+                    &core.layers.geometry,
+                )
+            }))
+        });
+
+        let mut interiors = [Vec::new(), Vec::new()];
+        for interior in self.region().interiors() {
+            let half_edge = interior.half_edges().first();
+            let point = position_on_surface(
+                half_edge.curve(),
+                half_edge.start_vertex(),
+                surface,
+                &core.layers.geometry,
+            );
+
+            let index = if polygons[0].contains(point, FillRule::EvenOdd) {
+                0
+            } else {
+                1
+            };
+            interiors[index].push(interior.clone());
+        }
+
+        let [a, b] = exteriors;
+        let [interiors_a, interiors_b] = interiors;
+
+        [(a, interiors_a), (b, interiors_b)].map(|(exterior, interiors)| {
+            let exterior = Cycle::new(exterior)
+                .insert(core)
+                .derive_from(self.region().exterior(), core);
+            let region = Region::new(exterior, interiors)
+                .insert(core)
+                .derive_from(self.region(), core);
+
+            Face::new(surface.clone(), region)
+                .insert(core)
+                .derive_from(self, core)
+        })
+    }
+}
+
+/// Make sure there's a vertex at the point, splitting a half-edge if necessary
+///
+/// Returns the vertex at the point.
+fn split_at_point(
+    half_edges: &mut Vec<Handle<HalfEdge>>,
+    point: Point<2>,
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) -> Handle<Vertex> {
+    let tolerance = core.tolerance().inner();
+
+    for i in 0..half_edges.len() {
+        let half_edge = half_edges[i].clone();
+        let next = half_edges[(i + 1) % half_edges.len()].clone();
+
+        let geometry = &core.layers.geometry;
+        let Path::Line(line) = geometry
+            .of_curve(half_edge.curve())
+            .unwrap()
+            .local_on(surface)
+            .unwrap()
+            .path
+        else {
+            continue;
+        };
+
+        let [start, end] =
+            [half_edge.start_vertex(), next.start_vertex()].map(|vertex| {
+                geometry
+                    .of_vertex(vertex)
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position
+            });
+
+        if line.point_from_line_coords(start).distance_to(&point) <= tolerance {
+            return half_edge.start_vertex().clone();
+        }
+
+        let position = line.point_to_line_coords(point);
+        let distance_to_line =
+            line.point_from_line_coords(position).distance_to(&point);
+        let [min, max] = if start < end {
+            [start, end]
+        } else {
+            [end, start]
+        };
+
+        if distance_to_line <= tolerance && min < position && position < max {
+            let [a, b] = Cycle::new(half_edges.iter().cloned())
+                .split_half_edge(&half_edge, position, core);
+            let vertex = b.start_vertex().clone();
+
+            half_edges.splice(i..=i, [a, b]);
+
+            return vertex;
+        }
+    }
+
+    panic!("Point {point:?} is not on a straight edge of the face boundary");
+}
+
+fn position_on_surface(
+    curve: &Handle<Curve>,
+    vertex: &Handle<Vertex>,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Point<2> {
+    geometry
+        .of_curve(curve)
+        .unwrap()
+        .local_on(surface)
+        .unwrap()
+        .path
+        .point_from_path_coords(
+            geometry
+                .of_vertex(vertex)
+                .unwrap()
+                .local_on(curve)
+                .unwrap()
+                .position,
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        operations::{
+            build::{BuildCycle, BuildFace},
+            insert::Insert,
+            split::SplitFaceAlongLine,
+            update::{UpdateFace, UpdateRegion},
+        },
+        topology::{Cycle, Face},
+        Core,
+    };
+
+    #[test]
+    fn split_square_into_rectangles() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        )
+        .update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[1.5, 0.5], [1.5, 1.5], [1.75, 1.]],
+                        surface.clone(),
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let [a, b] = square.split_along_line([1., 0.], [1., 2.], &mut core);
+
+        let bounds = [&a, &b].map(|face| {
+            face.uv_bounds(&core.layers.geometry)
+                .expect("Split faces have half-edges")
+        });
+        assert_eq!(
+            bounds,
+            [
+                Aabb {
+                    min: Point::from([1., 0.]),
+                    max: Point::from([2., 2.]),
+                },
+                Aabb {
+                    min: Point::from([0., 0.]),
+                    max: Point::from([1., 2.]),
+                },
+            ]
+        );
+
+        for face in [&a, &b] {
+            assert_eq!(face.region().exterior().half_edges().len(), 4);
+        }
+        assert_eq!(a.region().interiors().len(), 1);
+        assert_eq!(b.region().interiors().len(), 0);
+    }
+}
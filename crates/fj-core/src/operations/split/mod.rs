@@ -7,6 +7,10 @@
 
 mod edge;
 mod face;
+mod face_along_line;
 mod half_edge;
 
-pub use self::{edge::SplitEdge, face::SplitFace, half_edge::SplitHalfEdge};
+pub use self::{
+    edge::SplitEdge, face::SplitFace, face_along_line::SplitFaceAlongLine,
+    half_edge::SplitHalfEdge,
+};
@@ -1,9 +1,12 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
-    operations::{insert::Insert, presentation::GetColor},
+    operations::{
+        derive::DeriveFrom, insert::Insert, presentation::GetColor,
+        reverse::Reverse,
+    },
     storage::Handle,
-    topology::{Face, Shell},
+    topology::{Face, Handedness, Shell},
     Core,
 };
 
@@ -22,6 +25,31 @@ pub trait SweepFace {
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> Shell;
+
+    /// # Sweep the [`Face`] into a [`Shell`], using it as the bottom cap
+    ///
+    /// Unlike [`SweepFace::sweep_face`], this doesn't require `path` to point
+    /// towards the back of the face. If the face is oriented such that it
+    /// can serve as the bottom cap of the resulting shell (its front points
+    /// away from `path`), the face itself is used, and only the side faces
+    /// and the top cap are created. This keeps the object graph shared, for
+    /// example if the face is already part of another solid.
+    ///
+    /// Otherwise, the face is reversed, and the reversed face is used as the
+    /// bottom cap instead.
+    ///
+    /// The bottom cap is the first face of the returned shell.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The orientation of the face is determined at the origin of its
+    /// surface. This is correct for planar faces, which is all that sweeping
+    /// is currently used with.
+    fn sweep_from_face(
+        &self,
+        path: impl Into<Vector<3>>,
+        core: &mut Core,
+    ) -> Shell;
 }
 
 impl SweepFace for Handle<Face> {
@@ -63,6 +91,94 @@ impl SweepFace for Handle<Face> {
         faces.push(bottom_face.clone());
         faces.extend(other_faces);
 
-        Shell::new(faces)
+        let shell = Shell::new(faces);
+        core.layers
+            .validation
+            .validate_swept_shell(&shell, &core.layers.geometry);
+
+        shell
+    }
+
+    fn sweep_from_face(
+        &self,
+        path: impl Into<Vector<3>>,
+        core: &mut Core,
+    ) -> Shell {
+        let path = path.into();
+
+        let normal = {
+            let surface = core.layers.geometry.of_surface(self.surface());
+            let tolerance = core.tolerance();
+
+            let u = surface.vector_from_surface_coords([1., 0.], tolerance);
+            let v = surface.vector_from_surface_coords([0., 1.], tolerance);
+
+            match self.coord_handedness(&core.layers.geometry) {
+                Handedness::RightHanded => u.cross(&v),
+                Handedness::LeftHanded => -u.cross(&v),
+            }
+        };
+
+        let bottom_face = if normal.dot(&path) > Scalar::ZERO {
+            self.reverse(core).insert(core).derive_from(self, core)
+        } else {
+            self.clone()
+        };
+
+        let mut cache = SweepCache::default();
+        bottom_face.sweep_face(path, &mut cache, core)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+        validate::Validate,
+        Core,
+    };
+
+    use super::SweepFace;
+
+    #[test]
+    fn sweep_from_face_reuses_face_as_bottom_cap() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let shell = face.sweep_from_face([0., 0., -1.], &mut core);
+
+        assert_eq!(shell.faces().first().id(), face.id());
+        assert_eq!(shell.faces().len(), 6);
+        shell.validate_and_return_first_error(&core.layers.geometry)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_from_face_reverses_face_if_necessary() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let shell = face.sweep_from_face([0., 0., 1.], &mut core);
+
+        assert_ne!(shell.faces().first().id(), face.id());
+        shell.validate_and_return_first_error(&core.layers.geometry)?;
+
+        Ok(())
     }
 }
@@ -59,22 +59,7 @@ impl SweepHalfEdge for Handle<HalfEdge> {
     ) -> SweptHalfEdge {
         let path = path.into();
 
-        let boundary = [
-            core.layers
-                .geometry
-                .of_vertex(self.start_vertex())
-                .unwrap()
-                .local_on(self.curve())
-                .unwrap()
-                .position,
-            core.layers
-                .geometry
-                .of_vertex(&end_vertex)
-                .unwrap()
-                .local_on(self.curve())
-                .unwrap()
-                .position,
-        ];
+        let boundary = self.curve_boundary(&end_vertex, &core.layers.geometry);
         let curve_geom = core
             .layers
             .geometry
@@ -109,7 +94,7 @@ impl SweepHalfEdge for Handle<HalfEdge> {
 
         // Let's figure out the surface coordinates of the edge vertices.
         let surface_points = {
-            let [a, b] = boundary;
+            let [a, b] = boundary.inner;
 
             [
                 [a.t, Scalar::ZERO],
@@ -127,7 +112,7 @@ impl SweepHalfEdge for Handle<HalfEdge> {
 
         // Now, the boundaries of each edge.
         let boundaries = {
-            let [a, b] = boundary;
+            let [a, b] = boundary.inner;
             let [c, d] = [0., 1.].map(|coord| Point::from([coord]));
 
             [[a, b], [c, d], [b, a], [d, c]]
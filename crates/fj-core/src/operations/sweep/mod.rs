@@ -19,7 +19,7 @@ pub use self::{
     path::SweepSurfacePath,
     region::{SweepRegion, SweptRegion},
     shell_face::{ShellExtendedBySweep, SweepFaceOfShell},
-    sketch::SweepSketch,
+    sketch::{SweepSketch, SweepSketches},
     vertex::SweepVertex,
 };
 
@@ -1,4 +1,4 @@
-use fj_math::{Circle, Line, Vector};
+use fj_math::{Circle, Ellipse, Line, Vector};
 
 use crate::{
     geometry::{Path, SurfaceGeom},
@@ -41,7 +41,7 @@ impl SweepSurfacePath for Path<2> {
     ) -> Handle<Surface> {
         let SurfaceGeom { u, .. } = surface;
         match u {
-            Path::Circle(_) => {
+            Path::Circle(_) | Path::Ellipse(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `Path<3>`, which we are computing below. That
                 // computation might or might not work with an arbitrary
@@ -75,9 +75,26 @@ impl SweepSurfacePath for Path<2> {
                 let b = surface
                     .vector_from_surface_coords(circle.b(), core.tolerance());
 
-                let circle = Circle::new(center, a, b);
+                // The surface might not be orthonormal, for example if it has
+                // been scaled non-uniformly. Then the circle is an ellipse in
+                // global coordinates.
+                if Ellipse::new(center, a, b).is_circle() {
+                    Path::Circle(Circle::new(center, a, b))
+                } else {
+                    Path::Ellipse(Ellipse::new(center, a, b))
+                }
+            }
+            Path::Ellipse(ellipse) => {
+                let center = surface.point_from_surface_coords(
+                    ellipse.center(),
+                    core.tolerance(),
+                );
+                let a = surface
+                    .vector_from_surface_coords(ellipse.a(), core.tolerance());
+                let b = surface
+                    .vector_from_surface_coords(ellipse.b(), core.tolerance());
 
-                Path::Circle(circle)
+                Path::Ellipse(Ellipse::new(center, a, b))
             }
             Path::Line(line) => {
                 let origin = surface
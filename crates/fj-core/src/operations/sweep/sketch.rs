@@ -1,7 +1,8 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
-    operations::insert::Insert,
+    geometry::Path,
+    operations::{derive::DeriveFrom, insert::Insert, reverse::Reverse},
     storage::Handle,
     topology::{Face, Sketch, Solid, Surface},
     Core,
@@ -43,6 +44,43 @@ pub trait SweepSketch {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Solid;
+
+    /// # Sweep the [`Sketch`], using the provided cache
+    ///
+    /// Works like [`SweepSketch::sweep_sketch`], but uses the provided cache
+    /// instead of a fresh one. Sweeping multiple sketches with the same cache
+    /// results in shared objects, where the sketches share vertices or
+    /// curves.
+    fn sweep_sketch_with_cache(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        cache: &mut SweepCache,
+        core: &mut Core,
+    ) -> Solid;
+
+    /// # Extrude the [`Sketch`] along the normal of the provided surface
+    ///
+    /// Sweeps the sketch along the normal of `surface`, scaled by `distance`.
+    /// Negative distances extrude in the opposite direction.
+    ///
+    /// The normal is derived from the geometry that is defined for `surface`,
+    /// so this works for any plane, not just the basis planes.
+    ///
+    /// Unlike [`SweepSketch::sweep_sketch`], this doesn't require the path to
+    /// point towards the back of `surface`. If the distance is positive, the
+    /// regions of the sketch are reversed before sweeping, so the result is a
+    /// valid solid either way.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if `surface` is not a plane.
+    fn extrude(
+        &self,
+        distance: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Solid;
 }
 
 impl SweepSketch for Sketch {
@@ -52,8 +90,18 @@ impl SweepSketch for Sketch {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Solid {
-        let path = path.into();
         let mut cache = SweepCache::default();
+        self.sweep_sketch_with_cache(surface, path, &mut cache, core)
+    }
+
+    fn sweep_sketch_with_cache(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        cache: &mut SweepCache,
+        core: &mut Core,
+    ) -> Solid {
+        let path = path.into();
 
         let mut shells = Vec::new();
         for region in self.regions() {
@@ -76,10 +124,235 @@ impl SweepSketch for Sketch {
             }
 
             let face = Face::new(surface.clone(), region.clone()).insert(core);
-            let shell = face.sweep_face(path, &mut cache, core).insert(core);
+            let shell = face.sweep_face(path, cache, core).insert(core);
             shells.push(shell);
         }
 
         Solid::new(shells)
     }
+
+    fn extrude(
+        &self,
+        distance: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Solid {
+        let surface_geom = core.layers.geometry.of_surface(surface);
+        let Path::Line(u) = surface_geom.u else {
+            panic!("Extruding along the normal of a curved surface");
+        };
+        let normal = u.direction().cross(&surface_geom.v).normalize();
+        let distance = distance.into();
+
+        if distance <= Scalar::ZERO {
+            return self.sweep_sketch(surface.clone(), normal * distance, core);
+        }
+
+        // The path points towards the front of `surface`. Reversing the
+        // regions makes the faces point the other way, away from the path.
+        let regions = self
+            .regions()
+            .iter()
+            .map(|region| {
+                region.reverse(core).insert(core).derive_from(region, core)
+            })
+            .collect::<Vec<_>>();
+        Sketch::new(self.surface().clone(), regions).sweep_sketch(
+            surface.clone(),
+            normal * distance,
+            core,
+        )
+    }
+}
+
+/// # Sweep multiple [`Sketch`]es
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait SweepSketches {
+    /// # Sweep all sketches, each along its own path
+    ///
+    /// All sketches are swept using the same [`SweepCache`]. Where sketches
+    /// share vertices or curves, the objects created by sweeping those are
+    /// shared between the resulting solids too.
+    ///
+    /// The same requirements as for [`SweepSketch::sweep_sketch`] apply to
+    /// each sketch.
+    fn sweep_all(
+        &self,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Vec<Solid>;
+}
+
+impl SweepSketches for [(Handle<Sketch>, Vector<3>)] {
+    fn sweep_all(
+        &self,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Vec<Solid> {
+        let mut cache = SweepCache::default();
+
+        self.iter()
+            .map(|(sketch, path)| {
+                sketch.sweep_sketch_with_cache(
+                    surface.clone(),
+                    *path,
+                    &mut cache,
+                    core,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::approx::Approx,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            join::JoinCycle,
+            update::UpdateSketch,
+        },
+        storage::ObjectId,
+        topology::{Cycle, Region, Sketch, Solid, Surface},
+        validate::Validate,
+        Core,
+    };
+
+    use super::{SweepSketch, SweepSketches};
+
+    #[test]
+    fn extrude() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let sketch = Sketch::empty(&core.layers.topology).add_regions(
+            [Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                surface,
+                &mut core,
+            )],
+            &mut core,
+        );
+
+        let xy_plane = core.layers.topology.surfaces.xy_plane();
+        for (distance, expected) in [(2., [0., 2.]), (-2., [-2., 0.])] {
+            let solid = sketch.extrude(distance, &xy_plane, &mut core);
+            solid.validate_and_return_first_error(&core.layers.geometry)?;
+
+            let heights = (&solid)
+                .approx(core.tolerance(), &core.layers.geometry)
+                .iter()
+                .flat_map(|face| face.points())
+                .map(|point| point.global_form.z)
+                .collect::<Vec<_>>();
+            let min = heights.iter().copied().min();
+            let max = heights.iter().copied().max();
+
+            assert_eq!([min, max], expected.map(|z| Some(Scalar::from(z))));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn extrude_from_tilted_plane() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let origin = Point::from([1., 2., 3.]);
+        let normal = Vector::from([0., 1., 1.]).normalize();
+        let surface = Surface::new().insert(&mut core);
+        core.layers.geometry.define_plane_from_normal(
+            surface.clone(),
+            origin,
+            normal,
+        );
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        let sketch = Sketch::empty(&core.layers.topology).add_regions(
+            [Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                space_2d,
+                &mut core,
+            )],
+            &mut core,
+        );
+
+        let solid = sketch.extrude(2., &surface, &mut core);
+        solid.validate_and_return_first_error(&core.layers.geometry)?;
+
+        let distances = (&solid)
+            .approx(core.tolerance(), &core.layers.geometry)
+            .iter()
+            .flat_map(|face| face.points())
+            .map(|point| (point.global_form - origin).dot(&normal))
+            .collect::<Vec<_>>();
+        let min = distances.iter().copied().min().unwrap();
+        let max = distances.iter().copied().max().unwrap();
+
+        assert_abs_diff_eq!(min, Scalar::ZERO, epsilon = Scalar::from(1e-12));
+        assert_abs_diff_eq!(
+            max,
+            Scalar::from(2.),
+            epsilon = Scalar::from(1e-12)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_all_shares_objects() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let a = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            surface.clone(),
+            &mut core,
+        );
+        let b = Cycle::polygon(
+            [[1., 1.], [1., 0.], [2., 0.], [2., 1.]],
+            surface.clone(),
+            &mut core,
+        )
+        .join_to(&a, 0..=0, 1..=1, surface.clone(), &mut core);
+
+        let [a, b] = [a, b].map(|cycle| {
+            let region = Region::new(cycle.insert(&mut core), []);
+            Sketch::empty(&core.layers.topology)
+                .add_regions([region], &mut core)
+                .insert(&mut core)
+        });
+
+        let path = Vector::from([0., 0., -1.]);
+        let solids = [(a, path), (b, path)]
+            .sweep_all(core.layers.topology.surfaces.xy_plane(), &mut core);
+
+        let [a, b] = [&solids[0], &solids[1]].map(curves_of);
+        let shared = a.intersection(&b).count();
+
+        // The curve that the sketches shared, and the curves that result from
+        // sweeping its vertices.
+        assert_eq!(shared, 3);
+    }
+
+    fn curves_of(solid: &Solid) -> BTreeSet<ObjectId> {
+        solid
+            .shells()
+            .iter()
+            .flat_map(|shell| shell.faces().iter())
+            .flat_map(|face| face.region().all_cycles())
+            .flat_map(|cycle| cycle.half_edges().iter())
+            .map(|half_edge| half_edge.curve().id())
+            .collect()
+    }
 }
@@ -6,13 +6,14 @@ mod face;
 mod half_edge;
 mod region;
 mod shell;
+mod sketch;
 mod solid;
 mod surface;
 mod vertex;
 
 use std::collections::{btree_map, BTreeMap};
 
-use fj_math::{Transform, Vector};
+use fj_math::{Point, Transform, Vector};
 use type_map::TypeMap;
 
 use crate::{
@@ -24,6 +25,8 @@ use crate::{
 
 use super::derive::DeriveFrom;
 
+pub use self::sketch::TransformSketch;
+
 /// Transform an object
 ///
 /// # Implementation Note
@@ -76,6 +79,27 @@ pub trait TransformObject: Sized {
     ) -> Self::Transformed {
         self.transform(&Transform::rotation(axis_angle), core)
     }
+
+    /// Scale the object, non-uniformly, around the provided center
+    ///
+    /// Each axis is scaled by the respective component of `factors`. Circles
+    /// become ellipses, if they aren't scaled uniformly.
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`].
+    fn scale(
+        self,
+        factors: impl Into<Vector<3>>,
+        center: impl Into<Point<3>>,
+        core: &mut Core,
+    ) -> Self::Transformed {
+        let center = center.into().coords;
+
+        let transform = Transform::translation(center)
+            * Transform::scale_non_uniform(factors)
+            * Transform::translation(-center);
+
+        self.transform(&transform, core)
+    }
 }
 
 impl<T> TransformObject for Handle<T>
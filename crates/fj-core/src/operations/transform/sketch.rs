@@ -0,0 +1,293 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    geometry::{LocalCurveGeom, Path},
+    operations::{derive::DeriveFrom, insert::Insert},
+    storage::{Handle, ObjectId},
+    topology::{Curve, Cycle, HalfEdge, Region, Sketch, Vertex},
+    Core,
+};
+
+/// Transform a [`Sketch`] within its surface
+///
+/// This is simpler than [`TransformObject`], which transforms objects in 3D
+/// space. Here, the surface of the sketch stays the same, and only the regions
+/// are moved around on it.
+///
+/// [`TransformObject`]: super::TransformObject
+pub trait TransformSketch {
+    /// Translate the sketch by the provided offset, in surface coordinates
+    #[must_use]
+    fn translate(&self, offset: impl Into<Vector<2>>, core: &mut Core) -> Self;
+
+    /// Rotate the sketch around the provided center, in surface coordinates
+    ///
+    /// The angle is in radians. Positive angles rotate counter-clockwise, from
+    /// the u-axis towards the v-axis.
+    #[must_use]
+    fn rotate(
+        &self,
+        angle: impl Into<Scalar>,
+        center: impl Into<Point<2>>,
+        core: &mut Core,
+    ) -> Self;
+}
+
+impl TransformSketch for Sketch {
+    fn translate(&self, offset: impl Into<Vector<2>>, core: &mut Core) -> Self {
+        let transform = Transform::translation(offset.into().to_xyz());
+        transform_sketch(self, &transform, core)
+    }
+
+    fn rotate(
+        &self,
+        angle: impl Into<Scalar>,
+        center: impl Into<Point<2>>,
+        core: &mut Core,
+    ) -> Self {
+        let center = center.into().to_xyz().coords;
+        let transform = Transform::translation(center)
+            * Transform::rotation(Vector::unit_z() * angle.into())
+            * Transform::translation(-center);
+
+        transform_sketch(self, &transform, core)
+    }
+}
+
+/// Transform the sketch, using a transform that operates in the xy-plane
+fn transform_sketch(
+    sketch: &Sketch,
+    transform: &Transform,
+    core: &mut Core,
+) -> Sketch {
+    let mut curves = BTreeMap::new();
+    let mut vertices = BTreeMap::new();
+
+    let regions = sketch
+        .regions()
+        .iter()
+        .map(|region| {
+            let mut transform_cycle = |cycle: &Handle<Cycle>| {
+                transform_cycle(
+                    cycle,
+                    sketch,
+                    transform,
+                    &mut curves,
+                    &mut vertices,
+                    core,
+                )
+            };
+
+            let exterior = transform_cycle(region.exterior());
+            let interiors = region
+                .interiors()
+                .iter()
+                .map(transform_cycle)
+                .collect::<Vec<_>>();
+
+            Region::new(exterior, interiors)
+                .insert(core)
+                .derive_from(region, core)
+        })
+        .collect::<Vec<_>>();
+
+    Sketch::new(sketch.surface().clone(), regions)
+}
+
+fn transform_cycle(
+    cycle: &Handle<Cycle>,
+    sketch: &Sketch,
+    transform: &Transform,
+    curves: &mut BTreeMap<ObjectId, Handle<Curve>>,
+    vertices: &mut BTreeMap<ObjectId, Handle<Vertex>>,
+    core: &mut Core,
+) -> Handle<Cycle> {
+    let mut transform_vertex = |vertex: &Handle<Vertex>, core: &mut Core| {
+        vertices
+            .entry(vertex.id())
+            .or_insert_with(|| Vertex::new().insert(core))
+            .clone()
+    };
+
+    let half_edges = cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next)| {
+            let curve = half_edge.curve();
+
+            let transformed_curve = match curves.get(&curve.id()) {
+                Some(transformed_curve) => transformed_curve.clone(),
+                None => {
+                    let path = core
+                        .layers
+                        .geometry
+                        .of_curve(curve)
+                        .unwrap()
+                        .local_on(sketch.surface())
+                        .unwrap()
+                        .path;
+                    let transformed_curve = Curve::new().insert(core);
+
+                    core.layers.geometry.define_curve(
+                        transformed_curve.clone(),
+                        sketch.surface().clone(),
+                        LocalCurveGeom {
+                            path: transform_path(path, transform),
+                        },
+                    );
+
+                    curves.insert(curve.id(), transformed_curve.clone());
+                    transformed_curve
+                }
+            };
+
+            // Vertex positions are defined in curve coordinates, so they don't
+            // change. They need to be defined for the transformed vertices on
+            // the transformed curve, though.
+            for vertex in [half_edge.start_vertex(), next.start_vertex()] {
+                let geometry = core
+                    .layers
+                    .geometry
+                    .of_vertex(vertex)
+                    .unwrap()
+                    .local_on(curve)
+                    .unwrap()
+                    .clone();
+                let transformed_vertex = transform_vertex(vertex, core);
+
+                core.layers.geometry.define_vertex(
+                    transformed_vertex,
+                    transformed_curve.clone(),
+                    geometry,
+                );
+            }
+
+            let start_vertex = transform_vertex(half_edge.start_vertex(), core);
+            HalfEdge::new(transformed_curve, start_vertex)
+                .insert(core)
+                .derive_from(half_edge, core)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges).insert(core).derive_from(cycle, core)
+}
+
+fn transform_path(path: Path<2>, transform: &Transform) -> Path<2> {
+    let xy = |point: Point<3>| Point::from([point.x, point.y]);
+
+    match path {
+        Path::Circle(circle) => {
+            let circle = Circle::new(
+                circle.center().to_xyz(),
+                circle.a().to_xyz(),
+                circle.b().to_xyz(),
+            )
+            .transform(transform);
+
+            Path::Circle(Circle::new(
+                xy(circle.center()),
+                circle.a().xy(),
+                circle.b().xy(),
+            ))
+        }
+        Path::Ellipse(ellipse) => {
+            let ellipse = Ellipse::new(
+                ellipse.center().to_xyz(),
+                ellipse.a().to_xyz(),
+                ellipse.b().to_xyz(),
+            )
+            .transform(transform);
+
+            Path::Ellipse(Ellipse::new(
+                xy(ellipse.center()),
+                ellipse.a().xy(),
+                ellipse.b().xy(),
+            ))
+        }
+        Path::Line(line) => {
+            let line = Line::from_origin_and_direction(
+                line.origin().to_xyz(),
+                line.direction().to_xyz(),
+            )
+            .transform(transform);
+
+            Path::Line(Line::from_origin_and_direction(
+                xy(line.origin()),
+                line.direction().xy(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume,
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch},
+        Core,
+    };
+
+    use super::TransformSketch;
+
+    fn square(core: &mut Core) -> Sketch {
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        Sketch::empty(&core.layers.topology).add_regions(
+            [Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                surface,
+                core,
+            )],
+            core,
+        )
+    }
+
+    fn bounds(sketch: &Sketch, core: &Core) -> Aabb<2> {
+        let exterior = sketch.regions().only().exterior();
+        (&**exterior, sketch.surface())
+            .aabb(&core.layers.geometry)
+            .unwrap()
+    }
+
+    #[test]
+    fn translate() {
+        let mut core = Core::new();
+
+        let square = square(&mut core);
+        let translated = square.translate([2., 3.], &mut core);
+
+        let bounds = bounds(&translated, &core);
+        let centroid = bounds.min + (bounds.max - bounds.min) / 2.;
+        assert_eq!(centroid, Point::from([2.5, 3.5]));
+    }
+
+    #[test]
+    fn rotate() {
+        let mut core = Core::new();
+
+        let square = square(&mut core);
+        let rotated = square.rotate(FRAC_PI_2, [0., 0.], &mut core);
+
+        let bounds = bounds(&rotated, &core);
+        approx::assert_abs_diff_eq!(
+            bounds.min,
+            Point::from([-1., 0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        approx::assert_abs_diff_eq!(
+            bounds.max,
+            Point::from([0., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
@@ -21,3 +21,65 @@ impl TransformObject for Solid {
         Self::new(shells)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume, fixtures,
+        topology::CurveKind, Core,
+    };
+
+    use super::TransformObject;
+
+    #[test]
+    fn scale_cylinder_non_uniformly() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+
+        let scaled = cylinder.scale([2., 1., 1.], Point::origin(), &mut core);
+
+        // Every circular edge of the cylinder has become an ellipse.
+        let mut num_ellipses = 0;
+        for face in scaled.shells().only().faces() {
+            for half_edge in face.region().exterior().half_edges() {
+                match half_edge.curve_kind(&core.layers.geometry) {
+                    CurveKind::Line => {}
+                    CurveKind::Ellipse {
+                        major_radius,
+                        minor_radius,
+                    } => {
+                        approx::assert_abs_diff_eq!(
+                            major_radius,
+                            Scalar::from(2.),
+                            epsilon = Scalar::from(1e-12),
+                        );
+                        approx::assert_abs_diff_eq!(
+                            minor_radius,
+                            Scalar::ONE,
+                            epsilon = Scalar::from(1e-12),
+                        );
+                        num_ellipses += 1;
+                    }
+                    kind => panic!("Unexpected curve kind: {kind:?}"),
+                }
+            }
+        }
+        assert!(num_ellipses > 0);
+
+        // Bounding volumes are padded a bit, hence the generous epsilon.
+        let aabb = (&scaled).aabb(&core.layers.geometry).unwrap();
+        approx::assert_abs_diff_eq!(
+            aabb.min,
+            Point::from([-2., -1., -1.]),
+            epsilon = Scalar::from(1e-2),
+        );
+        approx::assert_abs_diff_eq!(
+            aabb.max,
+            Point::from([2., 1., 0.]),
+            epsilon = Scalar::from(1e-2),
+        );
+    }
+}
@@ -4,23 +4,30 @@
 
 use std::collections::BTreeMap;
 
-use fj_interop::Color;
+use fj_interop::{Color, MaterialId};
 
 use crate::{storage::Handle, topology::Region};
 
 /// Presentation data for the object graph
 ///
-/// Assigns attributes relating to the presentation of objects (currently just a
-/// color) to those objects (currently only to regions).
+/// Assigns attributes relating to the presentation of objects (currently a
+/// color and a material) to those objects (currently only to regions).
 ///
 /// This data is made available through [`Layers`].
 ///
 /// [`Layers`]: crate::layers::Layers
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Presentation {
     /// Color assigned to regions
     ///
     /// Having a color is optional, so map does not necessarily contain
     /// assignments for all existing regions.
     pub color: BTreeMap<Handle<Region>, Color>,
+
+    /// Material assigned to regions
+    ///
+    /// Having a material is optional, so map does not necessarily contain
+    /// assignments for all existing regions. Regions without a material are
+    /// exported with a material that is based on their color.
+    pub material: BTreeMap<Handle<Region>, MaterialId>,
 }
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    geometry::CurveBoundary,
+    storage::Handle,
+    topology::{Curve, HalfEdge, Shell, Vertex},
+};
+
+use super::{BoundingVerticesOfHalfEdge, SiblingOfHalfEdge};
+
+/// Determine the open edges of a [`Shell`]
+pub trait BoundaryEdgesOfShell {
+    /// Return the half-edges that don't have a sibling in the shell
+    ///
+    /// These half-edges bound the holes in the shell. The result is empty, if
+    /// the shell is closed.
+    fn boundary_edges(&self) -> Vec<Handle<HalfEdge>>;
+
+    /// Indicate whether the shell is closed
+    ///
+    /// A shell is closed (watertight), if every edge is shared by exactly two
+    /// half-edges, which must be siblings of each other. This implies that it
+    /// has no boundary edges.
+    fn is_closed(&self) -> bool;
+}
+
+impl BoundaryEdgesOfShell for Shell {
+    fn boundary_edges(&self) -> Vec<Handle<HalfEdge>> {
+        half_edges_by_edge(self)
+            .into_values()
+            .filter_map(|half_edges| match half_edges.as_slice() {
+                [half_edge] => Some(half_edge.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_closed(&self) -> bool {
+        half_edges_by_edge(self).into_values().all(
+            |half_edges| match half_edges.as_slice() {
+                [a, b] => self.are_siblings(a, b),
+                _ => false,
+            },
+        )
+    }
+}
+
+type EdgeKey = (Handle<Curve>, CurveBoundary<Vertex>);
+
+/// Group the half-edges of a shell by the edge they are part of
+///
+/// Half-edges are part of the same edge, if they reference the same curve and
+/// are bounded by the same vertices, regardless of direction.
+pub(super) fn half_edges_by_edge(
+    shell: &Shell,
+) -> BTreeMap<EdgeKey, Vec<Handle<HalfEdge>>> {
+    let mut edges = BTreeMap::<_, Vec<_>>::new();
+
+    for face in shell.faces() {
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let vertices = cycle
+                    .bounding_vertices_of_half_edge(half_edge)
+                    .expect("`half_edge` came from `cycle`, must exist there");
+
+                let key = (half_edge.curve().clone(), vertices.normalize());
+                edges.entry(key).or_default().push(half_edge.clone());
+            }
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures, operations::update::UpdateShell, queries::SiblingOfHalfEdge,
+        topology::Shell, Core,
+    };
+
+    use super::BoundaryEdgesOfShell;
+
+    fn cube(core: &mut Core) -> Shell {
+        let solid = fixtures::unit_cube(core);
+
+        solid.shells().only().clone_object()
+    }
+
+    #[test]
+    fn closed_shell() {
+        let mut core = Core::new();
+        let cube = cube(&mut core);
+
+        assert!(cube.is_closed());
+        assert!(cube.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn open_shell() {
+        let mut core = Core::new();
+        let cube = cube(&mut core);
+
+        let removed = cube.faces().first().clone();
+        let open = cube.remove_face(&removed);
+
+        assert!(!open.is_closed());
+
+        let boundary_edges = open.boundary_edges();
+        assert_eq!(boundary_edges.len(), 4);
+        for half_edge in boundary_edges {
+            let sibling = cube
+                .get_sibling_of(&half_edge)
+                .expect("Edge in cube must have a sibling");
+            assert_eq!(sibling.face.id(), removed.id());
+        }
+    }
+}
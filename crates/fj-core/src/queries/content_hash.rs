@@ -0,0 +1,158 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use fj_math::Point;
+
+use crate::{
+    geometry::{Geometry, Path, SurfaceGeom},
+    topology::{Cycle, Face, Solid},
+};
+
+/// Hash the content of an object, rather than its identity
+pub trait ContentHash {
+    /// Compute a hash of the object's geometry
+    ///
+    /// Two objects that are built the same way have the same content hash,
+    /// even though they consist of different objects. This makes the hash
+    /// suitable as a key for caching results that only depend on geometry,
+    /// like exports or approximations.
+    ///
+    /// The order in which faces and half-edges are stored does not affect the
+    /// hash. Each of them is canonicalized by sorting it by its geometry.
+    ///
+    /// The hash is only stable within a single build of the program. It must
+    /// not be persisted.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of any curve or vertex of the object is not
+    /// defined.
+    fn content_hash(&self, geometry: &Geometry) -> u64;
+}
+
+impl ContentHash for Solid {
+    fn content_hash(&self, geometry: &Geometry) -> u64 {
+        let mut shells = self
+            .shells()
+            .iter()
+            .map(|shell| {
+                let mut faces = shell
+                    .faces()
+                    .iter()
+                    .map(|face| canonical_face(face, geometry))
+                    .collect::<Vec<_>>();
+                faces.sort();
+                faces
+            })
+            .collect::<Vec<_>>();
+        shells.sort();
+
+        let mut hasher = DefaultHasher::new();
+        shells.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl ContentHash for Face {
+    fn content_hash(&self, geometry: &Geometry) -> u64 {
+        hash_canonical_face(&canonical_face(self, geometry))
+    }
+}
+
+/// The content of a face, independent of the objects it consists of
+///
+/// Two faces have the same content hash, if their canonical forms are equal.
+/// Comparing those instead of the hashes rules out hash collisions.
+pub(crate) type CanonicalFace =
+    (SurfaceGeom, CanonicalCycle, Vec<CanonicalCycle>);
+type CanonicalCycle = Vec<(Path<2>, Point<1>)>;
+
+/// Hash the canonical form of a face
+///
+/// Results in the same value as [`ContentHash::content_hash`].
+pub(crate) fn hash_canonical_face(face: &CanonicalFace) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    face.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub(crate) fn canonical_face(
+    face: &Face,
+    geometry: &Geometry,
+) -> CanonicalFace {
+    let exterior = canonical_cycle(face.region().exterior(), face, geometry);
+
+    let mut interiors = face
+        .region()
+        .interiors()
+        .iter()
+        .map(|cycle| canonical_cycle(cycle, face, geometry))
+        .collect::<Vec<_>>();
+    interiors.sort();
+
+    (*geometry.of_surface(face.surface()), exterior, interiors)
+}
+
+fn canonical_cycle(
+    cycle: &Cycle,
+    face: &Face,
+    geometry: &Geometry,
+) -> CanonicalCycle {
+    // Each half-edge is represented by its path on the face's surface, and
+    // the position of its start vertex on that path. All of this is local
+    // geometry, but it's local to the surface, whose geometry is part of the
+    // canonical face.
+    let mut half_edges = cycle
+        .half_edges()
+        .iter()
+        .map(|half_edge| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(face.surface()))
+                .expect("Expected geometry of curve to be defined on surface")
+                .path;
+            let start = geometry
+                .of_vertex(half_edge.start_vertex())
+                .and_then(|vertex| vertex.local_on(half_edge.curve()))
+                .expect("Expected geometry of vertex to be defined on curve")
+                .position;
+
+            (path, start)
+        })
+        .collect::<Vec<_>>();
+    half_edges.sort();
+
+    half_edges
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixtures, topology::Solid, Core};
+
+    use super::ContentHash;
+
+    #[test]
+    fn same_cube_built_twice() {
+        let mut core = Core::new();
+
+        let a = cube([0., 0.], &mut core);
+        let b = cube([0., 0.], &mut core);
+        let c = cube([1., 0.], &mut core);
+
+        assert_ne!(a.shells().only(), b.shells().only());
+        assert_eq!(
+            a.content_hash(&core.layers.geometry),
+            b.content_hash(&core.layers.geometry),
+        );
+        assert_ne!(
+            a.content_hash(&core.layers.geometry),
+            c.content_hash(&core.layers.geometry),
+        );
+    }
+
+    fn cube([x, y]: [f64; 2], core: &mut Core) -> Solid {
+        fixtures::cuboid([x, y, -1.], [x + 1., y + 1., 0.], core)
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+
+use crate::topology::{AnyObject, Solid, Stored};
+
+use super::ReachableObjects;
+
+/// Compare the object graphs of two objects
+pub trait DiffObjects {
+    /// Determine which objects are shared with another object, by identity
+    ///
+    /// Considers all objects that are referenced by `self` and `other`,
+    /// directly or indirectly, but not `self` and `other` themselves.
+    ///
+    /// Objects are immutable, so an object that is unchanged between two
+    /// versions of a model is shared between both, and is reported as common.
+    /// An object that was changed is replaced by a new one, and the same is
+    /// true for all objects that reference it.
+    ///
+    /// This compares object graphs. To compare the objects of two [`Core`]s,
+    /// use [`Core::diff`].
+    ///
+    /// [`Core`]: crate::Core
+    /// [`Core::diff`]: crate::Core::diff
+    fn diff(&self, other: &Self) -> ModelDiff;
+}
+
+impl DiffObjects for Solid {
+    fn diff(&self, other: &Self) -> ModelDiff {
+        let [a, b] = [self, other].map(|solid| solid.reachable_objects());
+        ModelDiff::from_sets(&a, &b)
+    }
+}
+
+/// The result of [`DiffObjects::diff`] and [`Core::diff`]
+///
+/// [`Core::diff`]: crate::Core::diff
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModelDiff {
+    /// The objects that are only referenced by the object `diff` was called on
+    pub only_in_self: BTreeSet<AnyObject<Stored>>,
+
+    /// The objects that are only referenced by the other object
+    pub only_in_other: BTreeSet<AnyObject<Stored>>,
+
+    /// The objects that are referenced by both
+    pub common: BTreeSet<AnyObject<Stored>>,
+}
+
+impl ModelDiff {
+    pub(crate) fn from_sets(
+        a: &BTreeSet<AnyObject<Stored>>,
+        b: &BTreeSet<AnyObject<Stored>>,
+    ) -> Self {
+        Self {
+            only_in_self: a.difference(b).cloned().collect(),
+            only_in_other: b.difference(a).cloned().collect(),
+            common: a.intersection(b).cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        operations::{
+            reverse::Reverse,
+            update::{UpdateShell, UpdateSolid},
+        },
+        topology::AnyObject,
+        Core,
+    };
+
+    use super::DiffObjects;
+
+    #[test]
+    fn diff_after_editing_face() {
+        let mut core = Core::new();
+
+        let original = fixtures::unit_cube(&mut core);
+
+        let mut fork = core.fork();
+        let shell = original.shells().only();
+        let face = shell.faces().first();
+        let edited = original.update_shell(
+            shell,
+            |shell, core| {
+                [shell.update_face(
+                    face,
+                    |face, core| [face.reverse(core)],
+                    core,
+                )]
+            },
+            &mut fork,
+        );
+
+        let diff = original.diff(&edited);
+
+        // Reversing the face replaces it, its region, its cycle, and that
+        // cycle's half-edges. Since the face changed, so did the shell.
+        let removed = diff
+            .only_in_self
+            .iter()
+            .filter(|object| matches!(object, AnyObject::HalfEdge(_)))
+            .count();
+        assert_eq!(removed, 4);
+        assert_eq!(diff.only_in_self.len(), 8);
+        assert_eq!(diff.only_in_other.len(), 8);
+        assert!(diff.only_in_self.contains(&face.clone().into()));
+        assert!(diff.only_in_self.contains(&shell.clone().into()));
+
+        // The face's curves, vertices, and surface are unaffected.
+        for half_edge in face.region().exterior().half_edges() {
+            assert!(diff.common.contains(&half_edge.curve().clone().into()));
+            assert!(diff
+                .common
+                .contains(&half_edge.start_vertex().clone().into()));
+        }
+        assert!(diff.common.contains(&face.surface().clone().into()));
+
+        // Ignore validation errors caused by the reversed face.
+        let _ = fork.layers.validation.take_errors();
+    }
+}
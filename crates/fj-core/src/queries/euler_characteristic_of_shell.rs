@@ -0,0 +1,129 @@
+use std::collections::BTreeSet;
+
+use crate::topology::Shell;
+
+use super::{
+    boundary_edges_of_shell::half_edges_by_edge, BoundaryEdgesOfShell,
+};
+
+/// Determine the Euler characteristic and genus of a [`Shell`]
+pub trait EulerCharacteristicOfShell {
+    /// Compute the Euler characteristic of the shell
+    ///
+    /// This is `V - E + F`, with `V` being the number of distinct vertices,
+    /// `E` the number of edges (each shared by up to two half-edges), and `F`
+    /// the number of faces.
+    ///
+    /// A face with interior cycles is not simply connected, and each interior
+    /// cycle reduces the Euler characteristic by one. Without this correction,
+    /// a cube with a hole through it would have the same Euler characteristic
+    /// as a cube.
+    fn euler_characteristic(&self) -> i64;
+
+    /// Compute the genus of the shell
+    ///
+    /// The genus is the number of handles of the shell. A cube has a genus of
+    /// `0`, a torus has a genus of `1`.
+    ///
+    /// Returns `None`, if the shell is not closed, or if its Euler
+    /// characteristic doesn't correspond to a single connected surface.
+    fn genus(&self) -> Option<i64>;
+}
+
+impl EulerCharacteristicOfShell for Shell {
+    fn euler_characteristic(&self) -> i64 {
+        let mut vertices = BTreeSet::new();
+        let mut num_interiors = 0;
+
+        for face in self.faces() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    vertices.insert(half_edge.start_vertex().clone());
+                }
+            }
+
+            num_interiors += face.region().interiors().len();
+        }
+
+        let num_edges = half_edges_by_edge(self).len();
+        let num_faces = self.faces().len();
+
+        vertices.len() as i64 - num_edges as i64 + num_faces as i64
+            - num_interiors as i64
+    }
+
+    fn genus(&self) -> Option<i64> {
+        if !self.is_closed() {
+            return None;
+        }
+
+        let euler_characteristic = self.euler_characteristic();
+        if euler_characteristic > 2 || euler_characteristic % 2 != 0 {
+            return None;
+        }
+
+        Some((2 - euler_characteristic) / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        operations::{build::BuildCycle, insert::Insert, update::UpdateShell},
+        topology::{Cycle, Region, Shell},
+        Core,
+    };
+
+    use super::EulerCharacteristicOfShell;
+
+    #[test]
+    fn cube() {
+        let mut core = Core::new();
+        let cube = square_prism(false, &mut core);
+
+        assert_eq!(cube.euler_characteristic(), 2);
+        assert_eq!(cube.genus(), Some(0));
+
+        let open = cube.remove_face(cube.faces().first());
+        assert_eq!(open.euler_characteristic(), 1);
+        assert_eq!(open.genus(), None);
+    }
+
+    #[test]
+    fn cube_with_hole() {
+        let mut core = Core::new();
+        let torus = square_prism(true, &mut core);
+
+        assert_eq!(torus.euler_characteristic(), 0);
+        assert_eq!(torus.genus(), Some(1));
+    }
+
+    fn square_prism(with_hole: bool, core: &mut Core) -> Shell {
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let exterior = Cycle::polygon(
+            [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+            surface.clone(),
+            core,
+        )
+        .insert(core);
+        let interiors = with_hole.then(|| {
+            Cycle::polygon(
+                [[1., 1.], [1., 3.], [3., 3.], [3., 1.]],
+                surface,
+                core,
+            )
+            .insert(core)
+        });
+
+        let solid = fixtures::sweep_down(
+            Region::new(exterior, interiors),
+            0.,
+            1.,
+            core,
+        );
+
+        solid.shells().only().clone_object()
+    }
+}
@@ -10,13 +10,31 @@
 //! them for various objects that have the information to answer the query.
 
 mod all_half_edges_with_surface;
+mod boundary_edges_of_shell;
 mod bounding_vertices_of_half_edge;
+mod content_hash;
 mod cycle_of_half_edge;
+mod diff_objects;
+mod euler_characteristic_of_shell;
+mod nearest_feature;
+mod neighbors_of_face;
+mod reachable_objects;
 mod sibling_of_half_edge;
 
 pub use self::{
     all_half_edges_with_surface::AllHalfEdgesWithSurface,
+    boundary_edges_of_shell::BoundaryEdgesOfShell,
     bounding_vertices_of_half_edge::BoundingVerticesOfHalfEdge,
+    content_hash::ContentHash,
     cycle_of_half_edge::CycleOfHalfEdge,
+    diff_objects::{DiffObjects, ModelDiff},
+    euler_characteristic_of_shell::EulerCharacteristicOfShell,
+    nearest_feature::{Feature, NearestFeature, NearestFeatureToPoint},
+    neighbors_of_face::NeighborsOfFace,
+    reachable_objects::ReachableObjects,
     sibling_of_half_edge::{Sibling, SiblingOfHalfEdge},
 };
+
+pub(crate) use self::content_hash::{
+    canonical_face, hash_canonical_face, CanonicalFace,
+};
@@ -0,0 +1,353 @@
+use fj_math::{LineSegment, Point, Scalar, Triangle};
+
+use crate::{
+    algorithms::{
+        approx::{cycle::approx_cycle, face::approx_face, ApproxCache},
+        triangulate::{triangulate_face, Seed},
+    },
+    construction::{Construction, ConstructionEntity},
+    geometry::{Geometry, Tolerance},
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell, Solid, Vertex},
+};
+
+/// Find the feature of an object that is nearest to a point
+pub trait NearestFeatureToPoint {
+    /// Find the vertex, edge, or face nearest to the provided point
+    ///
+    /// Vertices take priority over edges, and edges take priority over faces,
+    /// if they are within `snap_radius` of the point. This makes it possible
+    /// to snap to a vertex or edge, even if a face that touches it is slightly
+    /// closer. If no vertex or edge is within that radius, the nearest face is
+    /// returned.
+    ///
+    /// Distances are measured against the approximation of the object, using
+    /// the provided tolerance. The returned point is on that approximation.
+    ///
+    /// Returns `None`, if the object has no faces (or, for construction
+    /// geometry, no entities).
+    fn nearest_feature(
+        &self,
+        point: impl Into<Point<3>>,
+        snap_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<NearestFeature>;
+}
+
+impl NearestFeatureToPoint for Shell {
+    fn nearest_feature(
+        &self,
+        point: impl Into<Point<3>>,
+        snap_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<NearestFeature> {
+        let mut candidates = Candidates::new(point.into());
+        candidates.add_shell(self, tolerance.into(), geometry);
+        candidates.select(snap_radius.into())
+    }
+}
+
+impl NearestFeatureToPoint for Solid {
+    fn nearest_feature(
+        &self,
+        point: impl Into<Point<3>>,
+        snap_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<NearestFeature> {
+        let tolerance = tolerance.into();
+
+        let mut candidates = Candidates::new(point.into());
+        for shell in self.shells() {
+            candidates.add_shell(shell, tolerance, geometry);
+        }
+        candidates.select(snap_radius.into())
+    }
+}
+
+/// Construction points are treated like vertices, axes like edges, and planes
+/// like faces.
+impl NearestFeatureToPoint for Construction {
+    fn nearest_feature(
+        &self,
+        point: impl Into<Point<3>>,
+        snap_radius: impl Into<Scalar>,
+        _: impl Into<Tolerance>,
+        _: &Geometry,
+    ) -> Option<NearestFeature> {
+        let mut candidates = Candidates::new(point.into());
+        candidates.add_construction(self);
+        candidates.select(snap_radius.into())
+    }
+}
+
+/// Considers the features of the solid and the construction geometry together
+impl NearestFeatureToPoint for (&Solid, &Construction) {
+    fn nearest_feature(
+        &self,
+        point: impl Into<Point<3>>,
+        snap_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<NearestFeature> {
+        let (solid, construction) = self;
+        let tolerance = tolerance.into();
+
+        let mut candidates = Candidates::new(point.into());
+        for shell in solid.shells() {
+            candidates.add_shell(shell, tolerance, geometry);
+        }
+        candidates.add_construction(construction);
+        candidates.select(snap_radius.into())
+    }
+}
+
+/// The result of [`NearestFeatureToPoint::nearest_feature`]
+#[derive(Clone, Debug)]
+pub struct NearestFeature {
+    /// The feature nearest to the point
+    pub feature: Feature,
+
+    /// The point on the feature that is nearest to the queried point
+    pub point: Point<3>,
+
+    /// The distance between the queried point and `point`
+    pub distance: Scalar,
+}
+
+/// A feature of a shape, as returned by [`NearestFeatureToPoint`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Feature {
+    /// A vertex
+    Vertex(Handle<Vertex>),
+
+    /// An edge, represented by one of its half-edges
+    HalfEdge(Handle<HalfEdge>),
+
+    /// A face
+    Face(Handle<Face>),
+
+    /// An entity of construction geometry, identified by its name
+    Construction(String),
+}
+
+struct Candidates {
+    point: Point<3>,
+    cache: ApproxCache,
+
+    vertex: Option<NearestFeature>,
+    half_edge: Option<NearestFeature>,
+    face: Option<NearestFeature>,
+}
+
+impl Candidates {
+    fn new(point: Point<3>) -> Self {
+        Self {
+            point,
+            cache: ApproxCache::default(),
+            vertex: None,
+            half_edge: None,
+            face: None,
+        }
+    }
+
+    fn add_shell(
+        &mut self,
+        shell: &Shell,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) {
+        for face in shell.faces() {
+            for cycle in face.region().all_cycles() {
+                let approx = approx_cycle(
+                    cycle,
+                    face.surface(),
+                    tolerance,
+                    &mut self.cache,
+                    geometry,
+                );
+
+                let half_edges =
+                    cycle.half_edges().iter().zip(&approx.half_edges);
+                let starts = approx
+                    .half_edges
+                    .iter()
+                    .cycle()
+                    .skip(1)
+                    .map(|next| next.points[0].global_form);
+
+                for ((half_edge, half_edge_approx), end) in
+                    half_edges.zip(starts)
+                {
+                    let start = half_edge_approx.points[0].global_form;
+                    Self::update(
+                        &mut self.vertex,
+                        self.point,
+                        start,
+                        Feature::Vertex(half_edge.start_vertex().clone()),
+                    );
+
+                    let points = half_edge_approx
+                        .points
+                        .iter()
+                        .map(|point| point.global_form)
+                        .chain([end])
+                        .collect::<Vec<_>>();
+                    for segment in points.windows(2) {
+                        let segment =
+                            LineSegment::from([segment[0], segment[1]]);
+                        Self::update(
+                            &mut self.half_edge,
+                            self.point,
+                            segment.closest_point(self.point),
+                            Feature::HalfEdge(half_edge.clone()),
+                        );
+                    }
+                }
+            }
+
+            let approx =
+                approx_face(face.clone(), tolerance, &mut self.cache, geometry);
+            for triangle in triangulate_face(approx, Seed::default()) {
+                Self::update(
+                    &mut self.face,
+                    self.point,
+                    Triangle::from(triangle).closest_point(self.point),
+                    Feature::Face(face.clone()),
+                );
+            }
+        }
+    }
+
+    fn add_construction(&mut self, construction: &Construction) {
+        for (name, entity) in construction.iter() {
+            let nearest = match entity {
+                ConstructionEntity::Point(_) => &mut self.vertex,
+                ConstructionEntity::Line(_) => &mut self.half_edge,
+                ConstructionEntity::Plane(_) => &mut self.face,
+            };
+
+            Self::update(
+                nearest,
+                self.point,
+                entity.closest_point(self.point),
+                Feature::Construction(name.to_string()),
+            );
+        }
+    }
+
+    fn update(
+        nearest: &mut Option<NearestFeature>,
+        query: Point<3>,
+        point: Point<3>,
+        feature: Feature,
+    ) {
+        let distance = query.distance_to(&point);
+
+        if let Some(nearest) = nearest {
+            if nearest.distance <= distance {
+                return;
+            }
+        }
+
+        *nearest = Some(NearestFeature {
+            feature,
+            point,
+            distance,
+        });
+    }
+
+    fn select(self, snap_radius: Scalar) -> Option<NearestFeature> {
+        let within_radius =
+            |nearest: &NearestFeature| nearest.distance <= snap_radius;
+
+        self.vertex
+            .filter(within_radius)
+            .or(self.half_edge.filter(within_radius))
+            .or(self.face)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Vector};
+
+    use crate::{algorithms::approx::Approx, fixtures, topology::Solid, Core};
+
+    use super::{Feature, NearestFeatureToPoint};
+
+    fn cube(core: &mut Core) -> Solid {
+        fixtures::unit_cube(core)
+    }
+
+    #[test]
+    fn snap_to_vertex_near_corner() {
+        let mut core = Core::new();
+        let cube = cube(&mut core);
+
+        let nearest = cube
+            .nearest_feature(
+                [1.02, 0.99, 0.01],
+                0.1,
+                core.tolerance(),
+                &core.layers.geometry,
+            )
+            .unwrap();
+
+        assert!(matches!(nearest.feature, Feature::Vertex(_)));
+        assert_eq!(nearest.point, Point::from([1., 1., 0.]));
+    }
+
+    #[test]
+    fn snap_onto_face_near_center() {
+        let mut core = Core::new();
+        let cube = cube(&mut core);
+
+        let nearest = cube
+            .nearest_feature(
+                [0.5, 0.5, 0.05],
+                0.1,
+                core.tolerance(),
+                &core.layers.geometry,
+            )
+            .unwrap();
+
+        assert!(matches!(nearest.feature, Feature::Face(_)));
+        assert_eq!(nearest.point, Point::from([0.5, 0.5, 0.]));
+    }
+
+    #[test]
+    fn snap_to_construction_axis() {
+        let mut core = Core::new();
+        let cube = cube(&mut core);
+
+        let without_axis =
+            (&cube).approx(core.tolerance(), &core.layers.geometry);
+
+        core.construction.add(
+            "axis",
+            Line::from_origin_and_direction(
+                Point::from([2., 0., 0.]),
+                Vector::unit_z(),
+            ),
+        );
+
+        // Construction geometry is not part of the shape.
+        let with_axis = (&cube).approx(core.tolerance(), &core.layers.geometry);
+        assert_eq!(with_axis, without_axis);
+
+        let nearest = (&cube, &core.construction)
+            .nearest_feature(
+                [1.95, 0., 0.5],
+                0.1,
+                core.tolerance(),
+                &core.layers.geometry,
+            )
+            .unwrap();
+
+        assert_eq!(nearest.feature, Feature::Construction("axis".into()));
+        assert_eq!(nearest.point, Point::from([2., 0., 0.5]));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    storage::Handle,
+    topology::{Face, Shell},
+};
+
+use super::SiblingOfHalfEdge;
+
+/// Determine the faces that are adjacent to a [`Face`]
+pub trait NeighborsOfFace {
+    /// Return the faces that share an edge with the provided face
+    ///
+    /// Faces that share multiple edges with the provided face are only returned
+    /// once. The provided face itself is never returned, even if it shares an
+    /// edge with itself.
+    ///
+    /// Returns an empty `Vec`, if the provided face is not part of the object
+    /// this method is called on.
+    fn neighbors_of(&self, face: &Handle<Face>) -> Vec<Handle<Face>>;
+}
+
+impl NeighborsOfFace for Shell {
+    fn neighbors_of(&self, face: &Handle<Face>) -> Vec<Handle<Face>> {
+        if !self.faces().contains(face) {
+            return Vec::new();
+        }
+
+        let mut neighbors = Vec::new();
+
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let Some(sibling) = self.get_sibling_of(half_edge) else {
+                    continue;
+                };
+
+                if &sibling.face != face && !neighbors.contains(&sibling.face) {
+                    neighbors.push(sibling.face);
+                }
+            }
+        }
+
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixtures, Core};
+
+    use super::NeighborsOfFace;
+
+    #[test]
+    fn cube() {
+        let mut core = Core::new();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        let shell = cube.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        for face in shell.faces() {
+            let neighbors = shell.neighbors_of(face);
+
+            assert_eq!(neighbors.len(), 4);
+            assert!(!neighbors.contains(face));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeSet;
+
+use crate::topology::{AnyObject, Region, Sketch, Solid, Stored};
+
+/// Determine all objects that an object references
+pub trait ReachableObjects {
+    /// Collect all objects that are referenced by `self`
+    ///
+    /// Includes objects that are referenced directly or indirectly, but not
+    /// `self` itself.
+    fn reachable_objects(&self) -> BTreeSet<AnyObject<Stored>>;
+}
+
+impl ReachableObjects for Solid {
+    fn reachable_objects(&self) -> BTreeSet<AnyObject<Stored>> {
+        let mut objects = BTreeSet::new();
+
+        for shell in self.shells() {
+            objects.insert(shell.clone().into());
+
+            for face in shell.faces() {
+                objects.insert(face.clone().into());
+                objects.insert(face.surface().clone().into());
+                objects.insert(face.region().clone().into());
+
+                objects_of_region(face.region(), &mut objects);
+            }
+        }
+
+        objects
+    }
+}
+
+impl ReachableObjects for Sketch {
+    fn reachable_objects(&self) -> BTreeSet<AnyObject<Stored>> {
+        let mut objects = BTreeSet::new();
+
+        objects.insert(self.surface().clone().into());
+
+        for region in self.regions() {
+            objects.insert(region.clone().into());
+            objects_of_region(region, &mut objects);
+        }
+
+        objects
+    }
+}
+
+fn objects_of_region(
+    region: &Region,
+    objects: &mut BTreeSet<AnyObject<Stored>>,
+) {
+    for cycle in region.all_cycles() {
+        objects.insert(cycle.clone().into());
+
+        for half_edge in cycle.half_edges() {
+            objects.insert(half_edge.clone().into());
+            objects.insert(half_edge.curve().clone().into());
+            objects.insert(half_edge.start_vertex().clone().into());
+        }
+    }
+}
@@ -1,6 +1,18 @@
-use std::{any::type_name, borrow::Borrow, fmt, hash::Hash, ops::Deref};
-
-use super::{blocks::Index, store::StoreInner};
+use std::{
+    any::type_name,
+    borrow::Borrow,
+    fmt,
+    hash::Hash,
+    ops::Deref,
+    sync::{Arc, Weak},
+};
+
+use parking_lot::RwLock;
+
+use super::{
+    blocks::Index,
+    store::{StoreInner, StoreInnerInner},
+};
 
 /// # A handle that references a stored object
 ///
@@ -58,6 +70,14 @@ impl<T> Handle<T> {
         ObjectId::from_ptr(self.ptr)
     }
 
+    /// Create a weak reference to the storage the object lives in
+    ///
+    /// The object lives as long as its storage does. Unlike the handle, the
+    /// weak reference doesn't keep it alive.
+    pub(crate) fn downgrade(&self) -> Weak<RwLock<StoreInnerInner<T>>> {
+        Arc::downgrade(&self.store)
+    }
+
     /// Return a bare object, which is a clone of the referenced stored object
     pub fn clone_object(&self) -> T
     where
@@ -88,6 +88,17 @@ impl<T> Store<T> {
         inner.blocks.insert(handle.index, object);
     }
 
+    /// Create another instance of `Store` that shares this one's storage
+    ///
+    /// Since objects are immutable and storage is append-only, sharing it is
+    /// cheap and safe. Objects that are inserted through either instance are
+    /// visible through both.
+    pub fn share(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+
     /// Iterate over all objects in this store
     pub fn iter(&self) -> Iter<T> {
         Iter {
@@ -3,7 +3,7 @@ use crate::{
     storage::{Handle, ObjectId},
     topology::{
         Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
-        Topology, Vertex,
+        Topology, Vertex, Wire,
     },
     validate::Validate,
     validation::{ValidationConfig, ValidationError},
@@ -51,6 +51,26 @@ macro_rules! any_object {
                     )*
                 }
             }
+
+            /// Validate the object, recording how long each check takes
+            ///
+            /// See [`Validate::validate_timed`].
+            #[cfg(feature = "validation-timings")]
+            pub fn validate_timed(&self,
+                config: &ValidationConfig,
+                errors: &mut Vec<ValidationError>,
+                geometry: &Geometry,
+            ) -> crate::validation::ValidationTimings {
+                match self {
+                    $(
+                        Self::$ty(object) => object.validate_timed(
+                            config,
+                            errors,
+                            geometry,
+                        ),
+                    )*
+                }
+            }
         }
 
         impl AnyObject<AboutToBeStored> {
@@ -112,6 +132,7 @@ any_object!(
     Solid, "solid", solids;
     Surface, "surface", surfaces;
     Vertex, "vertex", vertices;
+    Wire, "wire", wires;
 );
 
 /// The form that an object can take
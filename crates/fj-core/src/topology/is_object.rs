@@ -1,7 +1,8 @@
 use crate::storage::Handle;
 
 use super::{
-    Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface, Vertex,
+    Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
+    Vertex, Wire,
 };
 
 /// A trait implemented for all object types
@@ -58,6 +59,10 @@ impl IsObject for Vertex {
     type BareObject = Vertex;
 }
 
+impl IsObject for Wire {
+    type BareObject = Wire;
+}
+
 impl IsObject for Handle<Curve> {
     type BareObject = Curve;
 }
@@ -97,3 +102,7 @@ impl IsObject for Handle<Surface> {
 impl IsObject for Handle<Vertex> {
     type BareObject = Vertex;
 }
+
+impl IsObject for Handle<Wire> {
+    type BareObject = Wire;
+}
@@ -26,13 +26,14 @@ pub use self::{
         curve::Curve,
         cycle::Cycle,
         face::{Face, Handedness},
-        half_edge::HalfEdge,
+        half_edge::{Arc3, CurveKind, HalfEdge},
         region::Region,
         shell::Shell,
         sketch::Sketch,
         solid::Solid,
         surface::Surface,
         vertex::Vertex,
+        wire::Wire,
     },
-    stores::{Surfaces, Topology},
+    stores::{Surfaces, Topology, TopologyCounts},
 };
@@ -1,9 +1,10 @@
-use fj_math::{Scalar, Winding};
+use fj_math::{signed_area, Scalar, Winding};
 
 use crate::{
-    geometry::{Geometry, Path},
+    algorithms::approx::{cycle::approx_cycle, ApproxCache},
+    geometry::{Geometry, Path, Tolerance},
     storage::Handle,
-    topology::{HalfEdge, ObjectSet},
+    topology::{HalfEdge, ObjectSet, Vertex},
 };
 
 use super::surface::Surface;
@@ -26,6 +27,17 @@ impl Cycle {
         &self.half_edges
     }
 
+    /// Access the vertices of the cycle
+    ///
+    /// Yields the start vertex of each half-edge, in the order of the
+    /// half-edges. Since the cycle is closed, this covers each vertex exactly
+    /// once.
+    pub fn vertices(&self) -> impl Iterator<Item = Handle<Vertex>> + '_ {
+        self.half_edges
+            .iter()
+            .map(|half_edge| half_edge.start_vertex().clone())
+    }
+
     /// Indicate the cycle's winding, assuming a right-handed coordinate system
     ///
     /// Please note that this is not *the* winding of the cycle, only one of the
@@ -78,13 +90,14 @@ impl Cycle {
             ];
             let edge_direction_positive = a < b;
 
-            let circle = match curve_geom.path {
-                Path::Circle(circle) => circle,
+            let [a, b] = match curve_geom.path {
+                Path::Circle(circle) => [circle.a(), circle.b()],
+                Path::Ellipse(ellipse) => [ellipse.a(), ellipse.b()],
                 Path::Line(_) => unreachable!(
                     "Invalid cycle: less than 3 edges, but not all are circles"
                 ),
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+            let cross_positive = a.cross2d(&b) > Scalar::ZERO;
 
             if edge_direction_positive == cross_positive {
                 return Winding::Ccw;
@@ -94,13 +107,11 @@ impl Cycle {
         }
 
         // Now that we got the special case out of the way, we can treat the
-        // cycle as a polygon:
-        // https://stackoverflow.com/a/1165943
-
-        let mut sum = Scalar::ZERO;
-
-        for (a, b) in self.half_edges().pairs() {
-            let [a, b] = [a, b].map(|half_edge| {
+        // cycle as a polygon.
+        let points = self
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
                 geometry
                     .of_curve(half_edge.curve())
                     .unwrap()
@@ -115,18 +126,102 @@ impl Cycle {
                             .unwrap()
                             .position,
                     )
-            });
+            })
+            .collect::<Vec<_>>();
+        let area = signed_area(&points);
 
-            sum += (b.u - a.u) * (b.v + a.v);
+        if area > Scalar::ZERO {
+            return Winding::Ccw;
         }
-
-        if sum > Scalar::ZERO {
+        if area < Scalar::ZERO {
             return Winding::Cw;
         }
-        if sum < Scalar::ZERO {
-            return Winding::Ccw;
-        }
 
         unreachable!("Encountered invalid cycle: {self:#?}");
     }
+
+    /// Compute the signed area of the cycle
+    ///
+    /// The area is computed in the coordinates of the provided surface, by
+    /// applying the shoelace formula to the cycle's approximation. It is
+    /// positive, if the cycle is counter-clockwise (see [`Cycle::winding`]),
+    /// and negative, if it is clockwise.
+    pub fn signed_area(
+        &self,
+        geometry: &Geometry,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Scalar {
+        approx_cycle(
+            self,
+            surface,
+            tolerance,
+            &mut ApproxCache::default(),
+            geometry,
+        )
+        .signed_area()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        operations::{build::BuildCycle, reverse::Reverse},
+        storage::ObjectId,
+        topology::Cycle,
+        Core,
+    };
+
+    #[test]
+    fn signed_area() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let ccw = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        );
+        let cw = ccw.reverse(&mut core);
+
+        let tolerance = core.tolerance();
+        assert_eq!(
+            ccw.signed_area(&core.layers.geometry, &surface, tolerance),
+            Scalar::from(4.),
+        );
+        assert_eq!(
+            cw.signed_area(&core.layers.geometry, &surface, tolerance),
+            Scalar::from(-4.),
+        );
+    }
+
+    #[test]
+    fn vertices() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let triangle = Cycle::polygon(
+            [[0., 0.], [1., 0.], [0., 1.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let vertices = triangle
+            .vertices()
+            .map(|v| v.id())
+            .collect::<Vec<ObjectId>>();
+        let start_vertices = triangle
+            .half_edges()
+            .iter()
+            .map(|half_edge| half_edge.start_vertex().id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vertices.len(), 3);
+        assert_eq!(vertices, start_vertices);
+        assert_ne!(vertices[0], vertices[1]);
+        assert_ne!(vertices[1], vertices[2]);
+        assert_ne!(vertices[2], vertices[0]);
+    }
 }
@@ -1,7 +1,14 @@
-use fj_math::Winding;
+use std::ops::Deref;
+
+use fj_math::{Aabb, Vector, Winding};
 
 use crate::{
-    geometry::Geometry,
+    algorithms::{
+        approx::{cycle::approx_cycle, ApproxCache},
+        bounding_volume::BoundingVolume,
+    },
+    construction::Plane,
+    geometry::{Geometry, Path, Tolerance},
     storage::Handle,
     topology::{Region, Surface},
 };
@@ -68,6 +75,88 @@ impl Face {
             Winding::Cw => Handedness::LeftHanded,
         }
     }
+
+    /// Compute the normal of the face's front side
+    ///
+    /// This is the normal of the face's surface, flipped, if necessary, to
+    /// point away from the front side of the face (see
+    /// [`Face::coord_handedness`]). For a face that is part of a valid shell,
+    /// this means it points outward.
+    ///
+    /// Returns `None`, if the face is defined on a curved surface. The normal
+    /// of such a face is not the same everywhere.
+    pub fn normal(&self, geometry: &Geometry) -> Option<Vector<3>> {
+        let surface = geometry.of_surface(self.surface());
+        let Path::Line(u) = surface.u else {
+            return None;
+        };
+
+        let normal = u.direction().cross(&surface.v).normalize();
+
+        match self.coord_handedness(geometry) {
+            Handedness::RightHanded => Some(normal),
+            Handedness::LeftHanded => Some(-normal),
+        }
+    }
+
+    /// Determine whether this face lies in the same plane as another
+    ///
+    /// The faces are coplanar, if the boundary of each face lies on the surface
+    /// of the other, within the provided tolerance. The orientation of the
+    /// faces, and whether they overlap, is not taken into account.
+    ///
+    /// Since distances are checked along the whole boundary, the angle by which
+    /// the surfaces may differ depends on the size of the faces. Larger faces
+    /// must be aligned more precisely.
+    ///
+    /// Returns `false`, if either face is defined on a curved surface.
+    pub fn is_coplanar_with(
+        &self,
+        other: &Face,
+        geometry: &Geometry,
+        tolerance: impl Into<Tolerance>,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        let [Some(plane_a), Some(plane_b)] = [self, other]
+            .map(|face| geometry.of_surface(face.surface()).as_plane())
+        else {
+            return false;
+        };
+
+        let boundary_is_on_plane = |face: &Face, plane: &Plane| {
+            let approx = approx_cycle(
+                face.region.exterior(),
+                face.surface(),
+                tolerance,
+                &mut ApproxCache::default(),
+                geometry,
+            );
+
+            approx.points().into_iter().all(|point| {
+                let distance =
+                    (point.global_form - plane.origin).dot(&plane.normal);
+                distance.abs() <= tolerance.inner()
+            })
+        };
+
+        boundary_is_on_plane(self, &plane_b)
+            && boundary_is_on_plane(other, &plane_a)
+    }
+
+    /// Compute the bounds of the face in the coordinates of its surface
+    ///
+    /// Returns the union of the bounds of all of the face's cycles. For faces
+    /// on curved surfaces, this is the range of surface parameters that the
+    /// face covers.
+    ///
+    /// Returns `None`, if none of the face's cycles have any half-edges.
+    pub fn uv_bounds(&self, geometry: &Geometry) -> Option<Aabb<2>> {
+        self.region
+            .all_cycles()
+            .filter_map(|cycle| (cycle.deref(), self.surface()).aabb(geometry))
+            .reduce(|a, b| a.merged(&b))
+    }
 }
 
 /// The handedness of a face's coordinate system
@@ -81,3 +170,171 @@ pub enum Handedness {
     /// The face's coordinate system is right-handed
     RightHanded,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Vector};
+
+    use crate::{
+        fixtures,
+        operations::build::{BuildFace, BuildSurface},
+        topology::{Face, Surface},
+        Core,
+    };
+
+    #[test]
+    fn normal() {
+        let mut core = Core::new();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        // The first face of a swept shell is the bottom cap, the last one is
+        // the top cap. The sweep went down from the xy-plane, so the bottom
+        // cap faces up.
+        let faces = cube.shells().only().faces();
+        let bottom = faces.first();
+        let top = faces.iter().last().unwrap();
+
+        let geometry = &core.layers.geometry;
+        assert_eq!(bottom.normal(geometry), Some(Vector::unit_z()));
+        assert_eq!(top.normal(geometry), Some(-Vector::unit_z()));
+
+        // The side faces follow the edges of the square, so each one is
+        // opposite the one after next.
+        for i in 1..=2 {
+            let [a, b] = [i, i + 2]
+                .map(|i| faces.nth(i).unwrap().normal(geometry).unwrap());
+            assert_eq!(a, -b);
+        }
+    }
+
+    #[test]
+    fn uv_bounds() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+
+        assert_eq!(
+            face.uv_bounds(&core.layers.geometry),
+            Some(Aabb {
+                min: Point::from([0., 0.]),
+                max: Point::from([1., 1.]),
+            }),
+        );
+
+        let unbound = Face::unbound(surface, &mut core);
+        assert_eq!(unbound.uv_bounds(&core.layers.geometry), None);
+    }
+
+    #[test]
+    fn is_coplanar_with_coplanar() {
+        let mut core = Core::new();
+
+        let a = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+
+        // A different surface in the same plane, with its normal pointing the
+        // other way.
+        let (surface, _) = Surface::plane_from_points(
+            [[2., 2., 0.], [2., 3., 0.], [3., 2., 0.]],
+            &mut core,
+        )
+        .unwrap();
+        let b =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [1., 1.]], &mut core);
+
+        let geometry = &core.layers.geometry;
+        let tolerance = core.tolerance();
+        assert!(a.is_coplanar_with(&a, geometry, tolerance));
+        assert!(a.is_coplanar_with(&b, geometry, tolerance));
+        assert!(b.is_coplanar_with(&a, geometry, tolerance));
+    }
+
+    #[test]
+    fn is_coplanar_with_parallel_but_offset() {
+        let mut core = Core::new();
+
+        let a = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]],
+            &mut core,
+        )
+        .unwrap();
+        let b =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [1., 1.]], &mut core);
+
+        let geometry = &core.layers.geometry;
+        let tolerance = core.tolerance();
+        assert!(!a.is_coplanar_with(&b, geometry, tolerance));
+        assert!(!b.is_coplanar_with(&a, geometry, tolerance));
+    }
+
+    #[test]
+    fn is_coplanar_with_depends_on_size() {
+        let mut core = Core::new();
+
+        let a = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+
+        // A plane that is tilted slightly against the xy-plane. Its normal
+        // differs by less than the tolerance.
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.0005]],
+            &mut core,
+        )
+        .unwrap();
+        let small = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [0.1, 0.], [0.1, 0.1]],
+            &mut core,
+        );
+        let large = Face::polygon(
+            surface,
+            [[0., 0.], [100., 0.], [100., 100.]],
+            &mut core,
+        );
+
+        let geometry = &core.layers.geometry;
+        let tolerance = core.tolerance();
+        assert!(a.is_coplanar_with(&small, geometry, tolerance));
+        assert!(!a.is_coplanar_with(&large, geometry, tolerance));
+        assert!(!large.is_coplanar_with(&a, geometry, tolerance));
+    }
+
+    #[test]
+    fn is_coplanar_with_perpendicular() {
+        let mut core = Core::new();
+
+        let a = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+        let b = Face::polygon(
+            core.layers.topology.surfaces.xz_plane(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+
+        let geometry = &core.layers.geometry;
+        let tolerance = core.tolerance();
+        assert!(!a.is_coplanar_with(&b, geometry, tolerance));
+        assert!(!b.is_coplanar_with(&a, geometry, tolerance));
+    }
+}
@@ -1,6 +1,9 @@
+use fj_math::{Point, Scalar, Vector};
+
 use crate::{
+    geometry::{CurveBoundary, Geometry, Path, SurfaceGeom},
     storage::Handle,
-    topology::{Curve, Vertex},
+    topology::{Curve, Surface, Vertex},
 };
 
 /// # A directed half-edge, defined in a surface's 2D space
@@ -54,4 +57,510 @@ impl HalfEdge {
     pub fn start_vertex(&self) -> &Handle<Vertex> {
         &self.start_vertex
     }
+
+    /// Compute the boundary of the half-edge on its curve
+    ///
+    /// A half-edge only refers to its start vertex, so the vertex where it ends
+    /// (the start vertex of the next half-edge in the cycle) must be provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of either vertex is not defined on the curve.
+    pub fn curve_boundary(
+        &self,
+        end_vertex: &Handle<Vertex>,
+        geometry: &Geometry,
+    ) -> CurveBoundary<Point<1>> {
+        let inner = [&self.start_vertex, end_vertex].map(|vertex| {
+            geometry
+                .of_vertex(vertex)
+                .and_then(|vertex| vertex.local_on(&self.curve))
+                .expect("Expected geometry of vertex to be defined on curve")
+                .position
+        });
+
+        CurveBoundary { inner }
+    }
+
+    /// Compute the direction of the half-edge in the surface's 2D space
+    ///
+    /// The direction points from the start of the half-edge towards its end,
+    /// and is normalized. For a curved half-edge, this is the direction of the
+    /// tangent at its start. Like [`HalfEdge::curve_boundary`], this requires
+    /// the vertex where the half-edge ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of the curve is not defined on the surface, if
+    /// the geometry of either vertex is not defined on the curve, or if the
+    /// half-edge has zero length.
+    pub fn direction_2d(
+        &self,
+        end_vertex: &Handle<Vertex>,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+    ) -> Vector<2> {
+        let path = geometry
+            .of_curve(&self.curve)
+            .and_then(|curve| curve.local_on(surface))
+            .expect("Expected geometry of curve to be defined on surface")
+            .path;
+        let boundary = self.curve_boundary(end_vertex, geometry);
+
+        direction_on_path(&path, boundary).normalize()
+    }
+
+    /// Compute the direction of the half-edge in 3D
+    ///
+    /// This is the 3D equivalent of [`HalfEdge::direction_2d`]. The curve
+    /// might be defined on multiple surfaces, and any one of them is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of the curve is not defined, if the geometry of
+    /// either vertex is not defined on the curve, or if the half-edge has zero
+    /// length.
+    pub fn direction_3d(
+        &self,
+        end_vertex: &Handle<Vertex>,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        let (surface, local) = geometry
+            .of_curve(&self.curve)
+            .and_then(|curve| curve.definitions.iter().next())
+            .expect("Expected geometry of curve to be defined");
+        let boundary = self.curve_boundary(end_vertex, geometry);
+
+        let [start, _] = boundary.inner;
+        let point_surface = local.path.point_from_path_coords(start);
+        let direction_surface = direction_on_path(&local.path, boundary);
+
+        let surface = geometry.of_surface(surface);
+        let u = tangent(&surface.u, point_surface.u);
+
+        (u * direction_surface.u + surface.v * direction_surface.v).normalize()
+    }
+
+    /// Determine the kind of curve that the half-edge is on
+    ///
+    /// The curve might be defined on multiple surfaces. Since all of those
+    /// definitions must describe the same curve in 3D space, any one of them is
+    /// used to determine the kind.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of the curve is not defined.
+    pub fn curve_kind(&self, geometry: &Geometry) -> CurveKind {
+        let (surface, local) = geometry
+            .of_curve(&self.curve)
+            .and_then(|curve| curve.definitions.iter().next())
+            .expect("Expected geometry of curve to be defined");
+
+        CurveKind::from_path_on_surface(
+            &local.path,
+            geometry.of_surface(surface),
+        )
+    }
+
+    /// Access the exact geometry of the half-edge, if it is a circular arc
+    ///
+    /// Returns `None`, if the curve of the half-edge is not a circle. Like
+    /// [`HalfEdge::curve_boundary`], this requires the vertex where the
+    /// half-edge ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of the curve is not defined, or the geometry of
+    /// either vertex is not defined on the curve.
+    pub fn as_arc(
+        &self,
+        end_vertex: &Handle<Vertex>,
+        geometry: &Geometry,
+    ) -> Option<Arc3> {
+        let curve = geometry
+            .of_curve(&self.curve)
+            .expect("Expected geometry of curve to be defined");
+        let [start, end] = self.curve_boundary(end_vertex, geometry).inner;
+
+        curve.definitions.iter().find_map(|(surface, local)| {
+            let (center, a, b, [start_angle, end_angle]) =
+                match (geometry.of_surface(surface).u, local.path) {
+                    (Path::Line(u), Path::Circle(circle)) => {
+                        let v = geometry.of_surface(surface).v;
+                        let to_3d = |vector: Vector<2>| {
+                            u.direction() * vector.u + v * vector.v
+                        };
+
+                        let center = u.origin() + to_3d(circle.center().coords);
+                        let [a, b] = [circle.a(), circle.b()].map(to_3d);
+
+                        (center, a, b, [start.t, end.t])
+                    }
+                    (Path::Circle(u), Path::Line(line)) => {
+                        // A line along the u-axis of a cylinder is a circle.
+                        // Its curve coordinates map to angles on the
+                        // cylinder.
+                        let direction = line.direction();
+                        if direction.v.abs() > direction.magnitude() * EPSILON {
+                            return None;
+                        }
+
+                        let v = geometry.of_surface(surface).v;
+                        let center = u.center() + v * line.origin().v;
+
+                        let [start_angle, end_angle] =
+                            [start, end].map(|point| {
+                                line.origin().u + direction.u * point.t
+                            });
+
+                        (center, u.a(), u.b(), [start_angle, end_angle])
+                    }
+                    _ => return None,
+                };
+
+            // The image of a circle on a plane is only a circle, if the axes
+            // of the plane are orthogonal and of equal length.
+            let radius = a.magnitude();
+            let is_circle = (b.magnitude() - radius).abs() <= radius * EPSILON
+                && a.dot(&b).abs() <= radius * radius * EPSILON;
+            if !is_circle {
+                return None;
+            }
+
+            Some(Arc3 {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                normal: a.cross(&b).normalize(),
+                reference: a.normalize(),
+            })
+        })
+    }
+}
+
+/// The exact geometry of a circular arc in 3D
+///
+/// Returned by [`HalfEdge::as_arc`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Arc3 {
+    /// The center of the circle that the arc is on
+    pub center: Point<3>,
+
+    /// The radius of the circle that the arc is on
+    pub radius: Scalar,
+
+    /// The angle at which the arc starts
+    ///
+    /// Angles are measured from [`Arc3::reference`], counter-clockwise around
+    /// [`Arc3::normal`].
+    pub start_angle: Scalar,
+
+    /// The angle at which the arc ends
+    ///
+    /// Is smaller than [`Arc3::start_angle`], if the arc goes clockwise around
+    /// [`Arc3::normal`].
+    pub end_angle: Scalar,
+
+    /// The normal of the plane that the arc is in
+    pub normal: Vector<3>,
+
+    /// The direction from the center, at which the angle is zero
+    pub reference: Vector<3>,
+}
+
+/// The kind of curve that a half-edge is on
+///
+/// See [`HalfEdge::curve_kind`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CurveKind {
+    /// The curve is a line
+    Line,
+
+    /// The curve is a circle
+    Circle {
+        /// The radius of the circle
+        radius: Scalar,
+    },
+
+    /// The curve is an ellipse
+    Ellipse {
+        /// The length of the semi-major axis
+        major_radius: Scalar,
+
+        /// The length of the semi-minor axis
+        minor_radius: Scalar,
+    },
+
+    /// The curve is of a kind that has no dedicated representation
+    ///
+    /// This is the case for helices, for example, which result from lines on
+    /// cylindrical surfaces that are not parallel to either of the surface's
+    /// axes.
+    Other,
+}
+
+impl CurveKind {
+    fn from_path_on_surface(path: &Path<2>, surface: &SurfaceGeom) -> Self {
+        match (surface.u, path) {
+            // Planes are an affine transform of their surface coordinates,
+            // and those map lines to lines.
+            (Path::Line(_), Path::Line(_)) => Self::Line,
+            (Path::Line(u), Path::Circle(circle)) => {
+                // An affine transform maps a circle to an ellipse. The images
+                // of the circle's axes are conjugate semi-diameters of it.
+                let [a, b] = [circle.a(), circle.b()]
+                    .map(|v| u.direction() * v.u + surface.v * v.v);
+                Self::from_conjugate_semi_diameters(a, b)
+            }
+            (Path::Line(u), Path::Ellipse(ellipse)) => {
+                // Same as for a circle. The affine transform maps an ellipse to
+                // another ellipse.
+                let [a, b] = [ellipse.a(), ellipse.b()]
+                    .map(|v| u.direction() * v.u + surface.v * v.v);
+                Self::from_conjugate_semi_diameters(a, b)
+            }
+            (Path::Circle(_) | Path::Ellipse(_), Path::Line(line)) => {
+                let direction = line.direction();
+                let epsilon = direction.magnitude() * EPSILON;
+
+                if direction.u.abs() <= epsilon {
+                    Self::Line
+                } else if direction.v.abs() <= epsilon {
+                    match surface.u {
+                        Path::Circle(u) => Self::Circle { radius: u.radius() },
+                        Path::Ellipse(u) => {
+                            Self::from_conjugate_semi_diameters(u.a(), u.b())
+                        }
+                        Path::Line(_) => unreachable!("Matched above"),
+                    }
+                } else {
+                    Self::Other
+                }
+            }
+            (
+                Path::Circle(_) | Path::Ellipse(_),
+                Path::Circle(_) | Path::Ellipse(_),
+            ) => Self::Other,
+        }
+    }
+
+    fn from_conjugate_semi_diameters(a: Vector<3>, b: Vector<3>) -> Self {
+        let [aa, bb, ab] = [a.dot(&a), b.dot(&b), a.dot(&b)];
+
+        let mean = (aa + bb) / 2.;
+        let deviation = (((aa - bb) / 2.) * ((aa - bb) / 2.) + ab * ab).sqrt();
+
+        let major_radius = (mean + deviation).sqrt();
+        let minor_radius = (mean - deviation).max(Scalar::ZERO).sqrt();
+
+        if major_radius - minor_radius <= major_radius * EPSILON {
+            return Self::Circle {
+                radius: major_radius,
+            };
+        }
+
+        Self::Ellipse {
+            major_radius,
+            minor_radius,
+        }
+    }
+}
+
+/// Compute the (not normalized) direction at the start of the boundary
+fn direction_on_path(
+    path: &Path<2>,
+    boundary: CurveBoundary<Point<1>>,
+) -> Vector<2> {
+    let [start, end] = boundary.inner;
+    tangent(path, start.t) * (end.t - start.t).sign().to_scalar()
+}
+
+/// Compute the derivative of a path, with respect to its path coordinate
+fn tangent<const D: usize>(path: &Path<D>, t: Scalar) -> Vector<D> {
+    match path {
+        Path::Circle(circle) => {
+            let (sin, cos) = t.sin_cos();
+            circle.b() * cos - circle.a() * sin
+        }
+        Path::Ellipse(ellipse) => {
+            let (sin, cos) = t.sin_cos();
+            ellipse.b() * cos - ellipse.a() * sin
+        }
+        Path::Line(line) => line.direction(),
+    }
+}
+
+const EPSILON: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Point, Scalar, Vector};
+    use itertools::Itertools;
+
+    use crate::{
+        operations::{
+            build::{BuildCycle, BuildHalfEdge},
+            reverse::Reverse,
+        },
+        topology::{Cycle, HalfEdge},
+        Core,
+    };
+
+    use super::CurveKind;
+
+    #[test]
+    fn curve_boundary() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let half_edges_and_boundaries = [[0., 0.], [1., 0.], [0., 1.]]
+            .into_iter()
+            .circular_tuple_windows()
+            .map(|(start, end)| {
+                HalfEdge::line_segment([start, end], surface.clone(), &mut core)
+            })
+            .collect::<Vec<_>>();
+        let cycle = Cycle::from_half_edges_and_boundaries(
+            half_edges_and_boundaries.clone(),
+            &mut core,
+        );
+
+        for ((half_edge, next_half_edge), (_, boundary)) in
+            cycle.half_edges().pairs().zip(half_edges_and_boundaries)
+        {
+            assert_eq!(
+                half_edge.curve_boundary(
+                    next_half_edge.start_vertex(),
+                    &core.layers.geometry,
+                ),
+                boundary,
+            );
+        }
+    }
+
+    #[test]
+    fn direction_of_reverse() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let half_edges_and_boundaries = [[0., 0.], [2., 0.], [0., 1.]]
+            .into_iter()
+            .circular_tuple_windows()
+            .map(|(start, end)| {
+                HalfEdge::line_segment([start, end], surface.clone(), &mut core)
+            })
+            .collect::<Vec<_>>();
+        let cycle = Cycle::from_half_edges_and_boundaries(
+            half_edges_and_boundaries,
+            &mut core,
+        );
+        let reversed = cycle.reverse(&mut core);
+
+        let geometry = &core.layers.geometry;
+        for (half_edge, next) in cycle.half_edges().pairs() {
+            let (reverse, reverse_next) = reversed
+                .half_edges()
+                .pairs()
+                .find(|(reverse, _)| reverse.curve() == half_edge.curve())
+                .unwrap();
+
+            assert_eq!(
+                reverse.direction_2d(
+                    reverse_next.start_vertex(),
+                    &surface,
+                    geometry
+                ),
+                -half_edge.direction_2d(
+                    next.start_vertex(),
+                    &surface,
+                    geometry
+                ),
+            );
+            assert_eq!(
+                reverse.direction_3d(reverse_next.start_vertex(), geometry),
+                -half_edge.direction_3d(next.start_vertex(), geometry),
+            );
+        }
+
+        let (half_edge, next) = cycle.half_edges().pairs().next().unwrap();
+        assert_eq!(
+            half_edge.direction_3d(next.start_vertex(), geometry),
+            Vector::from([1., 0., 0.]),
+        );
+    }
+
+    #[test]
+    fn as_arc() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let arc = HalfEdge::arc(
+            [1., 0.],
+            [0., 1.],
+            Scalar::PI / 2.,
+            surface.clone(),
+            &mut core,
+        );
+        let [line_a, line_b] = [[[0., 1.], [0., 0.]], [[0., 0.], [1., 0.]]]
+            .map(|points| {
+                HalfEdge::line_segment(points, surface.clone(), &mut core)
+            });
+        let cycle = Cycle::from_half_edges_and_boundaries(
+            [arc, line_a, line_b],
+            &mut core,
+        );
+
+        let (arc, next) = cycle.half_edges().pairs().next().unwrap();
+        let arc3 = arc
+            .as_arc(next.start_vertex(), &core.layers.geometry)
+            .unwrap();
+
+        let epsilon = Scalar::from(1e-12);
+        assert_abs_diff_eq!(
+            arc3.center,
+            Point::from([0., 0., 0.]),
+            epsilon = epsilon,
+        );
+        assert_abs_diff_eq!(arc3.radius, Scalar::ONE, epsilon = epsilon);
+        assert_abs_diff_eq!(
+            arc3.normal,
+            Vector::from([0., 0., 1.]),
+            epsilon = epsilon,
+        );
+        assert_abs_diff_eq!(
+            arc3.end_angle - arc3.start_angle,
+            Scalar::PI / 2.,
+            epsilon = epsilon,
+        );
+
+        let (line, next) = cycle.half_edges().pairs().nth(1).unwrap();
+        assert_eq!(
+            line.as_arc(next.start_vertex(), &core.layers.geometry),
+            None
+        );
+    }
+
+    #[test]
+    fn curve_kind() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xz_plane();
+
+        let (line, _) = HalfEdge::line_segment(
+            [[0., 0.], [1., 0.]],
+            surface.clone(),
+            &mut core,
+        );
+        let (arc, _) =
+            HalfEdge::arc([0., 0.], [2., 0.], Scalar::PI, surface, &mut core);
+
+        assert_eq!(line.curve_kind(&core.layers.geometry), CurveKind::Line);
+        assert_eq!(
+            arc.curve_kind(&core.layers.geometry),
+            CurveKind::Circle {
+                radius: Scalar::ONE
+            },
+        );
+    }
 }
@@ -8,3 +8,4 @@ pub mod sketch;
 pub mod solid;
 pub mod surface;
 pub mod vertex;
+pub mod wire;
@@ -1,8 +1,11 @@
 //! A single, continues 2d region
 
+use fj_math::Scalar;
+
 use crate::{
+    geometry::{Geometry, Tolerance},
     storage::Handle,
-    topology::{Cycle, ObjectSet},
+    topology::{Cycle, ObjectSet, Surface},
 };
 
 /// A single, continuous 2d region; may contain holes
@@ -38,15 +41,121 @@ impl Region {
 
     /// Access the cycles that bound the region on the inside
     ///
-    /// Each of these cycles defines a hole in the region .
+    /// Each of these cycles defines a hole in the region. They are returned in
+    /// the order in which they were provided on construction.
     pub fn interiors(&self) -> &ObjectSet<Cycle> {
         &self.interiors
     }
 
     /// Access all cycles of the region (both exterior and interior)
+    ///
+    /// The cycles are returned in the same order as by
+    /// [`Region::cycles_ordered`].
     pub fn all_cycles(&self) -> impl Iterator<Item = &Handle<Cycle>> {
+        self.cycles_ordered()
+    }
+
+    /// Access all cycles of the region, exterior first
+    ///
+    /// The exterior cycle always comes first, followed by the interior cycles,
+    /// in the order in which they were provided on construction (the same
+    /// order as returned by [`Region::interiors`]). The order is deterministic,
+    /// so code that needs to tell the exterior cycle apart from the interior
+    /// ones can rely on it.
+    pub fn cycles_ordered(&self) -> impl Iterator<Item = &Handle<Cycle>> {
         // It would be nice to return `&ObjectSet` here, but I don't see a way
         // for doing that here *and* in `interiors`.
         [self.exterior()].into_iter().chain(self.interiors())
     }
+
+    /// Compute the area of the region, minus the area of its holes
+    ///
+    /// This is the sum of the [signed areas] of all cycles. Since the interior
+    /// cycles have the opposite winding of the exterior cycle, their areas are
+    /// subtracted. The sign of the result is the sign of the exterior cycle's
+    /// area, meaning it is positive, if the exterior cycle is
+    /// counter-clockwise.
+    ///
+    /// [signed areas]: Cycle::signed_area
+    pub fn net_area(
+        &self,
+        geometry: &Geometry,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Scalar {
+        let tolerance = tolerance.into();
+
+        self.all_cycles().fold(Scalar::ZERO, |area, cycle| {
+            area + cycle.signed_area(geometry, surface, tolerance)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        operations::{build::BuildCycle, insert::Insert},
+        topology::{Cycle, Region},
+        Core,
+    };
+
+    #[test]
+    fn cycles_ordered_yields_exterior_first() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let mut square = |min: f64, max: f64| {
+            Cycle::polygon(
+                [[min, min], [max, min], [max, max], [min, max]],
+                surface.clone(),
+                &mut core,
+            )
+            .insert(&mut core)
+        };
+
+        let regions = [
+            Region::new(square(0., 4.), []),
+            Region::new(square(0., 4.), [square(1., 2.)]),
+            Region::new(square(0., 4.), [square(1., 2.), square(3., 3.5)]),
+        ];
+
+        for region in regions {
+            let cycles = region.cycles_ordered().collect::<Vec<_>>();
+
+            assert_eq!(cycles[0], region.exterior());
+            assert!(cycles[1..].iter().copied().eq(region.interiors()));
+
+            // The order doesn't change between calls.
+            assert!(region.cycles_ordered().eq(cycles.iter().copied()));
+            assert!(region.all_cycles().eq(cycles.iter().copied()));
+        }
+    }
+
+    #[test]
+    fn net_area_subtracts_holes() {
+        let mut core = Core::new();
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let exterior = Cycle::polygon(
+            [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+        let interior = Cycle::polygon(
+            [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let region = Region::new(exterior, [interior]);
+
+        assert_eq!(
+            region.net_area(&core.layers.geometry, &surface, core.tolerance()),
+            Scalar::from(15.),
+        );
+    }
 }
@@ -1,6 +1,14 @@
+use std::collections::BTreeSet;
+
+use fj_interop::Mesh;
+use fj_math::Point;
+
 use crate::{
+    algorithms::triangulate::{triangulate_solid_exact, ExactMeshError},
+    geometry::Geometry,
+    queries::BoundingVerticesOfHalfEdge,
     storage::Handle,
-    topology::{ObjectSet, Shell},
+    topology::{Cycle, HalfEdge, ObjectSet, Shell},
 };
 
 /// A 3-dimensional shape, built from [`Shell`]s. Many Solids will contains only
@@ -28,4 +36,119 @@ impl Solid {
     pub fn shells(&self) -> &ObjectSet<Shell> {
         &self.shells
     }
+
+    /// Iterate over all half-edges of the solid
+    ///
+    /// Each half-edge is yielded once, even if it is referenced multiple times.
+    pub fn edges(&self) -> impl Iterator<Item = Handle<HalfEdge>> + '_ {
+        let mut visited = BTreeSet::new();
+
+        self.half_edges_with_cycles()
+            .filter(move |(half_edge, _)| visited.insert(half_edge.id()))
+            .map(|(half_edge, _)| half_edge.clone())
+    }
+
+    /// Iterate over all edges of the solid
+    ///
+    /// An edge is made up of a half-edge and its sibling, which share a curve
+    /// and the same bounding vertices. For each edge, only the first of its
+    /// half-edges is yielded.
+    pub fn global_edges(&self) -> impl Iterator<Item = Handle<HalfEdge>> + '_ {
+        let mut visited = BTreeSet::new();
+
+        self.half_edges_with_cycles()
+            .filter(move |(half_edge, cycle)| {
+                let vertices = cycle
+                    .bounding_vertices_of_half_edge(half_edge)
+                    .expect("`half_edge` came from `cycle`, must exist there");
+
+                visited
+                    .insert((half_edge.curve().clone(), vertices.normalize()))
+            })
+            .map(|(half_edge, _)| half_edge.clone())
+    }
+
+    /// Triangulate the solid exactly, without approximation
+    ///
+    /// A solid that is bounded by planar faces with straight edges doesn't
+    /// need to be approximated. Its faces are polygons, which are triangulated
+    /// directly, resulting in the minimal number of triangles. All triangles
+    /// have the default color, as colors are not available from the geometry.
+    ///
+    /// Returns an error, if any face of the solid is curved, or bounded by a
+    /// curved edge. Use [`Triangulate`] for those.
+    ///
+    /// [`Triangulate`]: crate::algorithms::triangulate::Triangulate
+    pub fn mesh_exact(
+        &self,
+        geometry: &Geometry,
+    ) -> Result<Mesh<Point<3>>, ExactMeshError> {
+        triangulate_solid_exact(self, geometry)
+    }
+
+    fn half_edges_with_cycles(
+        &self,
+    ) -> impl Iterator<Item = (&Handle<HalfEdge>, &Handle<Cycle>)> {
+        self.shells
+            .iter()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| face.region().all_cycles())
+            .flat_map(|cycle| {
+                cycle
+                    .half_edges()
+                    .iter()
+                    .map(move |half_edge| (half_edge, cycle))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{algorithms::triangulate::ExactMeshError, fixtures, Core};
+
+    #[test]
+    fn edges_of_cube() {
+        let mut core = Core::new();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        assert_eq!(cube.edges().count(), 24);
+        assert_eq!(cube.global_edges().count(), 12);
+    }
+
+    #[test]
+    fn mesh_exact_cube() {
+        let mut core = Core::new();
+
+        let cube = fixtures::unit_cube(&mut core);
+
+        let mesh = cube.mesh_exact(&core.layers.geometry).unwrap();
+        assert_eq!(mesh.triangles().count(), 12);
+        assert_eq!(mesh.vertices().count(), 8);
+
+        // All triangles face outward.
+        let center = Point::from([0.5, 0.5, -0.5]);
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points;
+            let centroid = a + (b - a) / 3. + (c - a) / 3.;
+            assert!(
+                triangle.inner.normal().dot(&(centroid - center))
+                    > Scalar::ZERO
+            );
+        }
+    }
+
+    #[test]
+    fn mesh_exact_cylinder() {
+        let mut core = Core::new();
+
+        let cylinder = fixtures::cylinder(1., 1., &mut core);
+
+        assert!(matches!(
+            cylinder.mesh_exact(&core.layers.geometry),
+            Err(ExactMeshError::CurvedEdge | ExactMeshError::CurvedSurface),
+        ));
+    }
 }
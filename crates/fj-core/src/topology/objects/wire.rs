@@ -0,0 +1,62 @@
+use crate::{
+    storage::Handle,
+    topology::{HalfEdge, ObjectSet, Vertex},
+};
+
+/// An open chain of connected half-edges
+///
+/// Unlike a [`Cycle`], a wire does not loop back to where it started. It is
+/// not meant to bound a region, but can be used to define paths, for example
+/// for construction geometry.
+///
+/// A half-edge only refers to its start vertex, and ends where the next
+/// half-edge starts. Since the last half-edge of a wire has no next half-edge,
+/// the wire refers to the vertex where it ends.
+///
+/// [`Cycle`]: crate::topology::Cycle
+#[derive(Clone, Debug)]
+pub struct Wire {
+    half_edges: ObjectSet<HalfEdge>,
+    end_vertex: Handle<Vertex>,
+}
+
+impl Wire {
+    /// Create an instance of `Wire`
+    pub fn new(
+        half_edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        end_vertex: Handle<Vertex>,
+    ) -> Self {
+        let half_edges = half_edges.into_iter().collect();
+        Self {
+            half_edges,
+            end_vertex,
+        }
+    }
+
+    /// Access the half-edges that make up the wire
+    pub fn half_edges(&self) -> &ObjectSet<HalfEdge> {
+        &self.half_edges
+    }
+
+    /// Access the vertex where the wire ends
+    pub fn end_vertex(&self) -> &Handle<Vertex> {
+        &self.end_vertex
+    }
+
+    /// Access the half-edges of the wire, along with the vertex they end at
+    ///
+    /// This is the start vertex of the next half-edge, or the end vertex of the
+    /// wire, for the last half-edge.
+    pub fn half_edges_with_end_vertices(
+        &self,
+    ) -> impl Iterator<Item = (&Handle<HalfEdge>, &Handle<Vertex>)> {
+        let end_vertices = self
+            .half_edges
+            .iter()
+            .skip(1)
+            .map(|half_edge| half_edge.start_vertex())
+            .chain([&self.end_vertex]);
+
+        self.half_edges.iter().zip(end_vertices)
+    }
+}
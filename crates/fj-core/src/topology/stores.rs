@@ -1,7 +1,10 @@
-use crate::storage::{Handle, Store};
+use std::collections::BTreeSet;
+
+use crate::storage::{Handle, Iter, Store};
 
 use super::{
-    Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface, Vertex,
+    AnyObject, Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid,
+    Stored, Surface, Vertex, Wire,
 };
 
 /// The stores for all topological objects
@@ -36,6 +39,15 @@ pub struct Topology {
 
     /// Store for [`Vertex`] objects
     pub vertices: Store<Vertex>,
+
+    /// Store for [`Wire`]s
+    pub wires: Store<Wire>,
+
+    /// The objects that were inserted through this instance
+    ///
+    /// The stores are shared with other instances (see [`Topology::share`]),
+    /// so they can't tell which of them an object was inserted through.
+    inserted: BTreeSet<AnyObject<Stored>>,
 }
 
 impl Topology {
@@ -43,6 +55,97 @@ impl Topology {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create another instance of `Topology` that shares this one's stores
+    ///
+    /// See [`Store::share`].
+    pub fn share(&self) -> Self {
+        Self {
+            curves: self.curves.share(),
+            cycles: self.cycles.share(),
+            faces: self.faces.share(),
+            half_edges: self.half_edges.share(),
+            regions: self.regions.share(),
+            shells: self.shells.share(),
+            sketches: self.sketches.share(),
+            solids: self.solids.share(),
+            surfaces: self.surfaces.share(),
+            vertices: self.vertices.share(),
+            wires: self.wires.share(),
+            inserted: self.inserted.clone(),
+        }
+    }
+
+    /// Access the objects that were inserted through this instance
+    ///
+    /// This includes the objects that were inserted into the instance this
+    /// one was created from, before it was shared.
+    pub(crate) fn inserted(&self) -> &BTreeSet<AnyObject<Stored>> {
+        &self.inserted
+    }
+
+    pub(crate) fn record_insertion(&mut self, object: AnyObject<Stored>) {
+        self.inserted.insert(object);
+    }
+
+    /// Count the objects in each store
+    pub fn counts(&self) -> TopologyCounts {
+        TopologyCounts {
+            curves: self.curves.iter().count(),
+            cycles: self.cycles.iter().count(),
+            faces: self.faces.iter().count(),
+            half_edges: self.half_edges.iter().count(),
+            regions: self.regions.iter().count(),
+            shells: self.shells.iter().count(),
+            sketches: self.sketches.iter().count(),
+            solids: self.solids.iter().count(),
+            surfaces: self.surfaces.iter().count(),
+            vertices: self.vertices.iter().count(),
+            wires: self.wires.iter().count(),
+        }
+    }
+}
+
+/// The number of objects in each of the stores in [`Topology`]
+///
+/// Returned by [`Topology::counts`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TopologyCounts {
+    /// The number of [`Curve`]s
+    pub curves: usize,
+
+    /// The number of [`Cycle`]s
+    pub cycles: usize,
+
+    /// The number of [`Face`]s
+    pub faces: usize,
+
+    /// The number of [`HalfEdge`]s
+    pub half_edges: usize,
+
+    /// The number of [`Region`]s
+    pub regions: usize,
+
+    /// The number of [`Shell`]s
+    pub shells: usize,
+
+    /// The number of [`Sketch`]es
+    pub sketches: usize,
+
+    /// The number of [`Solid`]s
+    pub solids: usize,
+
+    /// The number of [`Surface`]s
+    ///
+    /// This includes the surfaces that are always present (see
+    /// [`Surfaces::space_2d`], for example).
+    pub surfaces: usize,
+
+    /// The number of [`Vertex`] objects
+    pub vertices: usize,
+
+    /// The number of [`Wire`]s
+    pub wires: usize,
 }
 
 /// Store for [`Surface`]s
@@ -68,6 +171,24 @@ impl Surfaces {
         self.store.insert(handle, surface);
     }
 
+    /// Create another instance of `Surfaces` that shares this one's store
+    ///
+    /// See [`Store::share`].
+    pub fn share(&self) -> Self {
+        Self {
+            store: self.store.share(),
+            space_2d: self.space_2d.clone(),
+            xy_plane: self.xy_plane.clone(),
+            xz_plane: self.xz_plane.clone(),
+            yz_plane: self.yz_plane.clone(),
+        }
+    }
+
+    /// Iterate over all surfaces in the store
+    pub fn iter(&self) -> Iter<Surface> {
+        self.store.iter()
+    }
+
     /// Access the surface representing 2D space
     ///
     /// Every other surface is a 2D subspace within a 3D space. This surface is
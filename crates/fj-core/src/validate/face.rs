@@ -1,13 +1,7 @@
 use crate::{
     geometry::Geometry,
     topology::Face,
-    validation::{
-        checks::{
-            AdjacentHalfEdgesNotConnected, FaceHasNoBoundary,
-            InteriorCycleHasInvalidWinding,
-        },
-        ValidationCheck, ValidationConfig, ValidationError,
-    },
+    validation::{ValidationConfig, ValidationError},
 };
 
 use super::Validate;
@@ -19,16 +13,19 @@ impl Validate for Face {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            AdjacentHalfEdgesNotConnected::check(self, geometry, config)
-                .map(Into::into),
-        );
-        errors.extend(
-            FaceHasNoBoundary::check(self, geometry, config).map(Into::into),
-        );
-        errors.extend(
-            InteriorCycleHasInvalidWinding::check(self, geometry, config)
-                .map(Into::into),
-        );
+        errors.extend(config.checks.face.run_all(self, geometry, config));
+    }
+
+    #[cfg(feature = "validation-timings")]
+    fn validate_timed(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        geometry: &Geometry,
+    ) -> crate::validation::ValidationTimings {
+        let (new_errors, timings) =
+            config.checks.face.run_all_timed(self, geometry, config);
+        errors.extend(new_errors);
+        timings
     }
 }
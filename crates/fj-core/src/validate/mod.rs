@@ -71,12 +71,16 @@ mod sketch;
 mod solid;
 mod surface;
 mod vertex;
+mod wire;
 
 use crate::{
     geometry::Geometry,
     validation::{ValidationConfig, ValidationError},
 };
 
+#[cfg(feature = "validation-timings")]
+use crate::validation::ValidationTimings;
+
 pub use self::{sketch::SketchValidationError, solid::SolidValidationError};
 
 /// Assert that some object has a validation error which matches a specific
@@ -123,4 +127,20 @@ pub trait Validate: Sized {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     );
+
+    /// Validate the object, recording how long each validation check takes
+    ///
+    /// Works like [`Validate::validate`], but also returns the time spent in
+    /// each check that was run. Objects whose checks are not registered in
+    /// [`ValidationConfig::checks`] don't record any timings.
+    #[cfg(feature = "validation-timings")]
+    fn validate_timed(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        geometry: &Geometry,
+    ) -> ValidationTimings {
+        self.validate(config, errors, geometry);
+        ValidationTimings::default()
+    }
 }
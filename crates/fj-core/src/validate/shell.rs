@@ -1,11 +1,4 @@
-use crate::{
-    geometry::Geometry,
-    topology::Shell,
-    validation::{
-        checks::{CoincidentHalfEdgesAreNotSiblings, HalfEdgeHasNoSibling},
-        ValidationCheck,
-    },
-};
+use crate::{geometry::Geometry, topology::Shell};
 
 use super::{Validate, ValidationConfig, ValidationError};
 
@@ -16,12 +9,19 @@ impl Validate for Shell {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            HalfEdgeHasNoSibling::check(self, geometry, config).map(Into::into),
-        );
-        errors.extend(
-            CoincidentHalfEdgesAreNotSiblings::check(self, geometry, config)
-                .map(Into::into),
-        );
+        errors.extend(config.checks.shell.run_all(self, geometry, config));
+    }
+
+    #[cfg(feature = "validation-timings")]
+    fn validate_timed(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        geometry: &Geometry,
+    ) -> crate::validation::ValidationTimings {
+        let (new_errors, timings) =
+            config.checks.shell.run_all_timed(self, geometry, config);
+        errors.extend(new_errors);
+        timings
     }
 }
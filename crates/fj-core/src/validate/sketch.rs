@@ -3,11 +3,8 @@ use fj_math::Winding;
 use crate::{
     geometry::Geometry,
     storage::Handle,
-    topology::{Cycle, HalfEdge, Region, Sketch},
-    validation::{
-        checks::{AdjacentHalfEdgesNotConnected, MultipleReferencesToObject},
-        ValidationCheck,
-    },
+    topology::{Cycle, Sketch},
+    validation::ValidationCheck,
 };
 
 use super::{Validate, ValidationConfig, ValidationError};
@@ -19,28 +16,20 @@ impl Validate for Sketch {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            AdjacentHalfEdgesNotConnected::check(self, geometry, config)
-                .map(Into::into),
-        );
-        errors.extend(
-            MultipleReferencesToObject::<Cycle, Region>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        errors.extend(
-            MultipleReferencesToObject::<HalfEdge, Cycle>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        SketchValidationError::check_exterior_cycles(
-            self, geometry, config, errors,
-        );
-        SketchValidationError::check_interior_cycles(
-            self, geometry, config, errors,
-        );
+        errors.extend(config.checks.sketch.run_all(self, geometry, config));
+    }
+
+    #[cfg(feature = "validation-timings")]
+    fn validate_timed(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        geometry: &Geometry,
+    ) -> crate::validation::ValidationTimings {
+        let (new_errors, timings) =
+            config.checks.sketch.run_all_timed(self, geometry, config);
+        errors.extend(new_errors);
+        timings
     }
 }
 
@@ -68,46 +57,37 @@ pub enum SketchValidationError {
     },
 }
 
-impl SketchValidationError {
-    fn check_exterior_cycles(
-        sketch: &Sketch,
-        geometry: &Geometry,
-        _config: &ValidationConfig,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        sketch.regions().iter().for_each(|region| {
+impl ValidationCheck<Sketch> for SketchValidationError {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut errors = Vec::new();
+
+        for region in object.regions() {
             let cycle = region.exterior();
-            if cycle.winding(geometry, sketch.surface()) == Winding::Cw {
-                errors.push(ValidationError::Sketch(
-                    SketchValidationError::ClockwiseExteriorCycle {
-                        cycle: cycle.clone(),
-                    },
-                ))
+            if cycle.winding(geometry, object.surface()) == Winding::Cw {
+                errors.push(Self::ClockwiseExteriorCycle {
+                    cycle: cycle.clone(),
+                });
             }
-        });
-    }
 
-    fn check_interior_cycles(
-        sketch: &Sketch,
-        geometry: &Geometry,
-        _config: &ValidationConfig,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        sketch.regions().iter().for_each(|region| {
-            region
-                .interiors()
-                .iter()
-                .filter(|interior| {
-                    interior.winding(geometry, sketch.surface()) == Winding::Ccw
-                })
-                .for_each(|cycle| {
-                    errors.push(ValidationError::Sketch(
-                        SketchValidationError::CounterClockwiseInteriorCycle {
-                            cycle: cycle.clone(),
-                        },
-                    ));
-                })
-        });
+            errors.extend(
+                region
+                    .interiors()
+                    .iter()
+                    .filter(|interior| {
+                        interior.winding(geometry, object.surface())
+                            == Winding::Ccw
+                    })
+                    .map(|cycle| Self::CounterClockwiseInteriorCycle {
+                        cycle: cycle.clone(),
+                    }),
+            );
+        }
+
+        errors.into_iter()
     }
 }
 
@@ -3,8 +3,8 @@ use std::iter::repeat;
 use crate::{
     geometry::Geometry,
     storage::Handle,
-    topology::{Cycle, Face, HalfEdge, Region, Shell, Solid, Vertex},
-    validation::{checks::MultipleReferencesToObject, ValidationCheck},
+    topology::{Solid, Vertex},
+    validation::ValidationCheck,
 };
 use fj_math::Point;
 
@@ -17,31 +17,20 @@ impl Validate for Solid {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            MultipleReferencesToObject::<Face, Shell>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        errors.extend(
-            MultipleReferencesToObject::<Region, Face>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        errors.extend(
-            MultipleReferencesToObject::<Cycle, Region>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        errors.extend(
-            MultipleReferencesToObject::<HalfEdge, Cycle>::check(
-                self, geometry, config,
-            )
-            .map(Into::into),
-        );
-        SolidValidationError::check_vertices(self, geometry, config, errors);
+        errors.extend(config.checks.solid.run_all(self, geometry, config));
+    }
+
+    #[cfg(feature = "validation-timings")]
+    fn validate_timed(
+        &self,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+        geometry: &Geometry,
+    ) -> crate::validation::ValidationTimings {
+        let (new_errors, timings) =
+            config.checks.solid.run_all_timed(self, geometry, config);
+        errors.extend(new_errors);
+        timings
     }
 }
 
@@ -89,14 +78,15 @@ pub enum SolidValidationError {
     },
 }
 
-impl SolidValidationError {
-    fn check_vertices(
-        solid: &Solid,
-        geometry: &Geometry,
-        config: &ValidationConfig,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        let vertices: Vec<(Point<3>, Handle<Vertex>)> = solid
+impl ValidationCheck<Solid> for SolidValidationError {
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut errors = Vec::new();
+
+        let vertices: Vec<(Point<3>, Handle<Vertex>)> = object
             .shells()
             .iter()
             .flat_map(|s| s.faces())
@@ -148,29 +138,25 @@ impl SolidValidationError {
                     < config.distinct_min_distance;
 
                 if vertices_are_identical && too_far_to_be_identical {
-                    errors.push(
-                        Self::IdenticalVerticesNotCoincident {
-                            vertex_a: vertex_a.clone(),
-                            vertex_b: vertex_b.clone(),
-                            position_a: *position_a,
-                            position_b: *position_b,
-                        }
-                        .into(),
-                    )
+                    errors.push(Self::IdenticalVerticesNotCoincident {
+                        vertex_a: vertex_a.clone(),
+                        vertex_b: vertex_b.clone(),
+                        position_a: *position_a,
+                        position_b: *position_b,
+                    })
                 }
 
                 if vertices_are_not_identical && too_close_to_be_distinct {
-                    errors.push(
-                        Self::DistinctVerticesCoincide {
-                            vertex_a: vertex_a.clone(),
-                            vertex_b: vertex_b.clone(),
-                            position_a: *position_a,
-                            position_b: *position_b,
-                        }
-                        .into(),
-                    )
+                    errors.push(Self::DistinctVerticesCoincide {
+                        vertex_a: vertex_a.clone(),
+                        vertex_b: vertex_b.clone(),
+                        position_a: *position_a,
+                        position_b: *position_b,
+                    })
                 }
             }
         }
+
+        errors.into_iter()
     }
 }
@@ -0,0 +1,139 @@
+use fj_math::Scalar;
+
+use crate::{
+    algorithms::approx::{face::approx_face, ApproxCache},
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, Solid},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Face`] is so thin, that downstream tools might not handle it
+///
+/// Such faces are technically valid, but some tools that consume exported
+/// models choke on them. This check reports them as warnings, by default.
+///
+/// The shape quality of a face is `4π * area / perimeter²`. This is `1` for a
+/// circle, `π/4` for a square, and approaches zero for faces that are thin
+/// compared to their size. Faces whose quality is below
+/// [`ValidationConfig::sliver_threshold`] are flagged. Both area and perimeter
+/// are computed from an approximation of the face's cycles, using
+/// [`ValidationConfig::tolerance`]. The area is the signed area of those cycles
+/// in surface coordinates, scaled to model coordinates using the normal of the
+/// surface (see [`SurfaceGeom::normal_at`]). For surfaces whose scale varies,
+/// like those based on an ellipse, it is averaged over the exterior cycle.
+///
+/// [`SurfaceGeom::normal_at`]: crate::geometry::SurfaceGeom::normal_at
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Face` is a sliver (shape quality {quality})\n\
+    - Face: {face:#?}"
+)]
+pub struct FaceIsSliver {
+    /// The face that is a sliver
+    pub face: Handle<Face>,
+
+    /// The shape quality of the face
+    pub quality: Scalar,
+}
+
+impl ValidationCheck<Solid> for FaceIsSliver {
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut cache = ApproxCache::default();
+
+        object
+            .shells()
+            .iter()
+            .flat_map(|shell| shell.faces())
+            .filter_map(move |face| {
+                let approx = approx_face(
+                    face.clone(),
+                    config.tolerance,
+                    &mut cache,
+                    geometry,
+                );
+
+                let perimeter = [&approx.exterior]
+                    .into_iter()
+                    .chain(&approx.interiors)
+                    .flat_map(|cycle| cycle.segments())
+                    .fold(Scalar::ZERO, |perimeter, segment| {
+                        let [a, b] = segment.points;
+                        perimeter + a.distance_to(&b)
+                    });
+                let area_in_surface = approx.interiors.iter().fold(
+                    approx.exterior.signed_area().abs(),
+                    |area, interior| area - interior.signed_area().abs(),
+                );
+
+                let surface = geometry.of_surface(face.surface());
+                let points = approx.exterior.points();
+                let scale = points
+                    .iter()
+                    .map(|point| {
+                        surface.normal_at(point.local_form).magnitude()
+                    })
+                    .fold(Scalar::ZERO, |sum, scale| sum + scale)
+                    / Scalar::from(points.len().max(1) as f64);
+
+                let area = area_in_surface * scale;
+
+                if perimeter == Scalar::ZERO {
+                    return None;
+                }
+
+                let quality = Scalar::from(4.) * Scalar::PI * area
+                    / (perimeter * perimeter);
+
+                (quality < config.sliver_threshold).then(|| FaceIsSliver {
+                    face: face.clone(),
+                    quality,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        validation::{
+            checks::FaceIsSliver, Severity, ValidationCheck, ValidationConfig,
+            ValidationError,
+        },
+        Core,
+    };
+
+    #[test]
+    fn face_is_sliver() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let config = ValidationConfig::default();
+
+        let square = fixtures::cuboid([0., 0., -10.], [1., 1., 0.], &mut core);
+        FaceIsSliver::check_and_return_first_error(
+            &square,
+            &core.layers.geometry,
+        )?;
+
+        // The top and bottom faces are 100:1 rectangles. The side faces are
+        // not thin enough to be slivers.
+        let sliver =
+            fixtures::cuboid([0., 0., -10.], [100., 1., 0.], &mut core);
+        let errors =
+            FaceIsSliver::check(&sliver, &core.layers.geometry, &config)
+                .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 2);
+        for err in errors {
+            assert_eq!(
+                ValidationError::from(err).severity(),
+                Severity::Warning
+            );
+        }
+
+        Ok(())
+    }
+}
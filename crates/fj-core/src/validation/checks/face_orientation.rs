@@ -0,0 +1,266 @@
+use fj_math::{Point, Polygon, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::{face::approx_face, ApproxCache},
+        intersect::intersect_ray_face,
+    },
+    geometry::{Geometry, SurfaceGeom},
+    queries::BoundaryEdgesOfShell,
+    storage::Handle,
+    topology::{Face, Shell, Solid},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// The front side of a [`Face`] points towards the inside of its [`Shell`]
+///
+/// The winding of a face's exterior cycle defines which side of the face is
+/// the front (see [`Face::coord_handedness`]). Within a closed shell, the
+/// front of every face is expected to point outward. Faces that violate this
+/// would render inside-out.
+///
+/// The front side of a face is determined by the sign of its exterior cycle's
+/// signed area, combined with the normal of its surface. This check casts a
+/// ray from each face, towards its front side. If the ray crosses the rest of
+/// the shell an odd number of times, it started out towards the inside, and
+/// the face is flagged.
+///
+/// Shells that have boundary edges have no inside, and are not checked. A
+/// shell with an inverted face still qualifies, even though it is no longer
+/// closed in the strict sense, as the half-edges of the inverted face don't
+/// form sibling pairs with their neighbors.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Front side of `Face` points towards the inside of its `Shell`\n\
+    - Face: {face:#?}\n\
+    - Shell: {shell:#?}"
+)]
+pub struct FaceWindingNormalMismatch {
+    /// The face that points towards the inside
+    pub face: Handle<Face>,
+
+    /// The shell that the face is part of
+    pub shell: Handle<Shell>,
+}
+
+impl ValidationCheck<Solid> for FaceWindingNormalMismatch {
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut mismatches = Vec::new();
+
+        for shell in object.shells() {
+            if !shell.boundary_edges().is_empty() {
+                continue;
+            }
+
+            let mut cache = ApproxCache::default();
+            let faces = shell
+                .faces()
+                .iter()
+                .map(|face| {
+                    let approx = approx_face(
+                        face.clone(),
+                        config.tolerance,
+                        &mut cache,
+                        geometry,
+                    );
+
+                    let polygons = [&approx.exterior]
+                        .into_iter()
+                        .chain(&approx.interiors)
+                        .map(|cycle| cycle.polygon())
+                        .collect::<Vec<_>>();
+                    let front = if approx.exterior.signed_area() < Scalar::ZERO
+                    {
+                        -Scalar::ONE
+                    } else {
+                        Scalar::ONE
+                    };
+
+                    (
+                        face,
+                        *geometry.of_surface(face.surface()),
+                        polygons,
+                        front,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            for (face, surface, polygons, front) in &faces {
+                let Some(point) = point_within(polygons) else {
+                    continue;
+                };
+
+                let origin = point_from_surface_coords(surface, point);
+                let direction = surface.normal_at(point) * *front;
+                if direction.magnitude() == Scalar::ZERO {
+                    continue;
+                }
+                let direction = direction.normalize();
+
+                let mut crossings = faces
+                    .iter()
+                    .filter(|(other, ..)| other.id() != face.id())
+                    .flat_map(|(_, surface, polygons, _)| {
+                        intersect_ray_face(
+                            surface,
+                            polygons,
+                            origin,
+                            direction,
+                            config.tolerance.inner(),
+                        )
+                    })
+                    .filter(|&distance| distance > config.tolerance.inner())
+                    .collect::<Vec<_>>();
+
+                // A ray that crosses an edge or vertex hits multiple faces.
+                // Those hits must only be counted once.
+                crossings.sort();
+                crossings.dedup_by(|a, b| {
+                    (*a - *b).abs() <= config.identical_max_distance
+                });
+
+                if crossings.len() % 2 == 1 {
+                    mismatches.push(FaceWindingNormalMismatch {
+                        face: (*face).clone(),
+                        shell: shell.clone(),
+                    });
+                }
+            }
+        }
+
+        mismatches.into_iter()
+    }
+}
+
+/// Find a point within the area bounded by the provided polygons
+///
+/// The polygons are treated as one, using the even-odd rule. The point is in
+/// the middle of the widest span, that a horizontal line through the middle of
+/// the first polygon covers.
+fn point_within(polygons: &[Polygon<2>]) -> Option<Point<2>> {
+    let exterior = polygons.first()?;
+    let min = exterior.points().iter().map(|point| point.v).min()?;
+    let max = exterior.points().iter().map(|point| point.v).max()?;
+    let v = (min + max) / 2.;
+
+    // Each edge is treated as including its lower point, but not its upper
+    // one. That way, a line through a vertex crosses it exactly once.
+    let mut crossings = polygons
+        .iter()
+        .flat_map(|polygon| polygon.edges())
+        .filter_map(|edge| {
+            let [a, b] = edge.points;
+            if (a.v <= v) == (b.v <= v) {
+                return None;
+            }
+
+            Some(a.u + (v - a.v) / (b.v - a.v) * (b.u - a.u))
+        })
+        .collect::<Vec<_>>();
+    crossings.sort();
+
+    crossings
+        .chunks_exact(2)
+        .max_by_key(|span| span[1] - span[0])
+        .map(|span| Point::from([(span[0] + span[1]) / 2., v]))
+}
+
+fn point_from_surface_coords(
+    surface: &SurfaceGeom,
+    point: Point<2>,
+) -> Point<3> {
+    surface.u.point_from_path_coords([point.u]) + surface.v * point.v
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        operations::{
+            reverse::Reverse,
+            update::{UpdateShell, UpdateSolid},
+        },
+        validation::{checks::FaceWindingNormalMismatch, ValidationCheck},
+        Core,
+    };
+
+    #[test]
+    fn face_pointing_inward() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = fixtures::unit_cube(&mut core);
+        FaceWindingNormalMismatch::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_shell(
+            valid.shells().only(),
+            |shell, core| {
+                let face = shell.faces().first();
+                [shell.update_face(
+                    face,
+                    |face, core| [face.reverse(core)],
+                    core,
+                )]
+            },
+            &mut core,
+        );
+        FaceWindingNormalMismatch::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        // Ignore remaining validation errors.
+        let _ = core.layers.validation.take_errors();
+
+        Ok(())
+    }
+
+    #[test]
+    fn curved_face_pointing_inward() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = fixtures::cylinder(1., 1., &mut core);
+        FaceWindingNormalMismatch::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_shell(
+            valid.shells().only(),
+            |shell, core| {
+                let face = shell
+                    .faces()
+                    .iter()
+                    .find(|face| {
+                        core.layers
+                            .geometry
+                            .of_surface(face.surface())
+                            .as_plane()
+                            .is_none()
+                    })
+                    .expect("Cylinder has curved faces");
+                [shell.update_face(
+                    face,
+                    |face, core| [face.reverse(core)],
+                    core,
+                )]
+            },
+            &mut core,
+        );
+        FaceWindingNormalMismatch::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        // Ignore remaining validation errors.
+        let _ = core.layers.validation.take_errors();
+
+        Ok(())
+    }
+}
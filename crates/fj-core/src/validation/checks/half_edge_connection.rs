@@ -154,7 +154,6 @@ fn check_cycle<'r>(
 
 #[cfg(test)]
 mod tests {
-
     use crate::{
         geometry::LocalVertexGeom,
         operations::{
@@ -4,16 +4,23 @@
 
 mod coincident_half_edges_are_not_siblings;
 mod face_boundary;
+mod face_is_sliver;
+mod face_orientation;
 mod face_winding;
 mod half_edge_connection;
 mod half_edge_has_no_sibling;
 mod multiple_references;
+mod non_finite_coordinates;
+mod swept_shell;
 
 pub use self::{
     coincident_half_edges_are_not_siblings::CoincidentHalfEdgesAreNotSiblings,
-    face_boundary::FaceHasNoBoundary,
+    face_boundary::FaceHasNoBoundary, face_is_sliver::FaceIsSliver,
+    face_orientation::FaceWindingNormalMismatch,
     face_winding::InteriorCycleHasInvalidWinding,
     half_edge_connection::AdjacentHalfEdgesNotConnected,
     half_edge_has_no_sibling::HalfEdgeHasNoSibling,
     multiple_references::MultipleReferencesToObject,
+    non_finite_coordinates::GeometryHasNonFiniteCoordinates,
+    swept_shell::SweptShellIsInconsistent,
 };
@@ -0,0 +1,247 @@
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    geometry::{Geometry, Path, SurfaceGeom},
+    storage::Handle,
+    topology::{Curve, Cycle, Sketch, Solid, Surface, Vertex},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// The geometry of an object has non-finite coordinates
+///
+/// `Scalar` rejects NaN on construction, but infinite values can still end up
+/// in the geometry, as a result of bad arithmetic. Such values would propagate
+/// through any computation that uses them, so this check flags them as early
+/// as possible.
+///
+/// All definitions of the geometry of the vertices, curves, and surfaces that
+/// are referenced by the validated object are checked.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum GeometryHasNonFiniteCoordinates {
+    /// The position of a vertex on a curve is not finite
+    #[error(
+        "Position of vertex on curve is not finite\n\
+        - Vertex: {vertex:#?}\n\
+        - Curve: {curve:#?}"
+    )]
+    Vertex {
+        /// The vertex with the non-finite position
+        vertex: Handle<Vertex>,
+
+        /// The curve on which the position is defined
+        curve: Handle<Curve>,
+    },
+
+    /// The path of a curve on a surface has non-finite coordinates
+    #[error(
+        "Path of curve on surface has non-finite coordinates\n\
+        - Curve: {curve:#?}\n\
+        - Surface: {surface:#?}"
+    )]
+    Curve {
+        /// The curve with the non-finite path
+        curve: Handle<Curve>,
+
+        /// The surface on which the path is defined
+        surface: Handle<Surface>,
+    },
+
+    /// The geometry of a surface has non-finite coordinates
+    #[error("Geometry of surface has non-finite coordinates: {surface:#?}")]
+    Surface {
+        /// The surface with the non-finite geometry
+        surface: Handle<Surface>,
+    },
+}
+
+impl ValidationCheck<Sketch> for GeometryHasNonFiniteCoordinates {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        // The surface of a sketch usually is 2D space, which has no geometry.
+        // So we only check the curves and vertices here.
+        let cycles = object
+            .regions()
+            .iter()
+            .flat_map(|region| region.all_cycles().cloned());
+
+        check_cycles(cycles, [], geometry).into_iter()
+    }
+}
+
+impl ValidationCheck<Solid> for GeometryHasNonFiniteCoordinates {
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let faces = object.shells().iter().flat_map(|shell| shell.faces());
+
+        let cycles = faces
+            .clone()
+            .flat_map(|face| face.region().all_cycles().cloned());
+        let surfaces = faces.map(|face| face.surface().clone());
+
+        check_cycles(cycles, surfaces, geometry).into_iter()
+    }
+}
+
+fn check_cycles(
+    cycles: impl IntoIterator<Item = Handle<Cycle>>,
+    surfaces: impl IntoIterator<Item = Handle<Surface>>,
+    geometry: &Geometry,
+) -> Vec<GeometryHasNonFiniteCoordinates> {
+    let mut errors = Vec::new();
+
+    let mut visited_surfaces = BTreeSet::new();
+    for surface in surfaces {
+        if !visited_surfaces.insert(surface.clone()) {
+            continue;
+        }
+
+        let SurfaceGeom { u, v } = geometry.of_surface(&surface);
+        if !is_finite_path(u) || !is_finite_vector(v) {
+            errors.push(GeometryHasNonFiniteCoordinates::Surface { surface });
+        }
+    }
+
+    let mut visited_curves = BTreeSet::new();
+    let mut visited_vertices = BTreeSet::new();
+    for cycle in cycles {
+        for half_edge in cycle.half_edges() {
+            let curve = half_edge.curve();
+            if visited_curves.insert(curve.clone()) {
+                let definitions = geometry
+                    .of_curve(curve)
+                    .into_iter()
+                    .flat_map(|geometry| &geometry.definitions);
+
+                for (surface, local) in definitions {
+                    if !is_finite_path(&local.path) {
+                        errors.push(GeometryHasNonFiniteCoordinates::Curve {
+                            curve: curve.clone(),
+                            surface: surface.clone(),
+                        });
+                    }
+                }
+            }
+
+            let vertex = half_edge.start_vertex();
+            if visited_vertices.insert(vertex.clone()) {
+                let definitions = geometry
+                    .of_vertex(vertex)
+                    .into_iter()
+                    .flat_map(|geometry| &geometry.definitions);
+
+                for (curve, local) in definitions {
+                    if !is_finite_point(&local.position) {
+                        errors.push(GeometryHasNonFiniteCoordinates::Vertex {
+                            vertex: vertex.clone(),
+                            curve: curve.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+fn is_finite_path<const D: usize>(path: &Path<D>) -> bool {
+    match path {
+        Path::Circle(circle) => {
+            is_finite_point(&circle.center())
+                && is_finite_vector(&circle.a())
+                && is_finite_vector(&circle.b())
+        }
+        Path::Ellipse(ellipse) => {
+            is_finite_point(&ellipse.center())
+                && is_finite_vector(&ellipse.a())
+                && is_finite_vector(&ellipse.b())
+        }
+        Path::Line(line) => {
+            is_finite_point(&line.origin())
+                && is_finite_vector(&line.direction())
+        }
+    }
+}
+
+fn is_finite_point<const D: usize>(point: &Point<D>) -> bool {
+    is_finite_vector(&point.coords)
+}
+
+fn is_finite_vector<const D: usize>(vector: &Vector<D>) -> bool {
+    vector
+        .components
+        .iter()
+        .all(|component: &Scalar| component.into_f64().is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        geometry::LocalVertexGeom,
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch},
+        validation::{
+            checks::GeometryHasNonFiniteCoordinates, ValidationCheck,
+        },
+        Core,
+    };
+
+    #[test]
+    fn geometry_has_non_finite_coordinates() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let valid = Sketch::empty(&core.layers.topology).add_regions(
+            [Region::polygon(
+                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                surface,
+                &mut core,
+            )],
+            &mut core,
+        );
+        GeometryHasNonFiniteCoordinates::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        // `Scalar` doesn't allow NaN, so an infinite value has to do.
+        let half_edge = valid
+            .regions()
+            .first()
+            .exterior()
+            .half_edges()
+            .first()
+            .clone();
+        core.layers.geometry.define_vertex(
+            half_edge.start_vertex().clone(),
+            half_edge.curve().clone(),
+            LocalVertexGeom {
+                position: Point::from([f64::INFINITY]),
+            },
+        );
+
+        let err = GeometryHasNonFiniteCoordinates::check_and_expect_one_error(
+            &valid,
+            &core.layers.geometry,
+        );
+        assert!(matches!(
+            err,
+            GeometryHasNonFiniteCoordinates::Vertex { .. }
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    geometry::Geometry,
+    queries::SiblingOfHalfEdge,
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Shell`] that was created by a sweep is inconsistent
+///
+/// A swept shell consists of a bottom cap, followed by the side faces, followed
+/// by a top cap. The side faces that were created by sweeping the exterior
+/// cycle of the bottom cap come first.
+///
+/// The exterior cycles of both caps must have the same number of half-edges,
+/// and every exterior side face must connect exactly one half-edge of each cap.
+///
+/// Whether a shell was created by a sweep can't be determined from the shell
+/// itself. This check is not part of the regular validation of shells, and
+/// the sweep operation runs it explicitly instead. See
+/// [`Layer::validate_swept_shell`].
+///
+/// [`Layer::validate_swept_shell`]: crate::layers::Layer::validate_swept_shell
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum SweptShellIsInconsistent {
+    /// The exterior cycles of the caps have different numbers of half-edges
+    #[error(
+        "Exterior cycles of swept caps have different numbers of half-edges\n\
+        - Bottom: {bottom}\n\
+        - Top: {top}"
+    )]
+    CapHalfEdgeCountMismatch {
+        /// The number of half-edges in the exterior cycle of the bottom cap
+        bottom: usize,
+
+        /// The number of half-edges in the exterior cycle of the top cap
+        top: usize,
+    },
+
+    /// A half-edge of a cap is not connected to an exterior side face
+    #[error(
+        "Half-edge of swept cap is not connected to side face: {half_edge:#?}"
+    )]
+    CapNotConnectedToSideFace {
+        /// The half-edge that is not connected
+        half_edge: Handle<HalfEdge>,
+    },
+
+    /// A side face is not connected to exactly one half-edge of a cap
+    #[error(
+        "Side face is connected to {connections} half-edges of a swept cap, \
+        instead of one: {face:#?}"
+    )]
+    SideFaceNotConnectedToCap {
+        /// The side face
+        face: Handle<Face>,
+
+        /// The number of half-edges of the cap that the side face connects to
+        connections: usize,
+    },
+}
+
+impl ValidationCheck<Shell> for SweptShellIsInconsistent {
+    fn check<'r>(
+        object: &'r Shell,
+        _: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut errors = Vec::new();
+
+        let faces = object.faces();
+        let (Some(bottom), Some(top)) =
+            (faces.iter().next(), faces.iter().last())
+        else {
+            return errors.into_iter();
+        };
+        let [bottom, top] =
+            [bottom, top].map(|face| face.region().exterior().half_edges());
+
+        if bottom.len() != top.len() {
+            errors.push(Self::CapHalfEdgeCountMismatch {
+                bottom: bottom.len(),
+                top: top.len(),
+            });
+            return errors.into_iter();
+        }
+
+        let side_faces = faces.iter().skip(1).take(bottom.len());
+
+        for cap in [bottom, top] {
+            let mut connections = side_faces
+                .clone()
+                .map(|face| (face.clone(), 0))
+                .collect::<BTreeMap<_, _>>();
+
+            for half_edge in cap {
+                let connection = object
+                    .get_sibling_of(half_edge)
+                    .and_then(|sibling| connections.get_mut(&sibling.face));
+
+                match connection {
+                    Some(connections) => *connections += 1,
+                    None => errors.push(Self::CapNotConnectedToSideFace {
+                        half_edge: half_edge.clone(),
+                    }),
+                }
+            }
+
+            errors.extend(connections.into_iter().filter_map(
+                |(face, connections)| {
+                    (connections != 1).then_some(
+                        Self::SideFaceNotConnectedToCap { face, connections },
+                    )
+                },
+            ));
+        }
+
+        errors.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures,
+        operations::{build::BuildRegion, update::UpdateShell},
+        topology::Region,
+        validation::{checks::SweptShellIsInconsistent, ValidationCheck},
+        Core,
+    };
+
+    #[test]
+    fn swept_shell_is_inconsistent() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let triangle =
+            Region::polygon([[0., 0.], [1., 0.], [0., 1.]], surface, &mut core);
+        let prism = fixtures::sweep_down(triangle, 0., 1., &mut core);
+
+        let valid = prism.shells().only();
+        SweptShellIsInconsistent::check_and_return_first_error(
+            valid,
+            &core.layers.geometry,
+        )?;
+
+        // Without its top cap, the last face of the shell is a side face, which
+        // has a different number of half-edges than the bottom cap.
+        let top = valid.faces().iter().last().unwrap();
+        let invalid = valid.remove_face(top);
+        assert!(SweptShellIsInconsistent::check_and_return_first_error(
+            &invalid,
+            &core.layers.geometry,
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -1,9 +1,20 @@
+use std::collections::BTreeSet;
+
 use fj_math::Scalar;
 
 use crate::geometry::Tolerance;
 
+use super::{
+    Severity, ValidationChecks, ValidationError, ValidationErrorCode,
+    WarningPolicy,
+};
+
 /// Configuration required for the validation process
-#[derive(Debug, Clone, Copy)]
+///
+/// Besides the tolerances that validation checks use, this includes the sets of
+/// error codes that are treated differently, and the registries of validation
+/// checks. Cloning it is cheap, as the checks are shared between clones.
+#[derive(Debug, Clone)]
 pub struct ValidationConfig {
     /// Panic on first validation error, instead of storing it
     ///
@@ -37,6 +48,52 @@ pub struct ValidationConfig {
     /// Objects whose distance is less than the value defined in this field, are
     /// considered identical.
     pub distinct_min_distance: Scalar,
+
+    /// The shape quality below which a face is considered a sliver
+    ///
+    /// See [`FaceIsSliver`] for how the shape quality is computed. Defaults to
+    /// `0.05`, which flags rectangles that are about 60 times longer than
+    /// wide.
+    ///
+    /// [`FaceIsSliver`]: super::checks::FaceIsSliver
+    pub sliver_threshold: Scalar,
+
+    /// Decides whether warnings make a model unacceptable
+    ///
+    /// See [`ValidationSummary::is_ok`].
+    ///
+    /// Defaults to [`WarningPolicy::Allow`].
+    ///
+    /// [`ValidationSummary::is_ok`]: super::ValidationSummary::is_ok
+    pub warning_policy: WarningPolicy,
+
+    /// Decides which validation errors are reported, based on their code
+    ///
+    /// Errors that are excluded by this filter are dropped, instead of being
+    /// stored in the validation layer.
+    ///
+    /// Defaults to [`ValidationErrorCodeFilter::All`].
+    pub error_codes: ValidationErrorCodeFilter,
+
+    /// Validation errors with these codes are reported as warnings
+    ///
+    /// Overrides the severity returned by [`ValidationError::severity`], for
+    /// checks that are too strict for a given use case, but whose findings
+    /// should still be visible.
+    ///
+    /// Defaults to an empty set.
+    ///
+    /// [`ValidationError::severity`]: super::ValidationError::severity
+    pub warning_codes: BTreeSet<ValidationErrorCode>,
+
+    /// The validation checks that are run for each kind of object
+    ///
+    /// Custom checks can be registered here, and checks can be disabled or
+    /// re-enabled. Changes take effect for all objects that are validated
+    /// using this configuration.
+    ///
+    /// Defaults to the built-in checks.
+    pub checks: ValidationChecks,
 }
 
 impl ValidationConfig {
@@ -60,6 +117,22 @@ impl ValidationConfig {
             tolerance,
             identical_max_distance,
             distinct_min_distance,
+            sliver_threshold: Scalar::from(0.05),
+            warning_policy: WarningPolicy::default(),
+            error_codes: ValidationErrorCodeFilter::default(),
+            warning_codes: BTreeSet::new(),
+            checks: ValidationChecks::default(),
+        }
+    }
+
+    /// Determine the severity of a validation error
+    ///
+    /// Takes [`ValidationConfig::warning_codes`] into account.
+    pub fn severity_of(&self, err: &ValidationError) -> Severity {
+        if self.warning_codes.contains(&err.code()) {
+            Severity::Warning
+        } else {
+            err.severity()
         }
     }
 }
@@ -69,3 +142,30 @@ impl Default for ValidationConfig {
         Self::from_tolerance(0.001)
     }
 }
+
+/// Decides which validation errors are reported, based on their code
+///
+/// See [`ValidationConfig::error_codes`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ValidationErrorCodeFilter {
+    /// Report all errors
+    #[default]
+    All,
+
+    /// Only report errors with the provided codes
+    Allow(BTreeSet<ValidationErrorCode>),
+
+    /// Report all errors, except those with the provided codes
+    Deny(BTreeSet<ValidationErrorCode>),
+}
+
+impl ValidationErrorCodeFilter {
+    /// Indicate whether errors with the provided code are reported
+    pub fn includes(&self, code: ValidationErrorCode) -> bool {
+        match self {
+            Self::All => true,
+            Self::Allow(codes) => codes.contains(&code),
+            Self::Deny(codes) => !codes.contains(&code),
+        }
+    }
+}
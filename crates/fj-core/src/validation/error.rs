@@ -5,10 +5,15 @@ use crate::{
     validate::{SketchValidationError, SolidValidationError},
 };
 
-use super::checks::{
-    AdjacentHalfEdgesNotConnected, CoincidentHalfEdgesAreNotSiblings,
-    FaceHasNoBoundary, HalfEdgeHasNoSibling, InteriorCycleHasInvalidWinding,
-    MultipleReferencesToObject,
+use super::{
+    checks::{
+        AdjacentHalfEdgesNotConnected, CoincidentHalfEdgesAreNotSiblings,
+        FaceHasNoBoundary, FaceIsSliver, FaceWindingNormalMismatch,
+        GeometryHasNonFiniteCoordinates, HalfEdgeHasNoSibling,
+        InteriorCycleHasInvalidWinding, MultipleReferencesToObject,
+        SweptShellIsInconsistent,
+    },
+    Severity,
 };
 
 /// An error that can occur during a validation
@@ -24,10 +29,26 @@ pub enum ValidationError {
         #[from] CoincidentHalfEdgesAreNotSiblings,
     ),
 
+    /// Error reported by a custom validation check
+    #[error(transparent)]
+    Custom(#[from] CustomValidationError),
+
     /// Face has no boundary
     #[error(transparent)]
     FaceHasNoBoundary(#[from] FaceHasNoBoundary),
 
+    /// Face is a sliver
+    #[error(transparent)]
+    FaceIsSliver(#[from] FaceIsSliver),
+
+    /// Front side of face points towards the inside of its shell
+    #[error(transparent)]
+    FaceWindingNormalMismatch(#[from] FaceWindingNormalMismatch),
+
+    /// Geometry has non-finite coordinates
+    #[error(transparent)]
+    GeometryHasNonFiniteCoordinates(#[from] GeometryHasNonFiniteCoordinates),
+
     /// Half-edge has no sibling
     #[error(transparent)]
     HalfEdgeHasNoSibling(#[from] HalfEdgeHasNoSibling),
@@ -58,6 +79,10 @@ pub enum ValidationError {
         #[from] MultipleReferencesToObject<Region, Face>,
     ),
 
+    /// Shell created by a sweep is inconsistent
+    #[error(transparent)]
+    SweptShellIsInconsistent(#[from] SweptShellIsInconsistent),
+
     /// `Solid` validation error
     #[error("`Solid` validation error")]
     Solid(#[from] SolidValidationError),
@@ -67,6 +92,178 @@ pub enum ValidationError {
     Sketch(#[from] SketchValidationError),
 }
 
+impl ValidationError {
+    /// Access the default severity of the validation error
+    ///
+    /// Most checks report objects that are invalid, and result in errors of
+    /// severity [`Severity::Error`]. [`FaceIsSliver`] reports objects that are
+    /// valid but problematic, and results in [`Severity::Warning`]. This can be
+    /// overridden per error code, using [`ValidationConfig::warning_codes`].
+    ///
+    /// [`ValidationConfig::warning_codes`]: super::ValidationConfig::warning_codes
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::FaceIsSliver(_) => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Access the code of the validation error
+    ///
+    /// The code identifies the validation check that produced the error. It
+    /// can be used to filter errors programmatically (see
+    /// [`ValidationConfig::error_codes`]).
+    ///
+    /// [`ValidationConfig::error_codes`]: super::ValidationConfig::error_codes
+    pub fn code(&self) -> ValidationErrorCode {
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(_) => {
+                ValidationErrorCode::AdjacentHalfEdgesNotConnected
+            }
+            Self::CoincidentHalfEdgesAreNotSiblings(_) => {
+                ValidationErrorCode::CoincidentHalfEdgesAreNotSiblings
+            }
+            Self::Custom(err) => ValidationErrorCode::Custom(err.code),
+            Self::FaceHasNoBoundary(_) => {
+                ValidationErrorCode::FaceHasNoBoundary
+            }
+            Self::FaceIsSliver(_) => ValidationErrorCode::FaceIsSliver,
+            Self::FaceWindingNormalMismatch(_) => {
+                ValidationErrorCode::FaceWindingNormalMismatch
+            }
+            Self::GeometryHasNonFiniteCoordinates(_) => {
+                ValidationErrorCode::GeometryHasNonFiniteCoordinates
+            }
+            Self::HalfEdgeHasNoSibling(_) => {
+                ValidationErrorCode::HalfEdgeHasNoSibling
+            }
+            Self::InteriorCycleHasInvalidWinding(_) => {
+                ValidationErrorCode::InteriorCycleHasInvalidWinding
+            }
+            Self::MultipleReferencesToCycle(_) => {
+                ValidationErrorCode::MultipleReferencesToCycle
+            }
+            Self::MultipleReferencesToFace(_) => {
+                ValidationErrorCode::MultipleReferencesToFace
+            }
+            Self::MultipleReferencesToHalfEdge(_) => {
+                ValidationErrorCode::MultipleReferencesToHalfEdge
+            }
+            Self::MultipleReferencesToRegion(_) => {
+                ValidationErrorCode::MultipleReferencesToRegion
+            }
+            Self::SweptShellIsInconsistent(_) => {
+                ValidationErrorCode::SweptShellIsInconsistent
+            }
+            Self::Solid(SolidValidationError::DistinctVerticesCoincide {
+                ..
+            }) => ValidationErrorCode::SolidDistinctVerticesCoincide,
+            Self::Solid(
+                SolidValidationError::IdenticalVerticesNotCoincident { .. },
+            ) => ValidationErrorCode::SolidIdenticalVerticesNotCoincident,
+            Self::Sketch(SketchValidationError::ClockwiseExteriorCycle {
+                ..
+            }) => ValidationErrorCode::SketchClockwiseExteriorCycle,
+            Self::Sketch(
+                SketchValidationError::CounterClockwiseInteriorCycle { .. },
+            ) => ValidationErrorCode::SketchCounterClockwiseInteriorCycle,
+        }
+    }
+}
+
+/// Identifies the kind of a [`ValidationError`]
+///
+/// See [`ValidationError::code`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ValidationErrorCode {
+    /// See [`ValidationError::AdjacentHalfEdgesNotConnected`]
+    AdjacentHalfEdgesNotConnected,
+
+    /// See [`ValidationError::CoincidentHalfEdgesAreNotSiblings`]
+    CoincidentHalfEdgesAreNotSiblings,
+
+    /// See [`ValidationError::Custom`]
+    ///
+    /// Custom checks are identified by a name of their choosing.
+    Custom(&'static str),
+
+    /// See [`ValidationError::FaceHasNoBoundary`]
+    FaceHasNoBoundary,
+
+    /// See [`ValidationError::FaceIsSliver`]
+    FaceIsSliver,
+
+    /// See [`ValidationError::FaceWindingNormalMismatch`]
+    FaceWindingNormalMismatch,
+
+    /// See [`ValidationError::GeometryHasNonFiniteCoordinates`]
+    GeometryHasNonFiniteCoordinates,
+
+    /// See [`ValidationError::HalfEdgeHasNoSibling`]
+    HalfEdgeHasNoSibling,
+
+    /// See [`ValidationError::InteriorCycleHasInvalidWinding`]
+    InteriorCycleHasInvalidWinding,
+
+    /// See [`ValidationError::MultipleReferencesToCycle`]
+    MultipleReferencesToCycle,
+
+    /// See [`ValidationError::MultipleReferencesToFace`]
+    MultipleReferencesToFace,
+
+    /// See [`ValidationError::MultipleReferencesToHalfEdge`]
+    MultipleReferencesToHalfEdge,
+
+    /// See [`ValidationError::MultipleReferencesToRegion`]
+    MultipleReferencesToRegion,
+
+    /// See [`ValidationError::SweptShellIsInconsistent`]
+    SweptShellIsInconsistent,
+
+    /// See [`SolidValidationError::DistinctVerticesCoincide`]
+    SolidDistinctVerticesCoincide,
+
+    /// See [`SolidValidationError::IdenticalVerticesNotCoincident`]
+    SolidIdenticalVerticesNotCoincident,
+
+    /// See [`SketchValidationError::ClockwiseExteriorCycle`]
+    SketchClockwiseExteriorCycle,
+
+    /// See [`SketchValidationError::CounterClockwiseInteriorCycle`]
+    SketchCounterClockwiseInteriorCycle,
+}
+
+/// An error reported by a custom validation check
+///
+/// Validation checks that are not part of this crate can't add their own
+/// variants to [`ValidationError`]. They report their errors using this type
+/// instead. See [`CheckRegistry`].
+///
+/// [`CheckRegistry`]: super::CheckRegistry
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("{message}")]
+pub struct CustomValidationError {
+    /// The name that identifies the check that produced the error
+    pub code: &'static str,
+
+    /// The message that describes the error
+    pub message: String,
+}
+
+/// All validation errors, categorized by severity
+///
+/// Returned by [`Layer<Validation>::take_issues`].
+///
+/// [`Layer<Validation>::take_issues`]: crate::layers::Layer::take_issues
+#[derive(Debug, Default)]
+pub struct ValidationIssues {
+    /// The validation errors of severity [`Severity::Error`]
+    pub errors: Vec<ValidationError>,
+
+    /// The validation errors of severity [`Severity::Warning`]
+    pub warnings: Vec<ValidationError>,
+}
+
 impl From<Infallible> for ValidationError {
     fn from(infallible: Infallible) -> Self {
         match infallible {}
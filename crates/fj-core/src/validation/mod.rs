@@ -22,14 +22,27 @@
 
 mod config;
 mod error;
+mod registry;
+mod summary;
 mod validation;
 mod validation_check;
 
 pub mod checks;
 
 pub use self::{
-    config::ValidationConfig,
-    error::{ValidationError, ValidationErrors},
+    config::{ValidationConfig, ValidationErrorCodeFilter},
+    error::{
+        CustomValidationError, ValidationError, ValidationErrorCode,
+        ValidationErrors, ValidationIssues,
+    },
+    registry::{
+        applicable_checks, BuiltinChecks, CheckRegistry, DynValidationCheck,
+        StaticCheck, ValidationChecks,
+    },
+    summary::{Severity, ValidationSummary, WarningPolicy},
     validation::Validation,
     validation_check::ValidationCheck,
 };
+
+#[cfg(feature = "validation-timings")]
+pub use self::registry::ValidationTimings;
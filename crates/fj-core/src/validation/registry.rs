@@ -0,0 +1,757 @@
+use std::{collections::BTreeSet, fmt, marker::PhantomData, sync::Arc};
+
+use crate::{
+    geometry::Geometry,
+    topology::{Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid},
+    validate::{SketchValidationError, SolidValidationError},
+};
+
+use super::{
+    checks::{
+        AdjacentHalfEdgesNotConnected, CoincidentHalfEdgesAreNotSiblings,
+        FaceHasNoBoundary, FaceIsSliver, FaceWindingNormalMismatch,
+        GeometryHasNonFiniteCoordinates, HalfEdgeHasNoSibling,
+        InteriorCycleHasInvalidWinding, MultipleReferencesToObject,
+    },
+    ValidationCheck, ValidationConfig, ValidationError, ValidationErrorCode,
+};
+
+/// A validation check that can be stored in a [`CheckRegistry`]
+///
+/// Unlike [`ValidationCheck`], this trait is object-safe. Checks that implement
+/// [`ValidationCheck`] can be registered using [`StaticCheck`]. Checks that are
+/// defined outside of this crate implement this trait directly, and report
+/// their errors using [`ValidationError::Custom`].
+pub trait DynValidationCheck<T> {
+    /// Access the codes of the errors that the check can produce
+    fn codes(&self) -> &[ValidationErrorCode];
+
+    /// Access the name of the check
+    ///
+    /// Used to identify the check in `ValidationTimings`, if the
+    /// `validation-timings` feature is enabled. Defaults to the name of the
+    /// type that implements this trait.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Run the validation check on the provided object
+    fn check(
+        &self,
+        object: &T,
+        geometry: &Geometry,
+        config: &ValidationConfig,
+    ) -> Vec<ValidationError>;
+}
+
+/// Adapter that makes a [`ValidationCheck`] available as [`DynValidationCheck`]
+pub struct StaticCheck<C> {
+    codes: &'static [ValidationErrorCode],
+    _check: PhantomData<fn() -> C>,
+}
+
+impl<C> StaticCheck<C> {
+    /// Construct an instance of `StaticCheck`
+    ///
+    /// The caller must provide the codes of all errors that the check can
+    /// produce.
+    pub fn new(codes: &'static [ValidationErrorCode]) -> Self {
+        Self {
+            codes,
+            _check: PhantomData,
+        }
+    }
+}
+
+impl<T, C> DynValidationCheck<T> for StaticCheck<C>
+where
+    C: ValidationCheck<T>,
+    ValidationError: From<C>,
+{
+    fn codes(&self) -> &[ValidationErrorCode] {
+        self.codes
+    }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<C>()
+    }
+
+    fn check(
+        &self,
+        object: &T,
+        geometry: &Geometry,
+        config: &ValidationConfig,
+    ) -> Vec<ValidationError> {
+        C::check(object, geometry, config).map(Into::into).collect()
+    }
+}
+
+/// # A collection of validation checks for one kind of object
+///
+/// Checks are run in the order they were registered. Checks can be disabled
+/// and re-enabled by the codes of the errors they produce.
+///
+/// The registries that are used during validation are stored in
+/// [`ValidationConfig::checks`]. Cloning a registry is cheap, as the checks
+/// themselves are shared between the clones.
+pub struct CheckRegistry<T> {
+    checks: Vec<Arc<dyn DynValidationCheck<T> + Send + Sync>>,
+    disabled: BTreeSet<ValidationErrorCode>,
+}
+
+impl<T> CheckRegistry<T> {
+    /// Construct an empty registry
+    pub fn new() -> Self {
+        Self {
+            checks: Vec::new(),
+            disabled: BTreeSet::new(),
+        }
+    }
+
+    /// Register a validation check
+    pub fn register(
+        &mut self,
+        check: impl DynValidationCheck<T> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.checks.push(Arc::new(check));
+        self
+    }
+
+    /// Enable the errors with the provided code
+    ///
+    /// Errors are enabled by default. This only has an effect, if the errors
+    /// have been disabled before.
+    pub fn enable(&mut self, code: ValidationErrorCode) -> &mut Self {
+        self.disabled.remove(&code);
+        self
+    }
+
+    /// Disable the errors with the provided code
+    ///
+    /// Checks that only produce disabled errors are not run at all.
+    pub fn disable(&mut self, code: ValidationErrorCode) -> &mut Self {
+        self.disabled.insert(code);
+        self
+    }
+
+    /// Access the codes of the errors that the registered checks can produce
+    ///
+    /// The codes are listed in the order in which the checks were registered.
+    /// This includes the codes of disabled errors.
+    pub fn codes(&self) -> Vec<ValidationErrorCode> {
+        self.checks
+            .iter()
+            .flat_map(|check| check.codes().iter().copied())
+            .collect()
+    }
+
+    /// Determine whether the errors with the provided code are enabled
+    pub fn is_enabled(&self, code: ValidationErrorCode) -> bool {
+        !self.disabled.contains(&code)
+    }
+
+    /// Run all registered checks on the provided object
+    ///
+    /// Checks are only run, if at least one of the errors they can produce is
+    /// enabled in this registry, and included in
+    /// [`ValidationConfig::error_codes`].
+    pub fn run_all(
+        &self,
+        object: &T,
+        geometry: &Geometry,
+        config: &ValidationConfig,
+    ) -> Vec<ValidationError> {
+        self.enabled_checks(config)
+            .flat_map(|check| check.check(object, geometry, config))
+            .filter(|err| self.is_enabled(err.code()))
+            .collect()
+    }
+
+    /// Run all registered checks, recording how long each of them takes
+    ///
+    /// Works like [`CheckRegistry::run_all`], but also returns the time spent
+    /// in each check that was run. Checks that are disabled are not run, and
+    /// don't show up in the timings.
+    #[cfg(feature = "validation-timings")]
+    pub fn run_all_timed(
+        &self,
+        object: &T,
+        geometry: &Geometry,
+        config: &ValidationConfig,
+    ) -> (Vec<ValidationError>, ValidationTimings) {
+        let mut errors = Vec::new();
+        let mut timings = ValidationTimings::default();
+
+        for check in self.enabled_checks(config) {
+            let start = std::time::Instant::now();
+            let check_errors = check.check(object, geometry, config);
+            timings.record(check.name(), start.elapsed());
+
+            errors.extend(
+                check_errors
+                    .into_iter()
+                    .filter(|err| self.is_enabled(err.code())),
+            );
+        }
+
+        (errors, timings)
+    }
+
+    fn enabled_checks<'r>(
+        &'r self,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = &'r dyn DynValidationCheck<T>> + 'r {
+        let is_enabled = move |code| {
+            self.is_enabled(code) && config.error_codes.includes(code)
+        };
+
+        self.checks
+            .iter()
+            .map(|check| check.as_ref() as &dyn DynValidationCheck<T>)
+            .filter(move |check| {
+                check.codes().iter().any(|&code| is_enabled(code))
+            })
+    }
+}
+
+/// The time spent in each validation check
+///
+/// Returned by [`CheckRegistry::run_all_timed`]. Checks are identified by
+/// [`DynValidationCheck::name`].
+#[cfg(feature = "validation-timings")]
+#[derive(Clone, Debug, Default)]
+pub struct ValidationTimings {
+    inner: std::collections::BTreeMap<&'static str, std::time::Duration>,
+}
+
+#[cfg(feature = "validation-timings")]
+impl ValidationTimings {
+    /// Access the time spent in the check with the provided name
+    pub fn get(&self, name: &str) -> Option<std::time::Duration> {
+        self.inner.get(name).copied()
+    }
+
+    /// Iterate over all checks and the time spent in them
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, std::time::Duration)> + '_ {
+        self.inner.iter().map(|(&name, &duration)| (name, duration))
+    }
+
+    /// The total time spent in all checks
+    pub fn total(&self) -> std::time::Duration {
+        self.inner.values().sum()
+    }
+
+    /// Add the time spent in the checks from another instance to this one
+    pub fn merge(&mut self, other: &Self) {
+        for (name, duration) in other.iter() {
+            self.record(name, duration);
+        }
+    }
+
+    fn record(&mut self, name: &'static str, duration: std::time::Duration) {
+        *self.inner.entry(name).or_default() += duration;
+    }
+}
+
+impl<T> Clone for CheckRegistry<T> {
+    fn clone(&self) -> Self {
+        Self {
+            checks: self.checks.clone(),
+            disabled: self.disabled.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for CheckRegistry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let checks = self
+            .checks
+            .iter()
+            .map(|check| check.name())
+            .collect::<Vec<_>>();
+
+        f.debug_struct("CheckRegistry")
+            .field("checks", &checks)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
+}
+
+impl<T> Default for CheckRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # The validation checks for each kind of object that has them
+///
+/// See [`ValidationConfig::checks`]. Defaults to the built-in checks.
+#[derive(Clone, Debug)]
+pub struct ValidationChecks {
+    /// The checks that are run for each [`Face`]
+    pub face: CheckRegistry<Face>,
+
+    /// The checks that are run for each [`Shell`]
+    pub shell: CheckRegistry<Shell>,
+
+    /// The checks that are run for each [`Solid`]
+    pub solid: CheckRegistry<Solid>,
+
+    /// The checks that are run for each [`Sketch`]
+    pub sketch: CheckRegistry<Sketch>,
+}
+
+impl Default for ValidationChecks {
+    fn default() -> Self {
+        Self {
+            face: CheckRegistry::<Face>::builtin(),
+            shell: CheckRegistry::<Shell>::builtin(),
+            solid: CheckRegistry::<Solid>::builtin(),
+            sketch: CheckRegistry::<Sketch>::builtin(),
+        }
+    }
+}
+
+/// Implemented for objects that have built-in validation checks
+///
+/// See [`CheckRegistry::builtin`] and [`applicable_checks`].
+pub trait BuiltinChecks: Sized {
+    /// The codes of the built-in checks for this kind of object
+    ///
+    /// Listed in the order in which the checks are registered by
+    /// [`BuiltinChecks::builtin_checks`].
+    const CODES: &'static [ValidationErrorCode];
+
+    /// Construct a registry with the built-in checks for this kind of object
+    fn builtin_checks() -> CheckRegistry<Self>;
+}
+
+impl<T: BuiltinChecks> CheckRegistry<T> {
+    /// Construct a registry with all built-in checks for objects of type `T`
+    pub fn builtin() -> Self {
+        T::builtin_checks()
+    }
+}
+
+impl BuiltinChecks for Face {
+    const CODES: &'static [ValidationErrorCode] = &[
+        ValidationErrorCode::AdjacentHalfEdgesNotConnected,
+        ValidationErrorCode::FaceHasNoBoundary,
+        ValidationErrorCode::InteriorCycleHasInvalidWinding,
+    ];
+
+    /// Construct a registry with all built-in checks for [`Face`]
+    fn builtin_checks() -> CheckRegistry<Self> {
+        let mut registry = CheckRegistry::new();
+        registry
+            .register(StaticCheck::<AdjacentHalfEdgesNotConnected>::new(&[
+                ValidationErrorCode::AdjacentHalfEdgesNotConnected,
+            ]))
+            .register(StaticCheck::<FaceHasNoBoundary>::new(&[
+                ValidationErrorCode::FaceHasNoBoundary,
+            ]))
+            .register(StaticCheck::<InteriorCycleHasInvalidWinding>::new(&[
+                ValidationErrorCode::InteriorCycleHasInvalidWinding,
+            ]));
+        registry
+    }
+}
+
+impl BuiltinChecks for Shell {
+    const CODES: &'static [ValidationErrorCode] = &[
+        ValidationErrorCode::HalfEdgeHasNoSibling,
+        ValidationErrorCode::CoincidentHalfEdgesAreNotSiblings,
+    ];
+
+    /// Construct a registry with all built-in checks for [`Shell`]
+    ///
+    /// This does not include [`SweptShellIsInconsistent`], which only applies
+    /// to shells that were created by a sweep.
+    ///
+    /// [`SweptShellIsInconsistent`]: super::checks::SweptShellIsInconsistent
+    fn builtin_checks() -> CheckRegistry<Self> {
+        let mut registry = CheckRegistry::new();
+        registry
+            .register(StaticCheck::<HalfEdgeHasNoSibling>::new(&[
+                ValidationErrorCode::HalfEdgeHasNoSibling,
+            ]))
+            .register(StaticCheck::<CoincidentHalfEdgesAreNotSiblings>::new(
+                &[ValidationErrorCode::CoincidentHalfEdgesAreNotSiblings],
+            ));
+        registry
+    }
+}
+
+impl BuiltinChecks for Solid {
+    const CODES: &'static [ValidationErrorCode] = &[
+        ValidationErrorCode::GeometryHasNonFiniteCoordinates,
+        ValidationErrorCode::FaceWindingNormalMismatch,
+        ValidationErrorCode::FaceIsSliver,
+        ValidationErrorCode::MultipleReferencesToFace,
+        ValidationErrorCode::MultipleReferencesToRegion,
+        ValidationErrorCode::MultipleReferencesToCycle,
+        ValidationErrorCode::MultipleReferencesToHalfEdge,
+        ValidationErrorCode::SolidDistinctVerticesCoincide,
+        ValidationErrorCode::SolidIdenticalVerticesNotCoincident,
+    ];
+
+    /// Construct a registry with all built-in checks for [`Solid`]
+    fn builtin_checks() -> CheckRegistry<Self> {
+        let mut registry = CheckRegistry::new();
+        registry
+            .register(StaticCheck::<GeometryHasNonFiniteCoordinates>::new(&[
+                ValidationErrorCode::GeometryHasNonFiniteCoordinates,
+            ]))
+            .register(StaticCheck::<FaceWindingNormalMismatch>::new(&[
+                ValidationErrorCode::FaceWindingNormalMismatch,
+            ]))
+            .register(StaticCheck::<FaceIsSliver>::new(&[
+                ValidationErrorCode::FaceIsSliver,
+            ]))
+            .register(
+                StaticCheck::<MultipleReferencesToObject<Face, Shell>>::new(&[
+                    ValidationErrorCode::MultipleReferencesToFace,
+                ]),
+            )
+            .register(
+                StaticCheck::<MultipleReferencesToObject<Region, Face>>::new(
+                    &[ValidationErrorCode::MultipleReferencesToRegion],
+                ),
+            )
+            .register(
+                StaticCheck::<MultipleReferencesToObject<Cycle, Region>>::new(
+                    &[ValidationErrorCode::MultipleReferencesToCycle],
+                ),
+            )
+            .register(
+                StaticCheck::<MultipleReferencesToObject<HalfEdge, Cycle>>::new(
+                    &[ValidationErrorCode::MultipleReferencesToHalfEdge],
+                ),
+            )
+            .register(StaticCheck::<SolidValidationError>::new(&[
+                ValidationErrorCode::SolidDistinctVerticesCoincide,
+                ValidationErrorCode::SolidIdenticalVerticesNotCoincident,
+            ]));
+        registry
+    }
+}
+
+impl BuiltinChecks for Sketch {
+    const CODES: &'static [ValidationErrorCode] = &[
+        ValidationErrorCode::GeometryHasNonFiniteCoordinates,
+        ValidationErrorCode::AdjacentHalfEdgesNotConnected,
+        ValidationErrorCode::MultipleReferencesToCycle,
+        ValidationErrorCode::MultipleReferencesToHalfEdge,
+        ValidationErrorCode::SketchClockwiseExteriorCycle,
+        ValidationErrorCode::SketchCounterClockwiseInteriorCycle,
+    ];
+
+    /// Construct a registry with all built-in checks for [`Sketch`]
+    fn builtin_checks() -> CheckRegistry<Self> {
+        let mut registry = CheckRegistry::new();
+        registry
+            .register(StaticCheck::<GeometryHasNonFiniteCoordinates>::new(&[
+                ValidationErrorCode::GeometryHasNonFiniteCoordinates,
+            ]))
+            .register(StaticCheck::<AdjacentHalfEdgesNotConnected>::new(&[
+                ValidationErrorCode::AdjacentHalfEdgesNotConnected,
+            ]))
+            .register(
+                StaticCheck::<MultipleReferencesToObject<Cycle, Region>>::new(
+                    &[ValidationErrorCode::MultipleReferencesToCycle],
+                ),
+            )
+            .register(
+                StaticCheck::<MultipleReferencesToObject<HalfEdge, Cycle>>::new(
+                    &[ValidationErrorCode::MultipleReferencesToHalfEdge],
+                ),
+            )
+            .register(StaticCheck::<SketchValidationError>::new(&[
+                ValidationErrorCode::SketchClockwiseExteriorCycle,
+                ValidationErrorCode::SketchCounterClockwiseInteriorCycle,
+            ]));
+        registry
+    }
+}
+
+/// Access the codes of the built-in checks that apply to objects of type `O`
+///
+/// The codes are listed in the order in which the checks are run by
+/// [`CheckRegistry::builtin`]. Checks of objects that `O` refers to are not
+/// included. Validating a [`Solid`] does not validate its [`Shell`]s, for
+/// example.
+///
+/// See [`BuiltinChecks::CODES`].
+pub fn applicable_checks<O: BuiltinChecks>() -> &'static [ValidationErrorCode] {
+    O::CODES
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Geometry,
+        operations::{
+            build::{BuildFace, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            update::UpdateRegion,
+            update::UpdateSketch,
+        },
+        topology::{Face, Shell, Sketch, Solid},
+        validation::{
+            CustomValidationError, ValidationConfig, ValidationError,
+            ValidationErrorCode, ValidationErrorCodeFilter,
+        },
+        Core,
+    };
+
+    use super::{
+        applicable_checks, BuiltinChecks, CheckRegistry, DynValidationCheck,
+    };
+
+    struct MaxRegions(usize);
+
+    impl DynValidationCheck<Sketch> for MaxRegions {
+        fn codes(&self) -> &[ValidationErrorCode] {
+            &[ValidationErrorCode::Custom("max-regions")]
+        }
+
+        fn check(
+            &self,
+            object: &Sketch,
+            _: &Geometry,
+            _: &ValidationConfig,
+        ) -> Vec<ValidationError> {
+            let num_regions = object.regions().len();
+
+            if num_regions <= self.0 {
+                return Vec::new();
+            }
+
+            vec![CustomValidationError {
+                code: "max-regions",
+                message: format!("Sketch has {num_regions} regions"),
+            }
+            .into()]
+        }
+    }
+
+    struct RejectFaces;
+
+    impl DynValidationCheck<Face> for RejectFaces {
+        fn codes(&self) -> &[ValidationErrorCode] {
+            &[ValidationErrorCode::Custom("reject-faces")]
+        }
+
+        fn check(
+            &self,
+            _: &Face,
+            _: &Geometry,
+            _: &ValidationConfig,
+        ) -> Vec<ValidationError> {
+            vec![CustomValidationError {
+                code: "reject-faces",
+                message: String::from("Faces are not allowed"),
+            }
+            .into()]
+        }
+    }
+
+    #[test]
+    fn checks_from_config_run_on_insert() {
+        let code = ValidationErrorCode::Custom("reject-faces");
+
+        let codes = |config: ValidationConfig| {
+            let mut core = Core::with_validation_config(config);
+            let _ = Face::circle(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0.],
+                1.,
+                &mut core,
+            )
+            .insert(&mut core);
+
+            core.layers
+                .validation
+                .take_issues()
+                .errors
+                .into_iter()
+                .map(|err| err.code())
+                .collect::<Vec<_>>()
+        };
+
+        let mut config = ValidationConfig::default();
+        config.checks.face.register(RejectFaces);
+        assert!(codes(config.clone()).contains(&code));
+
+        config.checks.face.disable(code);
+        assert!(!codes(config).contains(&code));
+    }
+
+    #[test]
+    fn checks_excluded_by_error_codes_are_not_run() {
+        struct Unreachable;
+
+        impl DynValidationCheck<Sketch> for Unreachable {
+            fn codes(&self) -> &[ValidationErrorCode] {
+                &[ValidationErrorCode::Custom("unreachable")]
+            }
+
+            fn check(
+                &self,
+                _: &Sketch,
+                _: &Geometry,
+                _: &ValidationConfig,
+            ) -> Vec<ValidationError> {
+                unreachable!("Check is excluded and must not run")
+            }
+        }
+
+        let mut core = Core::new();
+        let sketch = Sketch::circle([0., 0.], 1., &mut core);
+
+        let mut registry = CheckRegistry::<Sketch>::new();
+        registry.register(Unreachable);
+
+        let config = ValidationConfig {
+            error_codes: ValidationErrorCodeFilter::Deny(
+                [ValidationErrorCode::Custom("unreachable")].into(),
+            ),
+            ..ValidationConfig::default()
+        };
+        assert!(registry
+            .run_all(&sketch, &core.layers.geometry, &config)
+            .is_empty());
+    }
+
+    #[test]
+    fn custom_check_runs_alongside_builtin_checks() {
+        let mut core = Core::new();
+
+        let valid = Sketch::circle([0., 0.], 1., &mut core);
+        let invalid = valid.update_region(
+            valid.regions().first(),
+            |region, core| {
+                [region
+                    .update_exterior(|cycle, core| cycle.reverse(core), core)]
+            },
+            &mut core,
+        );
+
+        let custom = ValidationErrorCode::Custom("max-regions");
+        let builtin = ValidationErrorCode::SketchClockwiseExteriorCycle;
+
+        let mut registry = CheckRegistry::<Sketch>::builtin();
+        registry.register(MaxRegions(0));
+
+        let codes = |registry: &CheckRegistry<Sketch>| {
+            registry
+                .run_all(
+                    &invalid,
+                    &core.layers.geometry,
+                    &ValidationConfig::default(),
+                )
+                .into_iter()
+                .map(|err| err.code())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(codes(&registry), [builtin, custom]);
+
+        registry.disable(builtin);
+        assert_eq!(codes(&registry), [custom]);
+
+        registry.disable(custom);
+        assert_eq!(codes(&registry), []);
+
+        registry.enable(builtin);
+        assert_eq!(codes(&registry), [builtin]);
+    }
+
+    #[cfg(feature = "validation-timings")]
+    #[test]
+    fn timings_are_recorded_for_each_executed_check() {
+        let mut core = Core::new();
+        let sketch = Sketch::circle([0., 0.], 1., &mut core);
+        let config = ValidationConfig::default();
+
+        let sketch_check =
+            std::any::type_name::<crate::validate::SketchValidationError>();
+
+        let mut registry = CheckRegistry::<Sketch>::builtin();
+        registry.register(MaxRegions(0));
+
+        let (_, timings) =
+            registry.run_all_timed(&sketch, &core.layers.geometry, &config);
+        assert!(timings.get(sketch_check).is_some());
+
+        registry.disable(ValidationErrorCode::SketchClockwiseExteriorCycle);
+        registry
+            .disable(ValidationErrorCode::SketchCounterClockwiseInteriorCycle);
+
+        let (errors, timings) =
+            registry.run_all_timed(&sketch, &core.layers.geometry, &config);
+
+        assert_eq!(
+            errors.into_iter().map(|err| err.code()).collect::<Vec<_>>(),
+            [ValidationErrorCode::Custom("max-regions")],
+        );
+
+        let names = timings.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let expected = registry
+            .enabled_checks(&config)
+            .map(|check| check.name())
+            .collect::<std::collections::BTreeSet<_>>();
+        assert_eq!(names.len(), expected.len());
+        assert!(names.iter().all(|name| expected.contains(name)));
+
+        assert!(timings.get(std::any::type_name::<MaxRegions>()).is_some());
+        assert!(timings.get(sketch_check).is_none());
+    }
+
+    #[test]
+    fn applicable_checks_of_solid() {
+        let codes = applicable_checks::<Solid>();
+
+        assert!(codes.contains(&ValidationErrorCode::FaceWindingNormalMismatch));
+        assert!(
+            codes.contains(&ValidationErrorCode::SolidDistinctVerticesCoincide)
+        );
+        assert!(codes.contains(
+            &ValidationErrorCode::SolidIdenticalVerticesNotCoincident
+        ));
+        assert!(
+            !codes.contains(&ValidationErrorCode::SketchClockwiseExteriorCycle)
+        );
+    }
+
+    #[test]
+    fn applicable_checks_match_builtin_registry() {
+        fn check<O: BuiltinChecks>() {
+            assert_eq!(
+                applicable_checks::<O>(),
+                CheckRegistry::<O>::builtin().codes()
+            );
+        }
+
+        check::<Face>();
+        check::<Shell>();
+        check::<Solid>();
+        check::<Sketch>();
+    }
+
+    #[test]
+    fn codes_include_custom_checks() {
+        let mut registry = CheckRegistry::<Sketch>::builtin();
+        registry.register(MaxRegions(0));
+
+        let codes = registry.codes();
+        assert_eq!(codes[..codes.len() - 1], *applicable_checks::<Sketch>());
+        assert_eq!(
+            codes.last(),
+            Some(&ValidationErrorCode::Custom("max-regions"))
+        );
+    }
+}
@@ -0,0 +1,86 @@
+/// A summary of validation results
+///
+/// See [`Layer<Validation>::summary`].
+///
+/// [`Layer<Validation>::summary`]: crate::layers::Layer::summary
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValidationSummary {
+    /// The number of validation errors of severity [`Severity::Error`]
+    pub errors: usize,
+
+    /// The number of validation errors of severity [`Severity::Warning`]
+    pub warnings: usize,
+
+    /// The policy that decides whether warnings are acceptable
+    pub warning_policy: WarningPolicy,
+}
+
+impl ValidationSummary {
+    /// Indicate whether the validated model is acceptable
+    ///
+    /// A model is never acceptable, if there are errors. Whether it is
+    /// acceptable with warnings, depends on the [`WarningPolicy`].
+    pub fn is_ok(&self) -> bool {
+        let warnings_ok = match self.warning_policy {
+            WarningPolicy::Allow => true,
+            WarningPolicy::Deny => self.warnings == 0,
+        };
+
+        self.errors == 0 && warnings_ok
+    }
+}
+
+/// The severity of a validation error
+///
+/// See [`ValidationError::severity`].
+///
+/// [`ValidationError::severity`]: super::ValidationError::severity
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Severity {
+    /// The object is questionable, but can still be used
+    Warning,
+
+    /// The object is invalid
+    Error,
+}
+
+/// Decides whether warnings make a model unacceptable
+///
+/// See [`ValidationSummary::is_ok`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WarningPolicy {
+    /// Warnings are acceptable
+    #[default]
+    Allow,
+
+    /// Warnings are treated like errors
+    Deny,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValidationSummary, WarningPolicy};
+
+    #[test]
+    fn only_warnings() {
+        let summary = |warning_policy| ValidationSummary {
+            errors: 0,
+            warnings: 2,
+            warning_policy,
+        };
+
+        assert!(summary(WarningPolicy::default()).is_ok());
+        assert!(!summary(WarningPolicy::Deny).is_ok());
+    }
+
+    #[test]
+    fn errors() {
+        let summary = ValidationSummary {
+            errors: 1,
+            warnings: 0,
+            warning_policy: WarningPolicy::Allow,
+        };
+
+        assert!(!summary.is_ok());
+    }
+}
@@ -5,18 +5,39 @@ use super::{ValidationConfig, ValidationError};
 /// Errors that occurred while validating the objects inserted into the stores
 #[derive(Default)]
 pub struct Validation {
-    /// All unhandled validation errors
+    /// All unhandled validation errors of severity [`Severity::Error`]
+    ///
+    /// [`Severity::Error`]: super::Severity::Error
     pub errors: Vec<ValidationError>,
 
+    /// All unhandled validation errors of severity [`Severity::Warning`]
+    ///
+    /// Unlike errors, unhandled warnings don't cause a panic, when `Validation`
+    /// is dropped.
+    ///
+    /// [`Severity::Warning`]: super::Severity::Warning
+    pub warnings: Vec<ValidationError>,
+
     /// Validation configuration for the validation service
     pub config: ValidationConfig,
+
+    /// The time spent in each validation check
+    ///
+    /// Accumulated over all objects that have been validated.
+    #[cfg(feature = "validation-timings")]
+    pub timings: super::ValidationTimings,
 }
 
 impl Validation {
     /// Construct an instance of `Validation`, using the provided configuration
     pub fn with_validation_config(config: ValidationConfig) -> Self {
-        let errors = Vec::new();
-        Self { errors, config }
+        Self {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            config,
+            #[cfg(feature = "validation-timings")]
+            timings: super::ValidationTimings::default(),
+        }
     }
 }
 
@@ -10,22 +10,47 @@
 
 use std::{
     fs::File,
-    io::{Seek, Write},
+    io::{Seek, SeekFrom, Write},
     path::Path,
 };
 
 use thiserror::Error;
+use zip::{write::SimpleFileOptions, ZipWriter};
 
-use fj_interop::Mesh;
-use fj_math::{Point, Triangle};
+use fj_interop::{Color, MaterialId, Mesh};
+use fj_math::{Point, Scalar, Triangle, Vector};
 
 /// Export the provided mesh to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
-/// the provided path is used to switch between supported types.
+/// Currently 3MF, STL, OBJ & PLY file types are supported. The case insensitive file
+/// extension of the provided path is used to switch between supported types.
+/// PLY files are written in the binary format.
+///
+/// Coordinates are written as they are. See [`export_with_options`], to remove
+/// floating-point noise from the output.
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+    export_with_options(mesh, path, ExportOptions::default())
+}
+
+/// Export the provided mesh to the file at the given path, using options
+///
+/// Works like [`export`], but applies the provided [`ExportOptions`].
+pub fn export_with_options(
+    mesh: &Mesh<Point<3>>,
+    path: &Path,
+    options: ExportOptions,
+) -> Result<(), Error> {
+    let snapped;
+    let mesh = match options.snap_zeros {
+        Some(tolerance) => {
+            snapped = mesh.snap_zeros(tolerance);
+            &snapped
+        }
+        None => mesh,
+    };
+
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
             let mut file = File::create(path)?;
@@ -39,6 +64,10 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
             let mut file = File::create(path)?;
             export_obj(mesh, &mut file)
         }
+        Some(extension) if extension.to_ascii_uppercase() == "PLY" => {
+            let mut file = File::create(path)?;
+            export_ply(mesh, &mut file, PlyFormat::BinaryLittleEndian)
+        }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
         )),
@@ -46,42 +75,162 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
+/// Options for [`export_with_options`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExportOptions {
+    /// Set coordinates that are within this tolerance of zero to exact zero
+    ///
+    /// This removes floating-point noise, like `1e-17`, from the output. See
+    /// [`Mesh::snap_zeros`]. Defaults to `None`, which writes coordinates as
+    /// they are.
+    pub snap_zeros: Option<Scalar>,
+}
+
 /// Export the provided mesh to the provided writer in the 3MF format.
+///
+/// Triangles are assigned base materials. Triangles that have the same
+/// material share a base material. Triangles without a material share one with
+/// all other triangles of the same color.
 pub fn export_3mf(
     mesh: &Mesh<Point<3>>,
     write: impl Write + Seek,
 ) -> Result<(), Error> {
-    let vertices = mesh
-        .vertices()
-        .map(|point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        })
-        .collect();
+    let mut materials = Vec::new();
+    let mut material_indices = Vec::new();
+    for triangle in mesh.triangles() {
+        let key = match triangle.material {
+            Some(material) => ThreeMfMaterial::Material(material),
+            None => ThreeMfMaterial::Color(triangle.color),
+        };
 
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
+        let index = match materials.iter().position(|(k, _)| *k == key) {
+            Some(index) => index,
+            None => {
+                materials.push((key, triangle.color));
+                materials.len() - 1
+            }
+        };
+        material_indices.push(index);
+    }
 
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
-    };
+    // The `threemf` crate doesn't support materials. Its model has no way to
+    // define base materials, nor to reference them from triangles. So we write
+    // the model ourselves.
+    let mut model = String::new();
 
-    threemf::write(write, mesh)?;
+    model.push_str(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+        <model unit=\"millimeter\" \
+        xmlns=\"http://schemas.microsoft.com/3dmanufacturing/core/2015/02\">\n\
+        <resources>\n\
+        <basematerials id=\"1\">\n",
+    );
+    for (key, color) in &materials {
+        let name = match key {
+            ThreeMfMaterial::Material(material) => {
+                format!("Material {}", material.0)
+            }
+            ThreeMfMaterial::Color(color) => {
+                format!("Color {}", color.to_hex())
+            }
+        };
+        model.push_str(&format!(
+            "<base name=\"{}\" displaycolor=\"{}\"/>\n",
+            escape_attribute(&name),
+            escape_attribute(&color.to_hex()),
+        ));
+    }
+    model.push_str(
+        "</basematerials>\n\
+        <object id=\"2\" type=\"model\">\n\
+        <mesh>\n\
+        <vertices>\n",
+    );
+    for point in mesh.vertices() {
+        model.push_str(&format!(
+            "<vertex x=\"{}\" y=\"{}\" z=\"{}\"/>\n",
+            point.x.into_f64(),
+            point.y.into_f64(),
+            point.z.into_f64(),
+        ));
+    }
+    model.push_str("</vertices>\n<triangles>\n");
+    let indices: Vec<_> = mesh.indices().collect();
+    for (triangle, material) in indices.chunks(3).zip(material_indices) {
+        model.push_str(&format!(
+            "<triangle v1=\"{}\" v2=\"{}\" v3=\"{}\" pid=\"1\" p1=\"{material}\"/>\n",
+            triangle[0], triangle[1], triangle[2],
+        ));
+    }
+    model.push_str(
+        "</triangles>\n\
+        </mesh>\n\
+        </object>\n\
+        </resources>\n\
+        <build>\n\
+        <item objectid=\"2\"/>\n\
+        </build>\n\
+        </model>\n",
+    );
+
+    let mut archive = ZipWriter::new(write);
+    for (name, content) in [
+        ("[Content_Types].xml", THREE_MF_CONTENT_TYPES),
+        ("_rels/.rels", THREE_MF_RELS),
+        ("3D/model.model", model.as_str()),
+    ] {
+        archive.start_file(name, SimpleFileOptions::default())?;
+        archive.write_all(content.as_bytes())?;
+    }
+    archive.finish()?;
 
     Ok(())
 }
 
+/// Escape a value, so it can be used within a double-quoted XML attribute
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[derive(Eq, PartialEq)]
+enum ThreeMfMaterial {
+    Material(MaterialId),
+    Color(Color),
+}
+
+const THREE_MF_CONTENT_TYPES: &str = "\
+<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">
+<Default Extension=\"model\" \
+ContentType=\"application/vnd.ms-package.3dmanufacturing-3dmodel+xml\"/>
+<Default Extension=\"rels\" \
+ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>
+</Types>
+";
+
+const THREE_MF_RELS: &str = "\
+<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<Relationships \
+xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">
+<Relationship \
+Type=\"http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel\" \
+Target=\"/3D/model.model\" Id=\"rel0\"/>
+</Relationships>
+";
+
 /// Export the provided mesh to the provided writer in the STL format.
 pub fn export_stl(
     mesh: &Mesh<Point<3>>,
@@ -131,15 +280,240 @@ pub fn export_stl(
 
 /// Export the provided mesh to the provided writer in the OBJ format.
 pub fn export_obj(
+    mesh: &Mesh<Point<3>>,
+    write: impl Write,
+) -> Result<(), Error> {
+    let mut writer = ObjWriter::new(write);
+
+    for triangle in mesh.triangles() {
+        writer.write_triangle(triangle.inner.points)?;
+    }
+
+    writer.finish()?;
+
+    Ok(())
+}
+
+/// Export the provided mesh to the provided writer in the PLY format.
+///
+/// Each vertex is written with its position, normal, and color. The normal of
+/// a vertex is the average of the normals of the triangles that share it. Its
+/// color is taken from [`Mesh::vertex_colors`], if those have been assigned.
+/// Otherwise, it is the color of the first triangle that uses the vertex.
+pub fn export_ply(
     mesh: &Mesh<Point<3>>,
     mut write: impl Write,
+    format: PlyFormat,
 ) -> Result<(), Error> {
-    for (cnt, t) in mesh.triangles().enumerate() {
+    let vertices: Vec<_> = mesh.vertices().collect();
+    let indices: Vec<_> = mesh.indices().collect();
+
+    let mut normals = vec![Vector::from([0., 0., 0.]); vertices.len()];
+    let mut colors = vec![None; vertices.len()];
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        let normal = triangle.inner.normal();
+        for &index in indices {
+            let index = index as usize;
+            normals[index] = normals[index] + normal;
+            colors[index].get_or_insert(triangle.color);
+        }
+    }
+    let colors: Vec<_> = match mesh.vertex_colors() {
+        Some(vertex_colors) => vertex_colors.to_vec(),
+        None => colors
+            .into_iter()
+            .map(|color| color.unwrap_or_default())
+            .collect(),
+    };
+
+    let num_faces = indices.len() / 3;
+    let format_name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+    };
+    write!(
+        write,
+        "ply\n\
+        format {format_name} 1.0\n\
+        element vertex {}\n\
+        property float x\n\
+        property float y\n\
+        property float z\n\
+        property float nx\n\
+        property float ny\n\
+        property float nz\n\
+        property uchar red\n\
+        property uchar green\n\
+        property uchar blue\n\
+        property uchar alpha\n\
+        element face {num_faces}\n\
+        property list uchar uint vertex_indices\n\
+        end_header\n",
+        vertices.len(),
+    )?;
+
+    for ((point, normal), color) in vertices.iter().zip(normals).zip(colors) {
+        let normal = if normal.magnitude() == Scalar::ZERO {
+            normal
+        } else {
+            normal.normalize()
+        };
+        let components = point
+            .coords
+            .components
+            .into_iter()
+            .chain(normal.components)
+            .map(|s| s.into_f32());
+
+        match format {
+            PlyFormat::Ascii => {
+                let components: Vec<_> =
+                    components.map(|c| c.to_string()).collect();
+                let [r, g, b, a] = color.0;
+                writeln!(write, "{} {r} {g} {b} {a}", components.join(" "))?;
+            }
+            PlyFormat::BinaryLittleEndian => {
+                for component in components {
+                    write.write_all(&component.to_le_bytes())?;
+                }
+                write.write_all(&color.0)?;
+            }
+        }
+    }
+
+    for triangle in indices.chunks(3) {
+        match format {
+            PlyFormat::Ascii => {
+                writeln!(
+                    write,
+                    "3 {} {} {}",
+                    triangle[0], triangle[1], triangle[2],
+                )?;
+            }
+            PlyFormat::BinaryLittleEndian => {
+                write.write_all(&[3])?;
+                for index in triangle {
+                    write.write_all(&index.to_le_bytes())?;
+                }
+            }
+        }
+    }
+
+    write.flush()?;
+
+    Ok(())
+}
+
+/// The encoding of a PLY file
+///
+/// See [`export_ply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlyFormat {
+    /// Human-readable text
+    Ascii,
+
+    /// Binary data, in little-endian byte order
+    BinaryLittleEndian,
+}
+
+/// Writes triangles to the provided writer in the binary STL format
+///
+/// Unlike [`export_stl`], this doesn't need the whole mesh up front. Triangles
+/// are written one at a time, which means a large model doesn't need to be
+/// held in memory, while it is being exported.
+///
+/// The binary STL header contains the number of triangles, which is only known
+/// once all of them have been written. [`StlWriter::finish`] seeks back to
+/// fill it in, which is why the writer needs to implement [`Seek`].
+pub struct StlWriter<W> {
+    write: W,
+    start: u64,
+    num_triangles: u32,
+}
+
+impl<W: Write + Seek> StlWriter<W> {
+    /// Start writing an STL file to the provided writer
+    pub fn new(mut write: W) -> Result<Self, Error> {
+        let start = write.stream_position()?;
+
+        write.write_all(&[0u8; 80])?;
+        write.write_all(&0u32.to_le_bytes())?;
+
+        Ok(Self {
+            write,
+            start,
+            num_triangles: 0,
+        })
+    }
+
+    /// Write a triangle
+    pub fn write_triangle(
+        &mut self,
+        points: [Point<3>; 3],
+    ) -> Result<(), Error> {
+        self.num_triangles = self
+            .num_triangles
+            .checked_add(1)
+            .ok_or(Error::InvalidTriangleCount)?;
+
+        let normal = Triangle::from(points).normal();
+
+        let components = normal.components.into_iter().chain(
+            points.into_iter().flat_map(|point| point.coords.components),
+        );
+        for component in components {
+            self.write.write_all(&component.into_f32().to_le_bytes())?;
+        }
+
+        // attribute byte count
+        self.write.write_all(&0u16.to_le_bytes())?;
+
+        Ok(())
+    }
+
+    /// Finish writing the file, returning the writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        let end = self.write.stream_position()?;
+
+        self.write.seek(SeekFrom::Start(self.start + 80))?;
+        self.write.write_all(&self.num_triangles.to_le_bytes())?;
+        self.write.seek(SeekFrom::Start(end))?;
+
+        Ok(self.write)
+    }
+}
+
+/// Writes triangles to the provided writer in the OBJ format
+///
+/// Unlike [`export_obj`], this doesn't need the whole mesh up front. Triangles
+/// are written one at a time, as they are passed to
+/// [`ObjWriter::write_triangle`].
+pub struct ObjWriter<W> {
+    write: W,
+    num_triangles: usize,
+}
+
+impl<W: Write> ObjWriter<W> {
+    /// Start writing an OBJ file to the provided writer
+    pub fn new(write: W) -> Self {
+        Self {
+            write,
+            num_triangles: 0,
+        }
+    }
+
+    /// Write a triangle
+    pub fn write_triangle(
+        &mut self,
+        points: [Point<3>; 3],
+    ) -> Result<(), Error> {
+        let cnt = self.num_triangles;
+
         // write each point of the triangle
-        for v in t.inner.points {
+        for v in points {
             wavefront_rs::obj::writer::Writer { auto_newline: true }
                 .write(
-                    &mut write,
+                    &mut self.write,
                     &wavefront_rs::obj::entity::Entity::Vertex {
                         x: v.x.into_f64(),
                         y: v.y.into_f64(),
@@ -153,7 +527,7 @@ pub fn export_obj(
         // write the triangle
         wavefront_rs::obj::writer::Writer { auto_newline: true }
             .write(
-                &mut write,
+                &mut self.write,
                 &wavefront_rs::obj::entity::Entity::Face {
                     vertices: vec![
                         wavefront_rs::obj::entity::FaceVertex {
@@ -175,9 +549,17 @@ pub fn export_obj(
                 },
             )
             .or(Err(Error::OBJ))?;
+
+        self.num_triangles += 1;
+
+        Ok(())
     }
 
-    Ok(())
+    /// Finish writing the file, returning the writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.write.flush()?;
+        Ok(self.write)
+    }
 }
 
 /// An error that can occur while exporting
@@ -203,7 +585,238 @@ pub enum Error {
     #[error("threemf error whilst exporting to 3MF file")]
     ThreeMF(#[from] threemf::Error),
 
+    /// Zip error whilst writing the archive of a 3MF file
+    #[error("zip error whilst exporting to 3MF file")]
+    ThreeMFArchive(#[from] zip::result::ZipError),
+
     /// OBJ exporter error whilst exporting to OBJ file
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use fj_core::{
+        algorithms::triangulate::Triangulate,
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            presentation::SetMaterial,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch},
+        Core,
+    };
+    use fj_interop::{Color, MaterialId, Mesh};
+    use fj_math::{Point, Scalar, Vector};
+
+    use super::{
+        escape_attribute, export_3mf, export_ply, export_stl,
+        export_with_options, ExportOptions, ObjWriter, PlyFormat, StlWriter,
+    };
+
+    #[test]
+    fn export_3mf_with_materials() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let cube = Sketch::empty(&core.layers.topology)
+            .add_regions(
+                [Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    surface,
+                    &mut core,
+                )],
+                &mut core,
+            )
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                Vector::from([0., 0., -1.]),
+                &mut core,
+            );
+
+        let faces = cube.shells().only().faces();
+        for (i, face) in faces.iter().enumerate() {
+            let material = if i == 0 { 1 } else { 0 };
+            face.region().set_material(MaterialId(material), &mut core);
+        }
+
+        let tolerance = core.tolerance();
+        let mesh = (&cube, tolerance).triangulate(&mut core);
+
+        let mut file = Cursor::new(Vec::new());
+        export_3mf(&mesh, &mut file)?;
+
+        let mut model = String::new();
+        zip::ZipArchive::new(file)?
+            .by_name("3D/model.model")?
+            .read_to_string(&mut model)?;
+
+        assert_eq!(model.matches("<base ").count(), 2);
+        assert_eq!(
+            model.matches("<triangle ").count(),
+            mesh.triangles().count(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn escape_attribute_escapes_markup() {
+        assert_eq!(
+            escape_attribute(r#"<a b="c" d='e'> & f"#),
+            "&lt;a b=&quot;c&quot; d=&apos;e&apos;&gt; &amp; f",
+        );
+    }
+
+    #[test]
+    fn stl_writer_matches_export_stl() -> anyhow::Result<()> {
+        let mesh = two_triangles();
+
+        let mut expected = Vec::new();
+        export_stl(&mesh, &mut expected)?;
+
+        // Start at an offset, to make sure the header is written relative to
+        // where the writer started.
+        let mut write = Cursor::new(vec![0xff; 3]);
+        write.set_position(3);
+        let mut writer = StlWriter::new(write)?;
+        for triangle in mesh.triangles() {
+            writer.write_triangle(triangle.inner.points)?;
+        }
+        let streamed = writer.finish()?.into_inner();
+
+        assert_eq!(&streamed[..3], &[0xff; 3]);
+        assert_eq!(&streamed[3..], expected.as_slice());
+
+        let stl = stl::read_stl(&mut Cursor::new(&streamed[3..]))?;
+        assert_eq!(stl.header.num_triangles, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn obj_writer() -> anyhow::Result<()> {
+        let mesh = two_triangles();
+
+        let mut writer = ObjWriter::new(Vec::new());
+        for triangle in mesh.triangles() {
+            writer.write_triangle(triangle.inner.points)?;
+        }
+        let obj = String::from_utf8(writer.finish()?)?;
+
+        assert_eq!(
+            obj,
+            "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n\
+            v 1 0 0\nv 1 1 0\nv 0 1 0\nf 4 5 6\n",
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_with_snapped_zeros() -> anyhow::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[1e-17, 0., 0.], [1., -1e-16, 0.], [0., 1., 1e-15]],
+            Color::default(),
+        );
+
+        let path = std::env::temp_dir().join("fj-export-snap-zeros.obj");
+        export_with_options(
+            &mesh,
+            &path,
+            ExportOptions {
+                snap_zeros: Some(Scalar::from(1e-9)),
+            },
+        )?;
+        let obj = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let vertices = obj
+            .lines()
+            .filter(|line| line.starts_with("v "))
+            .collect::<Vec<_>>();
+        assert_eq!(vertices, ["v 0 0 0", "v 1 0 0", "v 0 1 0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_ply_binary_with_colors() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let cube = Sketch::empty(&core.layers.topology)
+            .add_regions(
+                [Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    surface,
+                    &mut core,
+                )],
+                &mut core,
+            )
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                Vector::from([0., 0., 1.]),
+                &mut core,
+            );
+
+        let tolerance = core.tolerance();
+        let mut mesh = (&cube, tolerance).triangulate(&mut core);
+        mesh.color_by(|point| {
+            let blue = (point.z.into_f64() * 255.) as u8;
+            Color([255 - blue, 0, blue, 255])
+        });
+
+        let mut file = Vec::new();
+        export_ply(&mesh, &mut file, PlyFormat::BinaryLittleEndian)?;
+
+        let end_header = b"end_header\n";
+        let header_len = file
+            .windows(end_header.len())
+            .position(|window| window == end_header)
+            .unwrap()
+            + end_header.len();
+        let header = std::str::from_utf8(&file[..header_len])?;
+
+        let count = |element: &str| -> anyhow::Result<usize> {
+            let count = header
+                .lines()
+                .find_map(|line| line.strip_prefix(element))
+                .unwrap()
+                .parse()?;
+            Ok(count)
+        };
+        let num_vertices = count("element vertex ")?;
+        let num_faces = count("element face ")?;
+
+        assert!(header.contains("format binary_little_endian 1.0"));
+        assert_eq!(num_vertices, mesh.vertices().count());
+        assert_eq!(num_faces, mesh.triangles().count());
+
+        // Each vertex has 6 floats and 4 color bytes; each face has a count
+        // byte and 3 indices.
+        assert_eq!(
+            file.len() - header_len,
+            num_vertices * (6 * 4 + 4) + num_faces * (1 + 3 * 4),
+        );
+
+        Ok(())
+    }
+
+    fn two_triangles() -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh
+    }
+}
@@ -0,0 +1,669 @@
+//! Import of DXF files
+//!
+//! Only the ASCII variant of DXF is supported, and only the `ENTITIES` section
+//! is considered. Everything else in the file is ignored.
+
+use std::{collections::BTreeMap, io::Read};
+
+use fj_core::{
+    geometry::{CurveBoundary, LocalCurveGeom, Path},
+    operations::{
+        build::{BuildCycle, BuildHalfEdge},
+        insert::Insert,
+    },
+    storage::Handle,
+    topology::{Cycle, HalfEdge, Region, Sketch, Surface},
+    Core,
+};
+use fj_math::{signed_area, Arc, FillRule, Point, Polygon, Scalar, Vector};
+
+use crate::Error;
+
+/// Import sketches from the DXF file provided by `reader`
+///
+/// Reads `LWPOLYLINE`, `LINE`, `ARC`, and `CIRCLE` entities. `LINE` and `ARC`
+/// entities are chained into loops by their endpoints. Closed loops are
+/// grouped into regions, with loops that lie within another loop becoming
+/// holes in the region of that loop. Arcs and circles are imported as circular
+/// half-edges, not approximated.
+///
+/// One sketch is returned for each DXF layer that contains closed loops,
+/// ordered by layer name.
+///
+/// Open polylines, as well as chains of `LINE` and `ARC` entities that don't
+/// close, are skipped with a warning.
+pub fn import_dxf(
+    mut reader: impl Read,
+    core: &mut Core,
+) -> Result<Vec<Sketch>, Error> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let pairs = parse_group_codes(&input)?;
+
+    let mut loops_by_layer = BTreeMap::<String, Vec<Loop>>::new();
+    let mut chains_by_layer = BTreeMap::<String, Vec<Segment>>::new();
+
+    for entity in entities(&pairs) {
+        let layer = entity.value(8).unwrap_or("0").to_string();
+
+        match entity.kind {
+            "LINE" => {
+                let start = entity.point(10, 20)?;
+                let end = entity.point(11, 21)?;
+
+                chains_by_layer
+                    .entry(layer)
+                    .or_default()
+                    .push(Segment::Line { start, end });
+            }
+            "ARC" => {
+                let center = entity.point(10, 20)?;
+                let radius = entity.number(40)?;
+                let start_angle = entity.number(50)?.to_radians();
+                let end_angle = entity.number(51)?.to_radians();
+
+                // DXF arcs always go counter-clockwise from start to end.
+                let mut angle = (end_angle - start_angle).rem_euclid(TAU);
+                if angle == 0. {
+                    angle = TAU;
+                }
+
+                chains_by_layer
+                    .entry(layer)
+                    .or_default()
+                    .push(Segment::Arc {
+                        start: point_on_circle(center, radius, start_angle),
+                        end: point_on_circle(center, radius, end_angle),
+                        center,
+                        angle: Scalar::from(angle),
+                    });
+            }
+            "CIRCLE" => {
+                let center = entity.point(10, 20)?;
+                let radius = entity.number(40)?;
+
+                // A cycle can't consist of a single half-edge, so the circle
+                // is split into quarters, same as `Cycle::circle` does.
+                let points = [0., 1., 2., 3.].map(|quarter| {
+                    point_on_circle(center, radius, quarter * TAU / 4.)
+                });
+                let segments = (0..points.len())
+                    .map(|i| Segment::Arc {
+                        start: points[i],
+                        end: points[(i + 1) % points.len()],
+                        center,
+                        angle: Scalar::TAU / 4.,
+                    })
+                    .collect();
+
+                loops_by_layer
+                    .entry(layer)
+                    .or_default()
+                    .push(Loop { segments });
+            }
+            "LWPOLYLINE" => {
+                let closed = entity
+                    .value(70)
+                    .map(|flags| parse_number(70, flags))
+                    .transpose()?
+                    .is_some_and(|flags| flags as i64 & 1 == 1);
+                if !closed {
+                    tracing::warn!(
+                        layer,
+                        "Skipping open `LWPOLYLINE` in DXF import"
+                    );
+                    continue;
+                }
+
+                let vertices = entity.polyline_vertices()?;
+                if vertices.len() < 2 {
+                    tracing::warn!(
+                        layer,
+                        "Skipping `LWPOLYLINE` with less than 2 vertices in \
+                        DXF import"
+                    );
+                    continue;
+                }
+
+                let segments = (0..vertices.len())
+                    .map(|i| {
+                        let (start, bulge) = vertices[i];
+                        let (end, _) = vertices[(i + 1) % vertices.len()];
+
+                        Segment::from_bulge(start, end, bulge)
+                    })
+                    .collect();
+
+                loops_by_layer
+                    .entry(layer)
+                    .or_default()
+                    .push(Loop { segments });
+            }
+            _ => {}
+        }
+    }
+
+    for (layer, segments) in chains_by_layer {
+        let loops = chain_segments(segments, &layer);
+        loops_by_layer.entry(layer).or_default().extend(loops);
+    }
+
+    let sketches = loops_by_layer
+        .into_values()
+        .filter(|loops| !loops.is_empty())
+        .map(|loops| build_sketch(loops, core))
+        .collect();
+
+    Ok(sketches)
+}
+
+const TAU: f64 = std::f64::consts::TAU;
+
+/// The maximum distance between two endpoints that are considered connected
+const CONNECTION_TOLERANCE: f64 = 1e-6;
+
+/// The number of points each arc is sampled with, for the containment tests
+const ARC_SAMPLES: usize = 16;
+
+fn parse_group_codes(input: &str) -> Result<Vec<(i32, &str)>, Error> {
+    let mut lines = input.lines().enumerate();
+    let mut pairs = Vec::new();
+
+    while let Some((index, code)) = lines.next() {
+        let code = code.trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        let code = code.parse().map_err(|_| Error::InvalidGroupCode {
+            code: code.to_string(),
+            line: index + 1,
+        })?;
+        let value = lines.next().map(|(_, value)| value.trim()).unwrap_or("");
+
+        pairs.push((code, value));
+    }
+
+    Ok(pairs)
+}
+
+fn parse_number(code: i32, value: &str) -> Result<f64, Error> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| Error::InvalidValue {
+            code,
+            value: value.to_string(),
+        })
+}
+
+fn entities<'r>(pairs: &'r [(i32, &'r str)]) -> Vec<Entity<'r>> {
+    let mut entities = Vec::new();
+    let mut in_entities_section = false;
+
+    let mut i = 0;
+    while i < pairs.len() {
+        let (code, value) = pairs[i];
+        i += 1;
+
+        if code == 0 && value == "SECTION" {
+            in_entities_section = pairs.get(i) == Some(&(2, "ENTITIES"));
+            continue;
+        }
+        if code == 0 && value == "ENDSEC" {
+            in_entities_section = false;
+            continue;
+        }
+        if !in_entities_section || code != 0 {
+            continue;
+        }
+
+        let start = i;
+        while i < pairs.len() && pairs[i].0 != 0 {
+            i += 1;
+        }
+
+        entities.push(Entity {
+            kind: value,
+            pairs: &pairs[start..i],
+        });
+    }
+
+    entities
+}
+
+struct Entity<'r> {
+    kind: &'r str,
+    pairs: &'r [(i32, &'r str)],
+}
+
+impl Entity<'_> {
+    fn value(&self, code: i32) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, value)| *value)
+    }
+
+    fn number(&self, code: i32) -> Result<f64, Error> {
+        let value = self.value(code).ok_or_else(|| Error::MissingValue {
+            entity: self.kind.to_string(),
+            code,
+        })?;
+
+        parse_number(code, value)
+    }
+
+    fn point(&self, x: i32, y: i32) -> Result<Point<2>, Error> {
+        Ok(Point::from([self.number(x)?, self.number(y)?]))
+    }
+
+    fn polyline_vertices(&self) -> Result<Vec<(Point<2>, f64)>, Error> {
+        let mut vertices = Vec::<([f64; 2], f64)>::new();
+
+        for &(code, value) in self.pairs {
+            match code {
+                10 => vertices.push(([parse_number(code, value)?, 0.], 0.)),
+                20 => {
+                    if let Some((point, _)) = vertices.last_mut() {
+                        point[1] = parse_number(code, value)?;
+                    }
+                }
+                42 => {
+                    if let Some((_, bulge)) = vertices.last_mut() {
+                        *bulge = parse_number(code, value)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(vertices
+            .into_iter()
+            .map(|(point, bulge)| (Point::from(point), bulge))
+            .collect())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Segment {
+    Line {
+        start: Point<2>,
+        end: Point<2>,
+    },
+    Arc {
+        start: Point<2>,
+        end: Point<2>,
+        center: Point<2>,
+
+        /// The angle swept from `start` to `end`; positive is counter-clockwise
+        angle: Scalar,
+    },
+}
+
+impl Segment {
+    fn from_bulge(start: Point<2>, end: Point<2>, bulge: f64) -> Self {
+        if bulge == 0. {
+            return Self::Line { start, end };
+        }
+
+        let angle = Scalar::from(4. * bulge.atan());
+        let center = Arc::from_endpoints_and_angle(start, end, angle).center;
+
+        Self::Arc {
+            start,
+            end,
+            center,
+            angle,
+        }
+    }
+
+    fn start(&self) -> Point<2> {
+        match *self {
+            Self::Line { start, .. } | Self::Arc { start, .. } => start,
+        }
+    }
+
+    fn end(&self) -> Point<2> {
+        match *self {
+            Self::Line { end, .. } | Self::Arc { end, .. } => end,
+        }
+    }
+
+    fn with_start(mut self, point: Point<2>) -> Self {
+        match &mut self {
+            Self::Line { start, .. } | Self::Arc { start, .. } => {
+                *start = point;
+            }
+        }
+        self
+    }
+
+    fn with_end(mut self, point: Point<2>) -> Self {
+        match &mut self {
+            Self::Line { end, .. } | Self::Arc { end, .. } => {
+                *end = point;
+            }
+        }
+        self
+    }
+
+    fn reverse(self) -> Self {
+        match self {
+            Self::Line { start, end } => Self::Line {
+                start: end,
+                end: start,
+            },
+            Self::Arc {
+                start,
+                end,
+                center,
+                angle,
+            } => Self::Arc {
+                start: end,
+                end: start,
+                center,
+                angle: -angle,
+            },
+        }
+    }
+
+    /// Push the start point and, for arcs, intermediate points to `points`
+    fn sample(&self, points: &mut Vec<Point<2>>) {
+        match *self {
+            Self::Line { start, .. } => points.push(start),
+            Self::Arc {
+                start,
+                center,
+                angle,
+                ..
+            } => {
+                let radius = start - center;
+
+                points.extend((0..ARC_SAMPLES).map(|i| {
+                    let (sin, cos) =
+                        (angle * i as f64 / ARC_SAMPLES as f64).sin_cos();
+
+                    center
+                        + Vector::from([
+                            radius.u * cos - radius.v * sin,
+                            radius.u * sin + radius.v * cos,
+                        ])
+                }));
+            }
+        }
+    }
+
+    fn build_half_edge(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> (Handle<HalfEdge>, CurveBoundary<Point<1>>) {
+        match *self {
+            Self::Line { start, end } => {
+                HalfEdge::line_segment([start, end], surface.clone(), core)
+            }
+            Self::Arc {
+                start,
+                center,
+                angle,
+                ..
+            } => {
+                // Not using `HalfEdge::arc` here, as that derives the
+                // boundary from the angles of both endpoints. Deriving it from
+                // the start angle and the swept angle instead keeps the
+                // direction of the arc, wherever it is on the circle.
+                let from_center = start - center;
+                let start_angle = from_center.v.atan2(from_center.u);
+
+                let path = Path::circle_from_center_and_radius(
+                    center,
+                    from_center.magnitude(),
+                );
+                let boundary = CurveBoundary {
+                    inner: [start_angle, start_angle + angle]
+                        .map(|coord| Point::from([coord])),
+                };
+
+                let half_edge = HalfEdge::unjoined(core).insert(core);
+                core.layers.geometry.define_curve(
+                    half_edge.curve().clone(),
+                    surface.clone(),
+                    LocalCurveGeom { path },
+                );
+
+                (half_edge, boundary)
+            }
+        }
+    }
+}
+
+struct Loop {
+    segments: Vec<Segment>,
+}
+
+impl Loop {
+    fn reverse(self) -> Self {
+        let segments = self
+            .segments
+            .into_iter()
+            .rev()
+            .map(Segment::reverse)
+            .collect();
+        Self { segments }
+    }
+
+    fn polygon(&self) -> Vec<Point<2>> {
+        let mut points = Vec::new();
+        for segment in &self.segments {
+            segment.sample(&mut points);
+        }
+        points
+    }
+
+    fn build_cycle(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Handle<Cycle> {
+        let half_edges = self
+            .segments
+            .iter()
+            .map(|segment| segment.build_half_edge(surface, core))
+            .collect::<Vec<_>>();
+
+        Cycle::from_half_edges_and_boundaries(half_edges, core).insert(core)
+    }
+}
+
+fn chain_segments(mut segments: Vec<Segment>, layer: &str) -> Vec<Loop> {
+    let mut loops = Vec::new();
+
+    while !segments.is_empty() {
+        let mut chain = vec![segments.remove(0)];
+
+        loop {
+            let first = chain[0].start();
+            let last = chain[chain.len() - 1].end();
+
+            if coincident(first, last) {
+                let closing = chain.pop().expect("Chain is not empty");
+                chain.push(closing.with_end(first));
+
+                loops.push(Loop { segments: chain });
+                break;
+            }
+
+            let next = segments.iter().position(|segment| {
+                coincident(segment.start(), last)
+                    || coincident(segment.end(), last)
+            });
+            let Some(next) = next else {
+                tracing::warn!(
+                    layer,
+                    "Skipping open chain of {} `LINE`/`ARC` entities in DXF \
+                    import",
+                    chain.len(),
+                );
+                break;
+            };
+
+            let mut segment = segments.remove(next);
+            if !coincident(segment.start(), last) {
+                segment = segment.reverse();
+            }
+            chain.push(segment.with_start(last));
+        }
+    }
+
+    loops
+}
+
+fn build_sketch(loops: Vec<Loop>, core: &mut Core) -> Sketch {
+    let polygons = loops.iter().map(Loop::polygon).collect::<Vec<_>>();
+    let areas = polygons
+        .iter()
+        .map(|polygon| signed_area(polygon))
+        .collect::<Vec<_>>();
+
+    // For each loop, the loops it lies within.
+    let containers = (0..loops.len())
+        .map(|i| {
+            (0..loops.len())
+                .filter(|&j| {
+                    j != i
+                        && areas[j].abs() > areas[i].abs()
+                        && contains(&polygons[j], polygons[i][0])
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Loops nested at even depth are exteriors. Loops at odd depth are holes
+    // in the region of the exterior they are directly nested in.
+    let mut loops = loops.into_iter().map(Some).collect::<Vec<_>>();
+    let surface = core.layers.topology.surfaces.space_2d();
+    let mut regions = Vec::new();
+
+    for i in 0..loops.len() {
+        let depth = containers[i].len();
+        if depth % 2 != 0 {
+            continue;
+        }
+
+        let exterior =
+            orient(loops[i].take(), areas[i], true).build_cycle(&surface, core);
+        let interiors = (0..loops.len())
+            .filter(|&j| {
+                containers[j].len() == depth + 1 && containers[j].contains(&i)
+            })
+            .map(|j| {
+                orient(loops[j].take(), areas[j], false)
+                    .build_cycle(&surface, core)
+            })
+            .collect::<Vec<_>>();
+
+        regions.push(Region::new(exterior, interiors).insert(core));
+    }
+
+    Sketch::new(surface, regions)
+}
+
+fn orient(loop_: Option<Loop>, signed_area: Scalar, ccw: bool) -> Loop {
+    let loop_ = loop_.expect("Each loop is only used once");
+
+    if signed_area.is_positive() == ccw {
+        loop_
+    } else {
+        loop_.reverse()
+    }
+}
+
+fn point_on_circle(center: Point<2>, radius: f64, angle: f64) -> Point<2> {
+    let (sin, cos) = angle.sin_cos();
+    center + Vector::from([radius * cos, radius * sin])
+}
+
+fn coincident(a: Point<2>, b: Point<2>) -> bool {
+    a.distance_to(&b) < Scalar::from(CONNECTION_TOLERANCE)
+}
+
+/// Test whether `point` lies within `polygon`, using the even-odd rule
+fn contains(polygon: &[Point<2>], point: Point<2>) -> bool {
+    Polygon::from_points(polygon.iter().copied())
+        .contains(point, FillRule::EvenOdd)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{validate::Validate, Core};
+
+    use super::import_dxf;
+
+    #[test]
+    fn rectangle_with_circular_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let dxf = [
+            "0",
+            "SECTION",
+            "2",
+            "ENTITIES", //
+            "0",
+            "LWPOLYLINE",
+            "8",
+            "0",
+            "90",
+            "4",
+            "70",
+            "1", //
+            "10",
+            "0.0",
+            "20",
+            "0.0", //
+            "10",
+            "4.0",
+            "20",
+            "0.0", //
+            "10",
+            "4.0",
+            "20",
+            "3.0", //
+            "10",
+            "0.0",
+            "20",
+            "3.0", //
+            "0",
+            "CIRCLE",
+            "8",
+            "0", //
+            "10",
+            "2.0",
+            "20",
+            "1.5",
+            "40",
+            "1.0", //
+            "0",
+            "ENDSEC",
+            "0",
+            "EOF",
+        ]
+        .join("\n");
+
+        let sketches = import_dxf(dxf.as_bytes(), &mut core)?;
+        assert_eq!(sketches.len(), 1);
+
+        let sketch = &sketches[0];
+        assert_eq!(sketch.regions().len(), 1);
+
+        let region = sketch.regions().first();
+        assert_eq!(region.exterior().half_edges().len(), 4);
+        assert_eq!(region.interiors().len(), 1);
+
+        sketch.validate_and_return_first_error(&core.layers.geometry)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+//! # Fornjot Importer
+//!
+//! [Fornjot] is an early-stage b-rep CAD kernel written in Rust. The kernel is
+//! split into multiple libraries that can be used semi-independently, and this
+//! is one of those.
+//!
+//! This library imports sketches and solids from external file formats.
+//!
+//! [Fornjot]: https://www.fornjot.app/
+
+mod dxf;
+mod obj;
+
+pub use self::{dxf::import_dxf, obj::import_obj};
+
+use fj_core::operations::build::BuildError;
+use thiserror::Error;
+
+/// An error that can occur while importing
+#[derive(Debug, Error)]
+pub enum Error {
+    /// I/O error while reading the input
+    #[error("I/O error while importing")]
+    Io(#[from] std::io::Error),
+
+    /// Group code in DXF input is not an integer
+    #[error("invalid DXF group code `{code}` on line {line}")]
+    InvalidGroupCode {
+        /// The text that could not be parsed as a group code
+        code: String,
+
+        /// The line the group code was found on
+        line: usize,
+    },
+
+    /// Value in DXF input could not be parsed as a number
+    #[error("invalid DXF value `{value}` for group code {code}")]
+    InvalidValue {
+        /// The group code the value belongs to
+        code: i32,
+
+        /// The text that could not be parsed
+        value: String,
+    },
+
+    /// An entity in the DXF input is missing a required value
+    #[error("DXF entity `{entity}` is missing group code {code}")]
+    MissingValue {
+        /// The type of the entity
+        entity: String,
+
+        /// The group code that is missing
+        code: i32,
+    },
+
+    /// Statement in OBJ input could not be parsed
+    #[error("invalid OBJ statement `{statement}` on line {line}")]
+    InvalidStatement {
+        /// The statement that could not be parsed
+        statement: String,
+
+        /// The line the statement was found on
+        line: usize,
+    },
+
+    /// Face in OBJ input refers to a vertex that doesn't exist
+    #[error("OBJ face on line {line} refers to non-existent vertex {index}")]
+    InvalidVertexIndex {
+        /// The index of the vertex, as it appears in the input
+        index: i64,
+
+        /// The line the face was found on
+        line: usize,
+    },
+
+    /// Face in OBJ input could not be built
+    ///
+    /// This includes faces that share an edge with the same orientation as
+    /// another face, or with two other faces, which makes the mesh
+    /// non-manifold.
+    #[error("invalid OBJ face on line {line}")]
+    InvalidFace {
+        /// The line the face was found on
+        line: usize,
+
+        /// The error that occurred while building the face
+        source: BuildError,
+    },
+
+    /// Object in OBJ input is not a closed, manifold mesh
+    #[error("OBJ object `{object}` is not a closed, manifold mesh")]
+    NonManifold {
+        /// The name of the object
+        ///
+        /// Empty, if the faces come before any named object.
+        object: String,
+    },
+}
@@ -0,0 +1,349 @@
+//! Import of OBJ files
+//!
+//! Only vertices (`v`), faces (`f`), and object names (`o`) are considered.
+//! Everything else in the file, including texture coordinates, normals, and
+//! materials, is ignored.
+
+use std::io::Read;
+
+use fj_core::{
+    operations::{
+        build::{BuildError, BuildSolid, ShellBuilder},
+        insert::Insert,
+        update::UpdateSolid,
+    },
+    queries::BoundaryEdgesOfShell,
+    topology::Solid,
+    Core,
+};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::Error;
+
+/// Import faceted solids from the OBJ file provided by `reader`
+///
+/// Each object (started by an `o` statement) becomes one solid. Faces that
+/// come before the first `o` statement belong to an unnamed object. Objects
+/// without faces are skipped.
+///
+/// Vertices that are shared between faces, or that coincide within the
+/// tolerance of `core`, are welded, so adjacent faces share their vertices and
+/// edges. Each planar polygon becomes one face. Polygons that are not planar
+/// are triangulated by ear clipping, which also works for concave polygons.
+///
+/// Returns an error, if the faces of an object don't form a closed, manifold
+/// mesh.
+pub fn import_obj(
+    mut reader: impl Read,
+    core: &mut Core,
+) -> Result<Vec<Solid>, Error> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let mut vertices = Vec::new();
+    let mut objects = vec![Object::new(String::new(), core)];
+
+    for (index, statement) in input.lines().enumerate() {
+        let line = index + 1;
+
+        let statement = match statement.split_once('#') {
+            Some((statement, _comment)) => statement,
+            None => statement,
+        };
+        let mut tokens = statement.split_whitespace();
+
+        let invalid = || Error::InvalidStatement {
+            statement: statement.trim().to_string(),
+            line,
+        };
+
+        match tokens.next() {
+            Some("v") => {
+                let coords = tokens
+                    .take(3)
+                    .map(|token| token.parse::<f64>().ok())
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|coords| coords.iter().all(|c| c.is_finite()));
+                let Some(&[x, y, z]) = coords.as_deref() else {
+                    return Err(invalid());
+                };
+
+                vertices.push(Point::from([x, y, z]));
+            }
+            Some("f") => {
+                let points = tokens
+                    .map(|token| {
+                        // A face vertex can also refer to texture coordinates
+                        // and normals, separated by `/`. Only the first index
+                        // is of interest here.
+                        let index = token
+                            .split('/')
+                            .next()
+                            .and_then(|index| index.parse::<i64>().ok())
+                            .ok_or_else(invalid)?;
+
+                        vertex(&vertices, index)
+                            .ok_or(Error::InvalidVertexIndex { index, line })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let object = objects.last_mut().expect("Created above");
+                object.add_polygon(&points, line, core)?;
+            }
+            Some("o") => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+                objects.push(Object::new(name, core));
+            }
+            _ => {}
+        }
+    }
+
+    objects
+        .into_iter()
+        .filter(|object| object.num_faces > 0)
+        .map(|object| object.build(core))
+        .collect()
+}
+
+/// Resolve an OBJ vertex index
+///
+/// Positive indices start at `1`. Negative indices are relative to the end of
+/// the vertices that have been defined so far.
+fn vertex(vertices: &[Point<3>], index: i64) -> Option<Point<3>> {
+    let index = if index < 0 {
+        vertices.len().checked_sub(index.unsigned_abs() as usize)?
+    } else {
+        usize::try_from(index).ok()?.checked_sub(1)?
+    };
+
+    vertices.get(index).copied()
+}
+
+struct Object {
+    name: String,
+    builder: ShellBuilder,
+    num_faces: usize,
+}
+
+impl Object {
+    fn new(name: String, core: &mut Core) -> Self {
+        Self {
+            name,
+            builder: ShellBuilder::new(core.tolerance()),
+            num_faces: 0,
+        }
+    }
+
+    fn add_polygon(
+        &mut self,
+        points: &[Point<3>],
+        line: usize,
+        core: &mut Core,
+    ) -> Result<(), Error> {
+        let invalid_face = |source| Error::InvalidFace { line, source };
+
+        match self.builder.add_face(points.iter().copied(), core) {
+            Ok(_) => {
+                self.num_faces += 1;
+            }
+            Err(err @ BuildError::NonPlanarFace { .. }) => {
+                let Some(triangles) = ear_clip(points) else {
+                    return Err(invalid_face(err));
+                };
+
+                // Adding the triangles can fail halfway through. Restore the
+                // previous state in that case, so the polygon is added
+                // completely or not at all.
+                let previous = self.builder.clone();
+                for triangle in &triangles {
+                    if let Err(err) = self.builder.add_face(*triangle, core) {
+                        self.builder = previous;
+                        return Err(invalid_face(err));
+                    }
+                }
+                self.num_faces += triangles.len();
+            }
+            Err(err) => return Err(invalid_face(err)),
+        }
+
+        Ok(())
+    }
+
+    fn build(self, core: &mut Core) -> Result<Solid, Error> {
+        let shell = self.builder.build(core);
+        if !shell.is_closed() {
+            return Err(Error::NonManifold { object: self.name });
+        }
+
+        let shell = shell.insert(core);
+        Ok(Solid::empty().add_shells([shell], core))
+    }
+}
+
+/// Triangulate a polygon that is not necessarily planar, by ear clipping
+///
+/// The polygon is projected into the plane that is defined by its normal, as
+/// computed by Newell's method. The triangles keep the orientation of the
+/// polygon.
+///
+/// Returns `None`, if the polygon is degenerate.
+fn ear_clip(points: &[Point<3>]) -> Option<Vec<[Point<3>; 3]>> {
+    let [origin, ..] = points else {
+        return None;
+    };
+    let origin = *origin;
+
+    let normal = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(Vector::from([0., 0., 0.]), |normal, (a, b)| {
+            normal + (a - origin).cross(&(b - origin))
+        });
+    if points.len() < 3 || normal.magnitude().is_zero() {
+        return None;
+    }
+    let normal = normal.normalize();
+
+    // Any direction that isn't parallel to the normal works to derive the
+    // plane's coordinate system. `u`, `v`, and the normal are right-handed, so
+    // the projected polygon is counter-clockwise.
+    let helper = if normal.dot(&Vector::unit_x()).abs() < Scalar::from(0.9) {
+        Vector::unit_x()
+    } else {
+        Vector::unit_y()
+    };
+    let u = helper.cross(&normal).normalize();
+    let v = normal.cross(&u);
+
+    let projected = points
+        .iter()
+        .map(|point| {
+            let offset = point - origin;
+            Point::from([offset.dot(&u), offset.dot(&v)])
+        })
+        .collect::<Vec<_>>();
+
+    let mut remaining = (0..points.len()).collect::<Vec<_>>();
+    let mut triangles = Vec::new();
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let corners = |i: usize| {
+            [(i + len - 1) % len, i, (i + 1) % len].map(|j| remaining[j])
+        };
+
+        let ear = (0..len).find(|&i| {
+            let corners = corners(i);
+            let [a, b, c] = corners.map(|j| projected[j]);
+
+            let is_convex = (b - a).cross2d(&(c - b)) > Scalar::ZERO;
+            let is_empty =
+                remaining.iter().filter(|j| !corners.contains(j)).all(|&j| {
+                    let p = projected[j];
+                    let is_inside = [(a, b), (b, c), (c, a)].into_iter().all(
+                        |(from, to)| {
+                            (to - from).cross2d(&(p - from)) >= Scalar::ZERO
+                        },
+                    );
+                    !is_inside
+                });
+
+            is_convex && is_empty
+        })?;
+
+        triangles.push(corners(ear).map(|j| points[j]));
+        remaining.remove(ear);
+    }
+
+    triangles
+        .push([remaining[0], remaining[1], remaining[2]].map(|j| points[j]));
+
+    Some(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{validate::Validate, Core};
+    use fj_math::{Point, Scalar};
+
+    use crate::Error;
+
+    use super::{ear_clip, import_obj};
+
+    #[test]
+    fn cube() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solids = import_obj(cube_obj(6).as_bytes(), &mut core)?;
+        assert_eq!(solids.len(), 1);
+
+        let solid = &solids[0];
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        solid.validate_and_return_first_error(&core.layers.geometry)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn cube_with_missing_face() {
+        let mut core = Core::new();
+
+        let result = import_obj(cube_obj(5).as_bytes(), &mut core);
+        assert!(matches!(
+            result,
+            Err(Error::NonManifold { object }) if object == "cube"
+        ));
+    }
+
+    #[test]
+    fn ear_clip_concave_non_planar_polygon() {
+        // A fan from the first point would create overlapping triangles, as the
+        // fourth point is not visible from there.
+        let points = [
+            [0., 0., 0.],
+            [4., 0., 0.],
+            [4., 4., 0.],
+            [2., 1., 0.5],
+            [0., 4., 0.],
+        ]
+        .map(Point::from);
+
+        let triangles = ear_clip(&points).expect("Polygon is not degenerate");
+        assert_eq!(triangles.len(), 3);
+
+        let mut area = Scalar::ZERO;
+        for triangle in triangles {
+            let [a, b, c] = triangle.map(|point| point.coords.xy());
+
+            // All triangles are counter-clockwise, when viewed from above.
+            let double_area = (b - a).cross2d(&(c - a));
+            assert!(double_area > Scalar::ZERO);
+
+            area += double_area / 2.;
+        }
+        assert_eq!(area, Scalar::from(10.));
+    }
+
+    fn cube_obj(num_faces: usize) -> String {
+        let vertices = [
+            "v 0 0 0", "v 1 0 0", "v 1 1 0", "v 0 1 0", //
+            "v 0 0 1", "v 1 0 1", "v 1 1 1", "v 0 1 1",
+        ];
+        let faces = [
+            "f 1 4 3 2",
+            "f 5 6 7 8",
+            "f 1 2 6 5",
+            "f 2/1 3/2 7/3 6/4",
+            "f 3//1 4//2 8//3 7//4",
+            "f -8 -4 -1 -5",
+        ];
+
+        ["# A unit cube", "o cube"]
+            .into_iter()
+            .chain(vertices)
+            .chain(faces.into_iter().take(num_faces))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -2,6 +2,66 @@
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Color(pub [u8; 4]);
 
+impl Color {
+    /// Black
+    pub const BLACK: Self = Self([0, 0, 0, 255]);
+
+    /// White
+    pub const WHITE: Self = Self([255, 255, 255, 255]);
+
+    /// Red
+    pub const RED: Self = Self([255, 0, 0, 255]);
+
+    /// Green
+    pub const GREEN: Self = Self([0, 255, 0, 255]);
+
+    /// Blue
+    pub const BLUE: Self = Self([0, 0, 255, 255]);
+
+    /// Yellow
+    pub const YELLOW: Self = Self([255, 255, 0, 255]);
+
+    /// Cyan
+    pub const CYAN: Self = Self([0, 255, 255, 255]);
+
+    /// Magenta
+    pub const MAGENTA: Self = Self([255, 0, 255, 255]);
+
+    /// Parse a color from a hex string
+    ///
+    /// Accepts `#rrggbb` and `#rrggbbaa`, with the leading `#` being optional.
+    /// If no alpha value is provided, the color is fully opaque.
+    pub fn from_hex(hex: &str) -> Result<Self, InvalidHexColor> {
+        let invalid = || InvalidHexColor(hex.to_string());
+
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.is_ascii() || ![6, 8].contains(&digits.len()) {
+            return Err(invalid());
+        }
+
+        let mut rgba = [255; 4];
+        for (channel, i) in rgba.iter_mut().zip((0..digits.len()).step_by(2)) {
+            *channel = u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|_| invalid())?;
+        }
+
+        Ok(Self(rgba))
+    }
+
+    /// Convert the color to a hex string
+    ///
+    /// Returns `#rrggbb` for fully opaque colors, `#rrggbbaa` otherwise.
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.0;
+
+        if a == 255 {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+}
+
 impl Default for Color {
     fn default() -> Self {
         // The default color is red. This is an arbitrary choice.
@@ -38,3 +98,41 @@ impl From<[f64; 3]> for Color {
         Self::from([r, g, b, 1.])
     }
 }
+
+/// Error parsing a [`Color`] from a hex string
+///
+/// Returned by [`Color::from_hex`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Invalid hex color: `{0}`")]
+pub struct InvalidHexColor(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, InvalidHexColor};
+
+    #[test]
+    fn from_hex_rgb() {
+        let color = Color::from_hex("#ff8800");
+
+        assert_eq!(color, Ok(Color([255, 136, 0, 255])));
+        assert_eq!(color.map(|color| color.to_hex()), Ok("#ff8800".into()));
+    }
+
+    #[test]
+    fn from_hex_rgba() {
+        let color = Color::from_hex("#ff880080");
+
+        assert_eq!(color, Ok(Color([255, 136, 0, 128])));
+        assert_eq!(color.map(|color| color.to_hex()), Ok("#ff880080".into()));
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        for hex in ["#ff88zz", "#ff88", "#ff8800ff00", "#ff88é0"] {
+            assert_eq!(
+                Color::from_hex(hex),
+                Err(InvalidHexColor(hex.to_string())),
+            );
+        }
+    }
+}
@@ -0,0 +1,517 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, BinaryHeap},
+};
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{Color, MaterialId, Mesh};
+
+impl Mesh<Point<3>> {
+    /// Reduce the number of triangles in the mesh
+    ///
+    /// Repeatedly collapses the edge whose collapse introduces the least error,
+    /// as measured by a quadric error metric, until the mesh has no more than
+    /// `target_triangles` triangles, or no valid collapse remains.
+    ///
+    /// A collapse is not valid, if it would change the boundary of the mesh,
+    /// result in a non-manifold configuration, or flip any triangle. Each
+    /// triangle keeps its color and material.
+    pub fn decimate(&mut self, target_triangles: usize) {
+        let mut decimation = Decimation::new(self);
+
+        while decimation.num_triangles > target_triangles {
+            if !decimation.collapse_next() {
+                break;
+            }
+        }
+
+        *self = decimation.into_mesh();
+    }
+}
+
+struct Decimation {
+    positions: Vec<Point<3>>,
+    quadrics: Vec<Quadric>,
+    versions: Vec<u64>,
+    is_boundary: Vec<bool>,
+
+    triangles: Vec<Option<DecimationTriangle>>,
+    triangles_by_vertex: Vec<BTreeSet<usize>>,
+    num_triangles: usize,
+
+    candidates: BinaryHeap<Candidate>,
+}
+
+impl Decimation {
+    fn new(mesh: &Mesh<Point<3>>) -> Self {
+        let mut positions = Vec::new();
+        let mut indices = BTreeMap::new();
+        let mut triangles = Vec::new();
+
+        for triangle in mesh.triangles() {
+            let vertices = triangle.inner.points.map(|point| {
+                *indices.entry(point).or_insert_with(|| {
+                    positions.push(point);
+                    positions.len() - 1
+                })
+            });
+            triangles.push(Some((vertices, triangle.color, triangle.material)));
+        }
+
+        let mut quadrics = vec![Quadric::default(); positions.len()];
+        let mut triangles_by_vertex = vec![BTreeSet::new(); positions.len()];
+        let mut triangles_by_edge = BTreeMap::<_, usize>::new();
+
+        for (index, (vertices, _, _)) in triangles.iter().flatten().enumerate()
+        {
+            let quadric = Quadric::of_triangle(vertices.map(|i| positions[i]));
+
+            for (i, &vertex) in vertices.iter().enumerate() {
+                quadrics[vertex] = quadrics[vertex] + quadric;
+                triangles_by_vertex[vertex].insert(index);
+
+                let next = vertices[(i + 1) % 3];
+                *triangles_by_edge.entry(edge(vertex, next)).or_default() += 1;
+            }
+        }
+
+        let mut is_boundary = vec![false; positions.len()];
+        for ([a, b], num_triangles) in triangles_by_edge {
+            if num_triangles != 2 {
+                is_boundary[a] = true;
+                is_boundary[b] = true;
+            }
+        }
+
+        let mut self_ = Self {
+            versions: vec![0; positions.len()],
+            positions,
+            quadrics,
+            is_boundary,
+            num_triangles: triangles.len(),
+            triangles,
+            triangles_by_vertex,
+            candidates: BinaryHeap::new(),
+        };
+
+        for vertex in 0..self_.positions.len() {
+            for neighbor in self_.neighbors(vertex) {
+                if vertex < neighbor {
+                    self_.add_candidate(vertex, neighbor);
+                }
+            }
+        }
+
+        self_
+    }
+
+    /// Collapse the edge with the lowest cost
+    ///
+    /// Returns `false`, if no valid collapse remains.
+    fn collapse_next(&mut self) -> bool {
+        while let Some(candidate) = self.candidates.pop() {
+            let [a, b] = candidate.vertices;
+            if candidate.versions != [self.versions[a], self.versions[b]] {
+                // One of the vertices has changed since this candidate was
+                // added. There's an up-to-date candidate for this edge, if it
+                // still exists.
+                continue;
+            }
+            if !self.can_collapse(a, b, candidate.position) {
+                continue;
+            }
+
+            self.collapse(a, b, candidate.position);
+            return true;
+        }
+
+        false
+    }
+
+    fn add_candidate(&mut self, a: usize, b: usize) {
+        if self.is_boundary[a] || self.is_boundary[b] {
+            return;
+        }
+
+        let quadric = self.quadrics[a] + self.quadrics[b];
+        let [pa, pb] = [a, b].map(|vertex| self.positions[vertex]);
+
+        let position = quadric.minimum().unwrap_or_else(|| {
+            [pa, pb, pa + (pb - pa) / 2.]
+                .into_iter()
+                .min_by(|x, y| quadric.error(*x).total_cmp(&quadric.error(*y)))
+                .expect("Array is not empty")
+        });
+
+        self.candidates.push(Candidate {
+            cost: quadric.error(position),
+            vertices: [a, b],
+            versions: [self.versions[a], self.versions[b]],
+            position,
+        });
+    }
+
+    fn can_collapse(&self, a: usize, b: usize, position: Point<3>) -> bool {
+        // Collapsing an edge removes two triangles. Closed meshes with fewer
+        // than four triangles don't exist.
+        if self.num_triangles < 6 {
+            return false;
+        }
+
+        // The link condition: the only vertices that are neighbors of both
+        // `a` and `b` must be the ones opposite the edge. Otherwise the
+        // collapse would result in a non-manifold configuration.
+        let shared = self.shared_triangles(a, b);
+        let opposite = shared
+            .iter()
+            .filter_map(|&triangle| {
+                let (vertices, _, _) = self.triangles[triangle]?;
+                vertices.into_iter().find(|&v| v != a && v != b)
+            })
+            .collect::<BTreeSet<_>>();
+        let common = self
+            .neighbors(a)
+            .intersection(&self.neighbors(b))
+            .copied()
+            .collect::<BTreeSet<_>>();
+        if shared.len() != 2 || common != opposite {
+            return false;
+        }
+
+        // Make sure none of the remaining triangles flips.
+        for vertex in [a, b] {
+            for &triangle in &self.triangles_by_vertex[vertex] {
+                if shared.contains(&triangle) {
+                    continue;
+                }
+                let Some((vertices, _, _)) = self.triangles[triangle] else {
+                    continue;
+                };
+
+                let before = vertices.map(|v| self.positions[v]);
+                let after = vertices.map(|v| {
+                    if v == vertex {
+                        position
+                    } else {
+                        self.positions[v]
+                    }
+                });
+
+                let [before, after] = [before, after].map(normal);
+                if after.magnitude() == Scalar::ZERO
+                    || before.dot(&after) <= Scalar::ZERO
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Collapse `b` into `a`, moving `a` to the provided position
+    fn collapse(&mut self, a: usize, b: usize, position: Point<3>) {
+        let triangles_of_b = std::mem::take(&mut self.triangles_by_vertex[b]);
+
+        for triangle in triangles_of_b {
+            let Some((vertices, color, material)) = self.triangles[triangle]
+            else {
+                continue;
+            };
+
+            if vertices.contains(&a) {
+                self.triangles[triangle] = None;
+                self.num_triangles -= 1;
+
+                for vertex in vertices {
+                    self.triangles_by_vertex[vertex].remove(&triangle);
+                }
+            } else {
+                let vertices = vertices.map(|v| if v == b { a } else { v });
+                self.triangles[triangle] = Some((vertices, color, material));
+                self.triangles_by_vertex[a].insert(triangle);
+            }
+        }
+
+        self.positions[a] = position;
+        self.quadrics[a] = self.quadrics[a] + self.quadrics[b];
+        self.versions[a] += 1;
+        self.versions[b] += 1;
+
+        for neighbor in self.neighbors(a) {
+            self.add_candidate(a, neighbor);
+        }
+    }
+
+    fn neighbors(&self, vertex: usize) -> BTreeSet<usize> {
+        self.triangles_by_vertex[vertex]
+            .iter()
+            .filter_map(|&triangle| self.triangles[triangle])
+            .flat_map(|(vertices, _, _)| vertices)
+            .filter(|&v| v != vertex)
+            .collect()
+    }
+
+    fn shared_triangles(&self, a: usize, b: usize) -> BTreeSet<usize> {
+        self.triangles_by_vertex[a]
+            .intersection(&self.triangles_by_vertex[b])
+            .copied()
+            .collect()
+    }
+
+    fn into_mesh(self) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+
+        for (vertices, color, material) in self.triangles.into_iter().flatten()
+        {
+            let points = vertices.map(|v| self.positions[v]);
+            mesh.push_triangle_with_material(points, color, material);
+        }
+
+        mesh
+    }
+}
+
+/// The vertices of a triangle, along with the attributes it keeps
+type DecimationTriangle = ([usize; 3], Color, Option<MaterialId>);
+
+fn edge(a: usize, b: usize) -> [usize; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+fn normal([a, b, c]: [Point<3>; 3]) -> Vector<3> {
+    (b - a).cross(&(c - a))
+}
+
+/// An edge that can be collapsed
+struct Candidate {
+    cost: f64,
+    vertices: [usize; 2],
+    versions: [u64; 2],
+    position: Point<3>,
+}
+
+impl Eq for Candidate {}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, but we want the lowest cost first.
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.vertices.cmp(&self.vertices))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The sum of squared distances to a set of planes
+///
+/// Stores the symmetric matrix `[[a, b], [b^T, c]]`, so that the error at
+/// point `p` is `p^T a p + 2 b·p + c`.
+#[derive(Clone, Copy, Default)]
+struct Quadric {
+    a: [[f64; 3]; 3],
+    b: [f64; 3],
+    c: f64,
+}
+
+impl Quadric {
+    fn of_triangle(points: [Point<3>; 3]) -> Self {
+        let normal = normal(points);
+        if normal.magnitude() == Scalar::ZERO {
+            return Self::default();
+        }
+
+        let n = normal.normalize().components.map(Scalar::into_f64);
+        let d = -n
+            .iter()
+            .zip(points[0].coords.components)
+            .map(|(n, p)| n * p.into_f64())
+            .sum::<f64>();
+
+        let mut a = [[0.; 3]; 3];
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = n[i] * n[j];
+            }
+        }
+
+        Self {
+            a,
+            b: n.map(|n| n * d),
+            c: d * d,
+        }
+    }
+
+    fn error(&self, point: Point<3>) -> f64 {
+        let p = point.coords.components.map(Scalar::into_f64);
+
+        let mut error = self.c;
+        for i in 0..3 {
+            for j in 0..3 {
+                error += p[i] * self.a[i][j] * p[j];
+            }
+            error += 2. * self.b[i] * p[i];
+        }
+
+        error
+    }
+
+    /// Find the point where the error is minimal, if it is unique
+    fn minimum(&self) -> Option<Point<3>> {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.a;
+
+        let cofactors = [
+            [e * i - f * h, c * h - b * i, b * f - c * e],
+            [f * g - d * i, a * i - c * g, c * d - a * f],
+            [d * h - e * g, b * g - a * h, a * e - b * d],
+        ];
+        let determinant =
+            a * cofactors[0][0] + b * cofactors[1][0] + c * cofactors[2][0];
+
+        if determinant.abs() < 1e-12 {
+            return None;
+        }
+
+        let point = cofactors.map(|row| {
+            -row.iter().zip(self.b).map(|(x, b)| x * b).sum::<f64>()
+                / determinant
+        });
+        Some(Point::from(point))
+    }
+}
+
+impl std::ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (row, other) in self.a.iter_mut().zip(other.a) {
+            for (value, other) in row.iter_mut().zip(other) {
+                *value += other;
+            }
+        }
+        for (value, other) in self.b.iter_mut().zip(other.b) {
+            *value += other;
+        }
+        self.c += other.c;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use fj_math::{Point, Vector};
+
+    use crate::{Color, Mesh};
+
+    #[test]
+    fn decimate_sphere() {
+        let mut mesh = sphere(3);
+        let num_triangles = mesh.triangles().count();
+
+        mesh.decimate(num_triangles / 2);
+
+        assert_eq!(mesh.triangles().count(), num_triangles / 2);
+        assert!(is_closed(&mesh));
+    }
+
+    /// Build a sphere, by subdividing an icosahedron
+    fn sphere(subdivisions: usize) -> Mesh<Point<3>> {
+        let t = (1. + 5_f64.sqrt()) / 2.;
+        let vertices = [
+            [-1., t, 0.],
+            [1., t, 0.],
+            [-1., -t, 0.],
+            [1., -t, 0.],
+            [0., -1., t],
+            [0., 1., t],
+            [0., -1., -t],
+            [0., 1., -t],
+            [t, 0., -1.],
+            [t, 0., 1.],
+            [-t, 0., -1.],
+            [-t, 0., 1.],
+        ]
+        .map(|v| Vector::from(v).normalize());
+
+        let mut triangles = [
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ]
+        .map(|triangle| triangle.map(|i| vertices[i]))
+        .to_vec();
+
+        for _ in 0..subdivisions {
+            triangles = triangles
+                .into_iter()
+                .flat_map(|[a, b, c]| {
+                    let [ab, bc, ca] = [(a, b), (b, c), (c, a)]
+                        .map(|(x, y)| ((x + y) / 2.).normalize());
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+
+        let mut mesh = Mesh::new();
+        for triangle in triangles {
+            mesh.push_triangle(
+                triangle.map(|v| Point { coords: v }),
+                Color::default(),
+            );
+        }
+
+        mesh
+    }
+
+    /// Check that every edge is shared by exactly two triangles
+    fn is_closed(mesh: &Mesh<Point<3>>) -> bool {
+        let mut edges = BTreeMap::<_, usize>::new();
+
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points;
+            for (x, y) in [(a, b), (b, c), (c, a)] {
+                let edge = if x < y { [x, y] } else { [y, x] };
+                *edges.entry(edge).or_default() += 1;
+            }
+        }
+
+        edges.values().all(|&count| count == 2)
+    }
+}
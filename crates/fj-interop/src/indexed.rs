@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Vector};
+
+use crate::{Color, Index, Mesh};
+
+impl Mesh<Point<3>> {
+    /// Convert the mesh into an indexed triangle list
+    ///
+    /// Triangles that share a position also share the vertex at that position.
+    /// The normal of each vertex is the area-weighted average of the normals of
+    /// the triangles that share it. Its color is the color of the first
+    /// triangle that uses it.
+    pub fn to_indexed(&self) -> IndexedMesh {
+        let mut mesh = IndexedMesh::default();
+
+        let mut indices_by_position = BTreeMap::new();
+        let mut normals = Vec::<Vector<3>>::new();
+
+        for triangle in self.triangles() {
+            let [a, b, c] = triangle.inner.points;
+
+            // Not normalized, so larger triangles contribute more to the
+            // normals of their vertices.
+            let normal = (b - a).cross(&(c - a));
+
+            for point in triangle.inner.points {
+                let index =
+                    *indices_by_position.entry(point).or_insert_with(|| {
+                        mesh.positions.push(point.into());
+                        mesh.colors.push(color_to_f32(triangle.color));
+                        normals.push(Vector::from([0., 0., 0.]));
+
+                        (mesh.positions.len() - 1) as Index
+                    });
+
+                normals[index as usize] = normals[index as usize] + normal;
+                mesh.indices.push(index);
+            }
+        }
+
+        mesh.normals = normals
+            .into_iter()
+            .map(|normal| {
+                if normal.magnitude().is_zero() {
+                    normal.into()
+                } else {
+                    normal.normalize().into()
+                }
+            })
+            .collect();
+
+        mesh
+    }
+}
+
+/// A triangle mesh, as indexed vertex buffers
+///
+/// Every vertex consists of a position, a normal, and a color, each stored at
+/// the same index in their respective buffer. Every three indices form a
+/// triangle.
+///
+/// All data is stored in formats that can be uploaded to a GPU directly.
+///
+/// See [`Mesh::to_indexed`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IndexedMesh {
+    /// The position of each vertex
+    pub positions: Vec<[f32; 3]>,
+
+    /// The normal of each vertex
+    pub normals: Vec<[f32; 3]>,
+
+    /// The color of each vertex, as RGBA values in the range `0.0..=1.0`
+    pub colors: Vec<[f32; 4]>,
+
+    /// The indices into the vertex buffers
+    pub indices: Vec<Index>,
+}
+
+fn color_to_f32(color: Color) -> [f32; 4] {
+    color.0.map(|v| f32::from(v) / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    #[test]
+    fn cube() {
+        let mesh = test_utils::cube();
+
+        let indexed = mesh.to_indexed();
+
+        assert_eq!(indexed.positions.len(), 8);
+        assert_eq!(indexed.normals.len(), 8);
+        assert_eq!(indexed.colors.len(), 8);
+        assert_eq!(indexed.indices.len(), 36);
+
+        // Every vertex normal of a cube points away from its center.
+        for (position, normal) in indexed.positions.iter().zip(&indexed.normals)
+        {
+            let outward = position.map(|coord| coord - 0.5);
+            let dot: f32 = outward.iter().zip(normal).map(|(a, b)| a * b).sum();
+            assert!(dot > 0.);
+        }
+    }
+}
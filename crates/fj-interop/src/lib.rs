@@ -10,13 +10,24 @@
 //! [Fornjot]: https://www.fornjot.app/
 
 mod color;
+mod decimate;
+mod indexed;
+mod material;
 mod mesh;
 mod model;
+#[cfg(feature = "parry")]
+mod parry;
+mod quality;
+#[cfg(test)]
+mod test_utils;
 
 pub mod ext;
 
 pub use self::{
-    color::Color,
+    color::{Color, InvalidHexColor},
+    indexed::IndexedMesh,
+    material::MaterialId,
     mesh::{Index, Mesh, Triangle},
     model::Model,
+    quality::QualityReport,
 };
@@ -0,0 +1,10 @@
+/// Identifies a material
+///
+/// Materials are assigned to faces, and end up in the triangles of a [`Mesh`].
+/// What the material actually is, is up to the application. Exporters that
+/// support materials export triangles with different material IDs as distinct
+/// materials.
+///
+/// [`Mesh`]: crate::Mesh
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct MaterialId(pub u32);
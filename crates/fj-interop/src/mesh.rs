@@ -1,8 +1,8 @@
 use std::{collections::HashMap, hash::Hash};
 
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
-use crate::Color;
+use crate::{Color, MaterialId};
 
 /// A triangle mesh
 #[derive(Clone, Debug)]
@@ -12,6 +12,9 @@ pub struct Mesh<V> {
 
     indices_by_vertex: HashMap<V, Index>,
     triangles: Vec<Triangle>,
+
+    // Either empty, or one color per vertex.
+    vertex_colors: Vec<Color>,
 }
 
 impl<V> Mesh<V>
@@ -24,11 +27,15 @@ where
     }
 
     /// Add a vertex to the mesh
+    ///
+    /// If the vertex is not already part of the mesh, this discards the vertex
+    /// colors. See [`Mesh::vertex_colors`].
     pub fn push_vertex(&mut self, vertex: V) {
         let index =
             *self.indices_by_vertex.entry(vertex).or_insert_with(|| {
                 let index = self.vertices.len();
                 self.vertices.push(vertex);
+                self.vertex_colors.clear();
                 index as u32
             });
 
@@ -69,6 +76,15 @@ where
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.triangles.iter().copied()
     }
+
+    /// Access the colors of the vertices, if they have been assigned
+    ///
+    /// If present, there is one color for each vertex, in the same order as
+    /// returned by [`Mesh::vertices`]. Returns `None`, if no colors have been
+    /// assigned, or if vertices have been added since.
+    pub fn vertex_colors(&self) -> Option<&[Color]> {
+        (!self.vertex_colors.is_empty()).then_some(&self.vertex_colors)
+    }
 }
 
 impl Mesh<Point<3>> {
@@ -77,6 +93,16 @@ impl Mesh<Point<3>> {
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
+    ) {
+        self.push_triangle_with_material(triangle, color, None);
+    }
+
+    /// Add a triangle with an optional material to the mesh
+    pub fn push_triangle_with_material(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        color: Color,
+        material: Option<MaterialId>,
     ) {
         let triangle = triangle.into();
 
@@ -87,8 +113,42 @@ impl Mesh<Point<3>> {
         self.triangles.push(Triangle {
             inner: triangle,
             color,
+            material,
         });
     }
+
+    /// Assign a color to each vertex, computed from its position
+    ///
+    /// This is intended for gradient shading, based on a field like the height
+    /// of each vertex. Exporters that support per-vertex colors can access
+    /// them via [`Mesh::vertex_colors`]. The colors of the triangles are not
+    /// affected.
+    pub fn color_by<F: Fn(Point<3>) -> Color>(&mut self, f: F) {
+        self.vertex_colors = self.vertices.iter().copied().map(f).collect();
+    }
+
+    /// Set coordinates that are within the provided tolerance of zero to zero
+    ///
+    /// Exporters write coordinates as they are. Apply this before exporting,
+    /// to remove floating-point noise from the output. See
+    /// [`Point::snap_zeros`].
+    pub fn snap_zeros(&self, tolerance: impl Into<Scalar>) -> Self {
+        let tolerance = tolerance.into();
+
+        let mut mesh = Self::new();
+        for triangle in self.triangles() {
+            mesh.push_triangle_with_material(
+                triangle
+                    .inner
+                    .points
+                    .map(|point| point.snap_zeros(tolerance)),
+                triangle.color,
+                triangle.material,
+            );
+        }
+
+        mesh
+    }
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -100,6 +160,7 @@ impl<V> Default for Mesh<V> {
             indices: Vec::default(),
             indices_by_vertex: HashMap::default(),
             triangles: Vec::default(),
+            vertex_colors: Vec::default(),
         }
     }
 }
@@ -109,7 +170,8 @@ pub type Index = u32;
 
 /// A triangle
 ///
-/// Extension of [`fj_math::Triangle`] that also includes a color.
+/// Extension of [`fj_math::Triangle`] that also includes a color and a
+/// material.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Triangle {
     /// The points of the triangle
@@ -117,4 +179,40 @@ pub struct Triangle {
 
     /// The color of the triangle
     pub color: Color,
+
+    /// The material of the triangle, if any
+    pub material: Option<MaterialId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{test_utils, Color};
+
+    #[test]
+    fn color_by_height() {
+        let mut mesh = test_utils::cube();
+        assert_eq!(mesh.vertex_colors(), None);
+
+        mesh.color_by(|point| {
+            let blue = (point.z.into_f64() * 255.) as u8;
+            Color([255 - blue, 0, blue, 255])
+        });
+
+        let colors = mesh.vertex_colors().unwrap();
+        assert_eq!(colors.len(), 8);
+        for (vertex, color) in mesh.vertices().zip(colors) {
+            let expected = if vertex.z == Scalar::ZERO {
+                Color::RED
+            } else {
+                Color::BLUE
+            };
+            assert_eq!(*color, expected);
+        }
+
+        // Adding a vertex discards the colors.
+        mesh.push_vertex(Point::from([2., 0., 0.]));
+        assert_eq!(mesh.vertex_colors(), None);
+    }
 }
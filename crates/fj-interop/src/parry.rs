@@ -0,0 +1,58 @@
+use fj_math::Point;
+use parry3d_f64::shape::{TriMesh, TriMeshFlags};
+
+use crate::Mesh;
+
+impl Mesh<Point<3>> {
+    /// Convert the mesh into a [Parry] triangle mesh
+    ///
+    /// The resulting triangle mesh shares its vertices between triangles, just
+    /// like this mesh does. It can be used for Parry's queries, like ray casts
+    /// or point projections.
+    ///
+    /// The mesh is assumed to be oriented, with the front sides of its
+    /// triangles pointing outward. This is the case for meshes created by
+    /// triangulating a valid solid. It allows Parry to determine, whether a
+    /// point is inside of the mesh.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the mesh has no triangles.
+    ///
+    /// Only available with the `parry` feature, which is enabled by default.
+    ///
+    /// [Parry]: https://www.parry.rs/
+    pub fn to_parry_trimesh(&self) -> TriMesh {
+        let vertices = self.vertices().map(Point::to_na).collect();
+        let indices = self
+            .indices()
+            .collect::<Vec<_>>()
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect();
+
+        TriMesh::with_flags(vertices, indices, TriMeshFlags::ORIENTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parry3d_f64::{math::Isometry, query::PointQuery};
+
+    use crate::test_utils;
+
+    #[test]
+    fn cube() {
+        let mesh = test_utils::cube();
+
+        let trimesh = mesh.to_parry_trimesh();
+        assert_eq!(trimesh.vertices().len(), 8);
+        assert_eq!(trimesh.indices().len(), 12);
+
+        let isometry = Isometry::identity();
+        assert!(trimesh.contains_point(&isometry, &[0.5, 0.5, 0.5].into()));
+        assert!(trimesh.contains_point(&isometry, &[0.9, 0.1, 0.2].into()));
+        assert!(!trimesh.contains_point(&isometry, &[1.5, 0.5, 0.5].into()));
+        assert!(!trimesh.contains_point(&isometry, &[0.5, -0.1, 0.5].into()));
+    }
+}
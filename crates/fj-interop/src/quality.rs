@@ -0,0 +1,128 @@
+use fj_math::{Point, Scalar};
+
+use crate::Mesh;
+
+impl Mesh<Point<3>> {
+    /// Compute statistics about the shape of the triangles in the mesh
+    ///
+    /// See [`QualityReport`] for the metrics that are computed.
+    pub fn quality_report(&self) -> QualityReport {
+        let mut min_aspect_ratio = None;
+        let mut sum = Scalar::ZERO;
+        let mut num_triangles = 0;
+        let mut num_slivers = 0;
+
+        for triangle in self.triangles() {
+            let aspect_ratio = aspect_ratio(triangle.inner.points);
+
+            min_aspect_ratio = Some(match min_aspect_ratio {
+                Some(min) if min < aspect_ratio => min,
+                _ => aspect_ratio,
+            });
+            sum += aspect_ratio;
+            num_triangles += 1;
+
+            if aspect_ratio < Scalar::from(QualityReport::SLIVER_THRESHOLD) {
+                num_slivers += 1;
+            }
+        }
+
+        let mean_aspect_ratio = if num_triangles == 0 {
+            Scalar::ZERO
+        } else {
+            sum / Scalar::from_u64(num_triangles as u64)
+        };
+
+        QualityReport {
+            num_triangles,
+            min_aspect_ratio: min_aspect_ratio.unwrap_or(Scalar::ZERO),
+            mean_aspect_ratio,
+            num_slivers,
+        }
+    }
+}
+
+/// Statistics about the shape of the triangles in a [`Mesh`]
+///
+/// Returned by [`Mesh::quality_report`].
+///
+/// The aspect ratio of a triangle is measured as the ratio of its inradius to
+/// its circumradius, scaled such that an equilateral triangle has an aspect
+/// ratio of `1`. Degenerate triangles have an aspect ratio of `0`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QualityReport {
+    /// The number of triangles in the mesh
+    pub num_triangles: usize,
+
+    /// The smallest aspect ratio of any triangle in the mesh
+    ///
+    /// This is `0`, if the mesh has no triangles.
+    pub min_aspect_ratio: Scalar,
+
+    /// The mean aspect ratio of all triangles in the mesh
+    ///
+    /// This is `0`, if the mesh has no triangles.
+    pub mean_aspect_ratio: Scalar,
+
+    /// The number of triangles with an aspect ratio below
+    /// [`QualityReport::SLIVER_THRESHOLD`]
+    pub num_slivers: usize,
+}
+
+impl QualityReport {
+    /// The aspect ratio below which a triangle is considered a sliver
+    pub const SLIVER_THRESHOLD: f64 = 0.1;
+}
+
+fn aspect_ratio([a, b, c]: [Point<3>; 3]) -> Scalar {
+    let [ab, bc, ca] = [b - a, c - b, a - c].map(|edge| edge.magnitude());
+    let perimeter = ab + bc + ca;
+    let product = ab * bc * ca;
+
+    if product == Scalar::ZERO {
+        return Scalar::ZERO;
+    }
+
+    // With the area `A` and the semi-perimeter `s`, the inradius is `A / s`,
+    // and the circumradius is `ab * bc * ca / (4 * A)`. Twice their ratio
+    // simplifies to the following.
+    let area = (b - a).cross(&(c - a)).magnitude() / 2.;
+    area * area * 16. / (perimeter * product)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{test_utils, Color};
+
+    use super::QualityReport;
+
+    #[test]
+    fn cube() {
+        let mut mesh = test_utils::cube();
+
+        let report = mesh.quality_report();
+
+        assert_eq!(report.num_triangles, 12);
+        assert!(report.min_aspect_ratio > Scalar::from(0.8));
+
+        // All triangles of the cube have the same shape.
+        let difference = report.mean_aspect_ratio - report.min_aspect_ratio;
+        assert!(difference.abs() < Scalar::from(1e-12));
+        assert_eq!(report.num_slivers, 0);
+
+        // A long, thin triangle is a sliver.
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0.5, 0.01, 0.]].map(Point::from),
+            Color::default(),
+        );
+        let report = mesh.quality_report();
+
+        assert!(
+            report.min_aspect_ratio
+                < Scalar::from(QualityReport::SLIVER_THRESHOLD)
+        );
+        assert_eq!(report.num_slivers, 1);
+    }
+}
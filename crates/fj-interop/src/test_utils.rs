@@ -0,0 +1,43 @@
+//! # Meshes that are shared between tests
+
+use fj_math::Point;
+
+use crate::{Color, Mesh};
+
+/// # Build a cube with edges of length `1`
+///
+/// The cube extends from the origin to `[1, 1, 1]`. Its triangles are
+/// counter-clockwise, when viewed from outside, and have the default color.
+pub fn cube() -> Mesh<Point<3>> {
+    let [a, b, c, d, e, f, g, h] = [
+        [0., 0., 0.],
+        [1., 0., 0.],
+        [1., 1., 0.],
+        [0., 1., 0.],
+        [0., 0., 1.],
+        [1., 0., 1.],
+        [1., 1., 1.],
+        [0., 1., 1.],
+    ]
+    .map(Point::from);
+
+    let mut mesh = Mesh::new();
+    for triangle in [
+        [a, c, b],
+        [a, d, c],
+        [e, f, g],
+        [e, g, h],
+        [a, b, f],
+        [a, f, e],
+        [b, c, g],
+        [b, g, f],
+        [c, d, h],
+        [c, h, g],
+        [d, a, e],
+        [d, e, h],
+    ] {
+        mesh.push_triangle(triangle, Color::default());
+    }
+
+    mesh
+}
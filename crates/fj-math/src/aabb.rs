@@ -1,7 +1,12 @@
+#[cfg(feature = "std")]
 use parry2d_f64::bounding_volume::BoundingVolume as _;
+#[cfg(feature = "std")]
 use parry3d_f64::bounding_volume::BoundingVolume as _;
 
-use super::{Point, Vector};
+#[cfg(feature = "std")]
+use super::Vector;
+
+use super::Point;
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -47,6 +52,7 @@ impl<const D: usize> Aabb<D> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Aabb<2> {
     /// Construct a 2-dimensional AABB from a list of points
     ///
@@ -83,6 +89,7 @@ impl Aabb<2> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Aabb<3> {
     /// Construct a 3-dimensional AABB from a list of points
     ///
@@ -142,19 +149,21 @@ impl Aabb<3> {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<parry2d_f64::bounding_volume::Aabb> for Aabb<2> {
     fn from(aabb: parry2d_f64::bounding_volume::Aabb) -> Self {
         Self::from_parry(aabb)
     }
 }
 
+#[cfg(feature = "std")]
 impl From<parry3d_f64::bounding_volume::Aabb> for Aabb<3> {
     fn from(aabb: parry3d_f64::bounding_volume::Aabb) -> Self {
         Self::from_parry(aabb)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::Aabb;
 
@@ -67,14 +67,18 @@ impl Arc {
         };
         let end_angle = {
             let from_center = p1 - center;
-            let offset = if more_than_half_turn {
-                Scalar::TAU
-            } else {
-                Scalar::ZERO
-            };
-
-            from_center.v.atan2(from_center.u) + offset
+            let end_angle = from_center.v.atan2(from_center.u);
+
+            // `atan2` returns angles in the range `(-pi, pi]`. Depending on
+            // floating-point noise, points close to the negative u-axis can
+            // end up on either side of that branch cut, for the start and end
+            // angle independently. Pick the end angle that matches the angle
+            // of the arc.
+            let turns =
+                ((start_angle + angle_rad - end_angle) / Scalar::TAU).round();
+            end_angle + Scalar::TAU * turns
         };
+
         Self {
             center,
             radius,
@@ -92,6 +96,29 @@ mod tests {
 
     use approx::{assert_abs_diff_eq, AbsDiffEq};
 
+    #[test]
+    fn arc_span_matches_angle() {
+        // The start point is on the negative u-axis, relative to the center.
+        // Depending on floating-point noise, its angle could be computed as
+        // either `-pi` or `pi`.
+        let center = Point::from([1., 1.]);
+        let radius = 0.3;
+        let points = [[-radius, 0.], [0., -radius]]
+            .map(|offset| center + Vector::from(offset));
+
+        for angle in [90_f64.to_radians(), -270_f64.to_radians()] {
+            let [p0, p1] = points;
+            let arc =
+                Arc::from_endpoints_and_angle(p0, p1, Scalar::from(angle));
+
+            assert_abs_diff_eq!(
+                arc.end_angle - arc.start_angle,
+                Scalar::from(angle),
+                epsilon = Scalar::default_epsilon() * 10.
+            );
+        }
+    }
+
     #[test]
     fn arc_construction() {
         check_arc_calculation(
@@ -6,7 +6,7 @@ use super::Scalar;
 /// [`Deref`]). This allows you to access the `t` field, even though [`Point`]
 /// and [`Vector`] do not have such a field themselves.
 ///
-/// [`Deref`]: std::ops::Deref
+/// [`Deref`]: core::ops::Deref
 /// [`Point`]: crate::Point
 /// [`Vector`]: crate::Vector
 #[repr(C)]
@@ -21,7 +21,7 @@ pub struct T {
 /// [`Deref`]). This allows you to access the `u`/`v` fields, even though
 /// [`Point`] and [`Vector`] do not have such fields themselves.
 ///
-/// [`Deref`]: std::ops::Deref
+/// [`Deref`]: core::ops::Deref
 /// [`Point`]: crate::Point
 /// [`Vector`]: crate::Vector
 #[repr(C)]
@@ -39,7 +39,7 @@ pub struct Uv {
 /// [`Deref`]). This allows you to access the `x`/`y`/`z` fields, even though
 /// [`Point`] and [`Vector`] do not have such fields themselves.
 ///
-/// [`Deref`]: std::ops::Deref
+/// [`Deref`]: core::ops::Deref
 /// [`Point`]: crate::Point
 /// [`Vector`]: crate::Vector
 #[repr(C)]
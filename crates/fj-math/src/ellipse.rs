@@ -0,0 +1,233 @@
+use crate::{Aabb, Circle, Point, Scalar, Transform, Vector};
+
+/// An n-dimensional ellipse
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+///
+/// An ellipse is defined like a [`Circle`], by a center and two vectors, `a`
+/// and `b`. But unlike for a circle, these vectors don't need to be of equal
+/// length, nor perpendicular to each other. They are conjugate semi-diameters
+/// of the ellipse.
+///
+/// This makes an ellipse the image of a circle under an affine transform, with
+/// the same coordinate system as that circle. Points on a circle keep their
+/// circle coordinates, when the circle is converted into an ellipse and
+/// transformed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` and `b` are parallel to each other, or if either of them
+    /// is zero. The ellipse would be degenerate in that case.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        let [aa, bb, ab] = [a.dot(&a), b.dot(&b), a.dot(&b)];
+        assert!(
+            aa * bb - ab * ab > Scalar::ZERO,
+            "`a` and `b` must not be parallel or zero"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the starting point of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the zero
+    /// coordinate of the ellipse's coordinate system.
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the plane of the ellipse
+    ///
+    /// Also defines the direction of the ellipse's coordinate system. This is
+    /// where the ellipse coordinate `PI / 2.` is, relative to the center.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Compute the lengths of the semi-major and semi-minor axes
+    ///
+    /// Returns `[major, minor]`.
+    pub fn radii(&self) -> [Scalar; 2] {
+        let [aa, bb, ab] = [
+            self.a.dot(&self.a),
+            self.b.dot(&self.b),
+            self.a.dot(&self.b),
+        ];
+
+        let mean = (aa + bb) / 2.;
+        let deviation = (((aa - bb) / 2.) * ((aa - bb) / 2.) + ab * ab).sqrt();
+
+        [mean + deviation, (mean - deviation).max(Scalar::ZERO)]
+            .map(Scalar::sqrt)
+    }
+
+    /// Indicate whether the ellipse is a circle
+    ///
+    /// This is the case, if `a` and `b` are of equal length and perpendicular to
+    /// each other, within a small epsilon.
+    pub fn is_circle(&self) -> bool {
+        let [major, minor] = self.radii();
+        major - minor <= major * Scalar::from(1e-9)
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional vector
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin
+    }
+
+    /// Calculate an AABB for the ellipse
+    pub fn aabb(&self) -> Aabb<D> {
+        // The extent of the ellipse along an axis is the magnitude of the
+        // vector made up of the components of `a` and `b` along that axis.
+        let mut center_to_min_max = Vector::from_component(Scalar::ZERO);
+        for i in 0..D {
+            let [a, b] = [self.a, self.b].map(|v| v.components[i]);
+            center_to_min_max.components[i] = (a * a + b * b).sqrt();
+        }
+
+        Aabb {
+            min: self.center - center_to_min_max,
+            max: self.center + center_to_min_max,
+        }
+    }
+}
+
+impl Ellipse<3> {
+    /// # Transform the ellipse
+    pub fn transform(&self, transform: &Transform) -> Self {
+        Ellipse::new(
+            transform.transform_point(&self.center()),
+            transform.transform_vector(&self.a()),
+            transform.transform_vector(&self.b()),
+        )
+    }
+}
+
+impl<const D: usize> From<Circle<D>> for Ellipse<D> {
+    fn from(circle: Circle<D>) -> Self {
+        Self {
+            center: circle.center(),
+            a: circle.a(),
+            b: circle.b(),
+        }
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Ellipse<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+impl<const D: usize> approx::RelativeEq for Ellipse<D> {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.center
+            .relative_eq(&other.center, epsilon, max_relative)
+            && self.a.relative_eq(&other.a, epsilon, max_relative)
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Circle, Point, Scalar, Transform, Vector};
+
+    use super::Ellipse;
+
+    #[test]
+    fn radii() {
+        let ellipse = Ellipse::new([0., 0.], [2., 0.], [0., 1.]);
+        assert_eq!(ellipse.radii(), [2., 1.].map(Scalar::from));
+        assert!(!ellipse.is_circle());
+
+        // `a` and `b` don't need to be perpendicular.
+        let sheared = Ellipse::new([0., 0.], [2., 0.], [1., 1.]);
+        let [major, minor] = sheared.radii();
+        assert!(major > Scalar::from(2.));
+        assert!(minor < Scalar::ONE);
+
+        let circle =
+            Ellipse::from(Circle::from_center_and_radius([0., 0.], 1.));
+        assert!(circle.is_circle());
+    }
+
+    #[test]
+    fn transform() {
+        let circle = Ellipse::from(Circle::from_center_and_radius(
+            Point::<3>::origin(),
+            1.,
+        ));
+        let scale = Transform::scale_non_uniform([2., 1., 1.]);
+
+        let ellipse = circle.transform(&scale);
+        assert_eq!(ellipse.radii(), [2., 1.].map(Scalar::from));
+        assert_eq!(
+            ellipse.point_from_ellipse_coords([0.]),
+            Point::from([2., 0., 0.]),
+        );
+        assert_eq!(ellipse.b(), Vector::from([0., 1., 0.]));
+    }
+}
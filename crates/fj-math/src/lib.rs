@@ -27,20 +27,39 @@
 //! [`From`]/[`Into`] documentation fails to provide any reasons for its
 //! mandate.
 //!
+//!
+//! ## `no_std` support
+//!
+//! This library supports `no_std` environments, as long as an allocator is
+//! available. Disable the default `std` feature to enable this.
+//!
+//! Without `std`, the core math types ([`Scalar`], [`Point`], [`Vector`], and
+//! everything built directly on top of them) are fully available. Operations
+//! that are implemented using [Parry] or [robust], like ray casting against
+//! [`Triangle`]s or merging [`Aabb`]s, require the `std` feature.
+//!
 //! [Fornjot]: https://www.fornjot.app/
 //! [nalgebra]: https://nalgebra.org/
 //! [Parry]: https://www.parry.rs/
+//! [robust]: https://crates.io/crates/robust
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod aabb;
 mod arc;
 mod bivector;
 mod circle;
 mod coordinates;
+mod ellipse;
 mod line;
 mod line_segment;
 mod point;
 mod poly_chain;
+mod polygon;
 mod scalar;
+mod segment_intersection;
 mod transform;
 mod triangle;
 mod vector;
@@ -51,11 +70,14 @@ pub use self::{
     bivector::Bivector,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
+    ellipse::Ellipse,
     line::Line,
     line_segment::LineSegment,
     point::Point,
     poly_chain::PolyChain,
+    polygon::{signed_area, FillRule, Polygon},
     scalar::{Scalar, Sign},
+    segment_intersection::{segment_intersection, SegmentIntersection},
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
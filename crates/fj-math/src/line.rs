@@ -171,6 +171,40 @@ impl Line<3> {
     }
 }
 
+impl<const D: usize> approx::AbsDiffEq for Line<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.origin.abs_diff_eq(&other.origin, epsilon)
+            && self.direction.abs_diff_eq(&other.direction, epsilon)
+    }
+}
+
+impl<const D: usize> approx::RelativeEq for Line<D> {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.origin
+            .relative_eq(&other.origin, epsilon, max_relative)
+            && self.direction.relative_eq(
+                &other.direction,
+                epsilon,
+                max_relative,
+            )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
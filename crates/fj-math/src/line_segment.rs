@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::{Line, Scalar};
 
@@ -31,6 +31,23 @@ impl<const D: usize> LineSegment<D> {
         self
     }
 
+    /// Compute the point on the segment that is closest to the provided point
+    pub fn closest_point(&self, point: impl Into<Point<D>>) -> Point<D> {
+        let point = point.into();
+        let [a, b] = self.points;
+
+        let ab = b - a;
+        let length_squared = ab.dot(&ab);
+        if length_squared == Scalar::ZERO {
+            return a;
+        }
+
+        let t = ((point - a).dot(&ab) / length_squared)
+            .clamp(Scalar::ZERO, Scalar::ONE);
+
+        a + ab * t
+    }
+
     /// # Convert this `LineSegment` into a [`Line`]
     ///
     /// This provides access to various utility methods.
@@ -42,6 +59,7 @@ impl<const D: usize> LineSegment<D> {
     }
 }
 
+#[cfg(feature = "std")]
 impl LineSegment<2> {
     /// Convert the 2-dimensional segment to a Parry segment
     pub fn to_parry(self) -> parry2d_f64::shape::Segment {
@@ -49,6 +67,7 @@ impl LineSegment<2> {
     }
 }
 
+#[cfg(feature = "std")]
 impl LineSegment<3> {
     /// Convert the 3-dimensional segment to a Parry segment
     pub fn to_parry(self) -> parry3d_f64::shape::Segment {
@@ -1,4 +1,4 @@
-use std::{fmt, ops};
+use core::{fmt, ops};
 
 use super::{
     coordinates::{Uv, Xyz, T},
@@ -63,6 +63,29 @@ impl<const D: usize> Point<D> {
     pub fn distance_to(&self, other: &Self) -> Scalar {
         (self.coords - other.coords).magnitude()
     }
+
+    /// Indicate whether two points are approximately equal
+    ///
+    /// The points are considered equal, if the distance between them is not
+    /// larger than the provided tolerance. Unlike the [`approx::AbsDiffEq`]
+    /// implementation, this measures the distance, not the difference of each
+    /// coordinate.
+    pub fn approx_eq(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        self.distance_to(other) <= tolerance.into()
+    }
+
+    /// Set coordinates that are within the provided tolerance of zero to zero
+    ///
+    /// See [`Vector::snap_zeros`].
+    pub fn snap_zeros(self, tolerance: impl Into<Scalar>) -> Self {
+        Self {
+            coords: self.coords.snap_zeros(tolerance),
+        }
+    }
 }
 
 impl ops::Deref for Point<1> {
@@ -217,3 +240,48 @@ impl<const D: usize> approx::AbsDiffEq for Point<D> {
         self.coords.abs_diff_eq(&other.coords, epsilon)
     }
 }
+
+impl<const D: usize> approx::RelativeEq for Point<D> {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.coords
+            .relative_eq(&other.coords, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Point;
+
+    #[test]
+    fn approx_eq() {
+        let a = Point::from([1., 2., 3.]);
+
+        assert!(a.approx_eq(&Point::from([1., 2., 3. + 1e-9]), 1e-6));
+        assert!(!a.approx_eq(&Point::from([1., 2., 4.]), 1e-6));
+
+        // The distance is what counts, not the difference per coordinate.
+        let b = Point::from([1. + 0.8e-6, 2. + 0.8e-6, 3.]);
+        assert!(!a.approx_eq(&b, 1e-6));
+    }
+
+    #[test]
+    fn snap_zeros() {
+        let point = Point::from([1e-17, 1., -2e-16]);
+
+        assert_eq!(point.snap_zeros(1e-12), Point::from([0., 1., 0.]));
+        assert_eq!(point.snap_zeros(0.), point);
+
+        // Components that are not noise are left alone.
+        let point = Point::from([1e-6, 2e-13]);
+        assert_eq!(point.snap_zeros(1e-12), Point::from([1e-6, 0.]));
+    }
+}
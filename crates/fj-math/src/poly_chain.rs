@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{LineSegment, Point};
 
 /// A polygonal chain
@@ -38,6 +40,11 @@ impl<const D: usize> PolyChain<D> {
         Self { points }
     }
 
+    /// Access the points of the polygonal chain
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
     /// Access the segments of the polygonal chain
     pub fn segments(&self) -> Vec<LineSegment<D>> {
         let mut segments = Vec::new();
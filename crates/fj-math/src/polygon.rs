@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+
+use crate::{LineSegment, Point, PolyChain, Scalar};
+
+/// A polygon
+///
+/// The dimensionality of the polygon is defined by the const generic `D`
+/// parameter.
+///
+/// Unlike a [`PolyChain`], a polygon is always closed. Its last point connects
+/// back to its first point, without that point being repeated.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Polygon<const D: usize> {
+    points: Vec<Point<D>>,
+}
+
+impl<const D: usize> Polygon<D> {
+    /// Construct a polygon from a number of points
+    ///
+    /// If the last point is the same as the first, it is ignored. This means
+    /// the points of an already closed [`PolyChain`] can be passed here.
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let mut points = points.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        Self { points }
+    }
+
+    /// Access the points of the polygon
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
+    /// Access the edges of the polygon
+    ///
+    /// This includes the edge from the last point back to the first one.
+    pub fn edges(&self) -> Vec<LineSegment<D>> {
+        let mut edges = Vec::new();
+
+        for (i, &a) in self.points.iter().enumerate() {
+            let b = self.points[(i + 1) % self.points.len()];
+            edges.push(LineSegment::from([a, b]));
+        }
+
+        edges
+    }
+}
+
+impl Polygon<2> {
+    /// Compute the signed area of the polygon
+    ///
+    /// See [`signed_area`].
+    pub fn signed_area(&self) -> Scalar {
+        signed_area(&self.points)
+    }
+
+    /// Determine whether the polygon contains a point
+    ///
+    /// Points exactly on the boundary of the polygon are always contained,
+    /// regardless of the fill rule. Use [`Polygon::is_on_boundary`] with a
+    /// non-zero tolerance, to also include points close to the boundary.
+    pub fn contains(&self, point: impl Into<Point<2>>, rule: FillRule) -> bool {
+        let point = point.into();
+
+        if self.is_on_boundary(point, Scalar::ZERO) {
+            return true;
+        }
+
+        let winding_number = self.winding_number(point);
+
+        match rule {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+        }
+    }
+
+    /// Determine whether a point is on the boundary of the polygon
+    ///
+    /// A point is considered to be on the boundary, if its distance to any of
+    /// the polygon's edges is no larger than `tolerance`. Pass a tolerance of
+    /// zero, to only accept points that are exactly on the boundary.
+    pub fn is_on_boundary(
+        &self,
+        point: impl Into<Point<2>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        self.edges().into_iter().any(|edge| {
+            let [a, b] = edge.points;
+
+            let is_near_line = (b - a).cross2d(&(point - a)).abs()
+                <= tolerance * (b - a).magnitude();
+            let is_within = (point - a).dot(&(point - b)) <= Scalar::ZERO;
+            let is_near_end = point.distance_to(&a) <= tolerance
+                || point.distance_to(&b) <= tolerance;
+
+            (is_near_line && is_within) || is_near_end
+        })
+    }
+
+    /// Compute the winding number of the polygon around a point
+    ///
+    /// The winding number is positive, if the polygon winds around the point
+    /// counter-clockwise, and negative, if it winds around it clockwise.
+    ///
+    /// The result is not meaningful for points on the boundary of the polygon.
+    /// Use [`Polygon::is_on_boundary`] to check for those first.
+    pub fn winding_number(&self, point: impl Into<Point<2>>) -> i32 {
+        let point = point.into();
+
+        let mut winding_number = 0;
+
+        for edge in self.edges() {
+            let [a, b] = edge.points;
+
+            // Each edge is treated as including its lower point, but not its
+            // upper one. That way, an edge crossing at a vertex is counted
+            // exactly once, and horizontal edges are not counted at all.
+            let side = (b - a).cross2d(&(point - a));
+
+            if a.v <= point.v && point.v < b.v && side > Scalar::ZERO {
+                winding_number += 1;
+            }
+            if b.v <= point.v && point.v < a.v && side < Scalar::ZERO {
+                winding_number -= 1;
+            }
+        }
+
+        winding_number
+    }
+}
+
+impl<const D: usize> From<PolyChain<D>> for Polygon<D> {
+    fn from(chain: PolyChain<D>) -> Self {
+        Self::from_points(chain.points().iter().copied())
+    }
+}
+
+/// Compute the signed area of a closed polygon, using the shoelace formula
+///
+/// The polygon is implicitly closed, by connecting the last point back to the
+/// first. Repeating the first point at the end is allowed, and doesn't change
+/// the result.
+///
+/// The area is positive, if the points are in counter-clockwise order, and
+/// negative, if they are in clockwise order.
+pub fn signed_area(points: &[Point<2>]) -> Scalar {
+    let twice_area = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(Scalar::ZERO, |area, (a, b)| area + a.u * b.v - b.u * a.v);
+
+    twice_area / 2.
+}
+
+/// The rule that determines which points are contained in a [`Polygon`]
+///
+/// The rules only differ for polygons that overlap themselves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FillRule {
+    /// A point is contained, if the polygon winds around it an odd number of
+    /// times
+    EvenOdd,
+
+    /// A point is contained, if the polygon winds around it at all
+    NonZero,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, PolyChain, Scalar};
+
+    use super::{signed_area, FillRule, Polygon};
+
+    #[test]
+    fn signed_area_of_square() {
+        let ccw = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]].map(Point::from);
+        let mut cw = ccw;
+        cw.reverse();
+
+        assert_eq!(signed_area(&ccw), Scalar::from(4.));
+        assert_eq!(signed_area(&cw), Scalar::from(-4.));
+
+        // Repeating the first point at the end doesn't change the area.
+        let closed = [ccw.as_slice(), &ccw[..1]].concat();
+        assert_eq!(signed_area(&closed), Scalar::from(4.));
+
+        assert_eq!(Polygon::from_points(ccw).signed_area(), Scalar::from(4.));
+    }
+
+    #[test]
+    fn self_overlapping() {
+        // A pentagram. Its center is enclosed twice.
+        let pentagram = Polygon::from_points([
+            [0., 3.],
+            [2., -3.],
+            [-3., 1.],
+            [3., 1.],
+            [-2., -3.],
+        ]);
+        let center = Point::from([0., 0.]);
+
+        assert_eq!(pentagram.winding_number(center).abs(), 2);
+        assert!(!pentagram.contains(center, FillRule::EvenOdd));
+        assert!(pentagram.contains(center, FillRule::NonZero));
+
+        // A point in one of the tips is enclosed once.
+        let tip = [0., 2.];
+        assert!(pentagram.contains(tip, FillRule::EvenOdd));
+        assert!(pentagram.contains(tip, FillRule::NonZero));
+    }
+
+    #[test]
+    fn boundary() {
+        let square = Polygon::from(
+            PolyChain::from([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]).close(),
+        );
+        assert_eq!(square.points().len(), 4);
+
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            assert!(square.contains([0.5, 0.], rule));
+            assert!(square.contains([1., 1.], rule));
+            assert!(square.contains([0.5, 0.5], rule));
+            assert!(!square.contains([1.5, 0.5], rule));
+            assert!(!square.contains([2., 0.], rule));
+        }
+
+        assert!(!square.is_on_boundary([0.5, 0.1], 0.));
+        assert!(square.is_on_boundary([0.5, 0.1], 0.2));
+        assert!(square.is_on_boundary([1.1, 1.1], 0.2));
+        assert!(!square.is_on_boundary([1.5, 1.5], 0.2));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{
+use core::{
     cmp,
     f64::consts::{PI, TAU},
     fmt,
@@ -7,6 +7,9 @@ use std::{
 };
 
 use decorum::R64;
+// Without `std`, floating point math on `f64` is provided by `libm`.
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float as _;
 
 /// A rational, finite scalar value
 ///
@@ -144,6 +147,11 @@ impl Scalar {
         (sin.into(), cos.into())
     }
 
+    /// Compute the square root
+    pub fn sqrt(self) -> Self {
+        self.0.sqrt().into()
+    }
+
     /// Compute the arccosine
     pub fn acos(self) -> Self {
         self.0.acos().into()
@@ -178,7 +186,7 @@ impl Ord for Scalar {
 }
 
 impl Hash for Scalar {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         // To the best of my knowledge, this matches the `PartialEq`
         // implementation.
         R64::from_inner(self.0).hash(state);
@@ -396,7 +404,7 @@ impl num_traits::Float for Scalar {
         self.0.is_normal()
     }
 
-    fn classify(self) -> std::num::FpCategory {
+    fn classify(self) -> core::num::FpCategory {
         self.0.classify()
     }
 
@@ -594,6 +602,21 @@ impl approx::AbsDiffEq for Scalar {
     }
 }
 
+impl approx::RelativeEq for Scalar {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative().into()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+    }
+}
+
 /// The sign of a [`Scalar`]
 ///
 /// See [`Scalar::sign`]
@@ -0,0 +1,223 @@
+use crate::{Point, Scalar};
+
+/// Compute the intersection of two 2D line segments
+///
+/// Returns `None`, if the segments don't intersect. This includes the case of
+/// parallel segments that are not collinear.
+///
+/// Segments that touch at an endpoint, or where one segment ends on the
+/// interior of another (a T-junction), intersect in a single point. If that
+/// point is close to an endpoint, the endpoint itself is returned, so callers
+/// can rely on exact equality in that case.
+///
+/// Floating point error is accounted for with a tolerance relative to the size
+/// of the segments. Segments are considered parallel, if the sine of the angle
+/// between them is below that tolerance.
+pub fn segment_intersection(
+    a: [Point<2>; 2],
+    b: [Point<2>; 2],
+) -> Option<SegmentIntersection> {
+    let [a0, a1] = a;
+    let [b0, b1] = b;
+
+    let epsilon = Scalar::from(RELATIVE_TOLERANCE);
+
+    let r = a1 - a0;
+    let s = b1 - b0;
+
+    let scale = r.magnitude().max(s.magnitude());
+    if scale == Scalar::ZERO {
+        return (a0 == b0).then_some(SegmentIntersection::Point { point: a0 });
+    }
+    let distance_tolerance = scale * epsilon;
+
+    // A degenerate segment is just a point. Make sure it's `b`, so the code
+    // below can divide by the length of `a`.
+    if r.magnitude() <= distance_tolerance {
+        return segment_intersection(b, a);
+    }
+    if s.magnitude() <= distance_tolerance {
+        let t = (b0 - a0).dot(&r) / r.dot(&r);
+        let distance = (a0 + r * t - b0).magnitude();
+
+        let is_on_a = distance <= distance_tolerance
+            && t >= -epsilon
+            && t <= Scalar::ONE + epsilon;
+        return is_on_a.then_some(SegmentIntersection::Point { point: b0 });
+    }
+
+    let denominator = r.cross2d(&s);
+    let a0_to_b0 = b0 - a0;
+
+    if denominator.abs() <= r.magnitude() * s.magnitude() * epsilon {
+        // The segments are parallel. They only intersect, if they are also
+        // collinear.
+        let distance = a0_to_b0.cross2d(&r).abs() / r.magnitude();
+        if distance > distance_tolerance {
+            return None;
+        }
+
+        // Express the points of `b` in the coordinates of `a`, then intersect
+        // the ranges.
+        let t_b0 = a0_to_b0.dot(&r) / r.dot(&r);
+        let t_b1 = (b1 - a0).dot(&r) / r.dot(&r);
+
+        let (b_start, b_end) = if t_b0 <= t_b1 {
+            ((t_b0, b0), (t_b1, b1))
+        } else {
+            ((t_b1, b1), (t_b0, b0))
+        };
+
+        let start = if b_start.0 > Scalar::ZERO {
+            b_start
+        } else {
+            (Scalar::ZERO, a0)
+        };
+        let end = if b_end.0 < Scalar::ONE {
+            b_end
+        } else {
+            (Scalar::ONE, a1)
+        };
+
+        let overlap = (end.0 - start.0) * r.magnitude();
+        if overlap < -distance_tolerance {
+            return None;
+        }
+        if overlap <= distance_tolerance {
+            return Some(SegmentIntersection::Point { point: start.1 });
+        }
+
+        return Some(SegmentIntersection::Overlap {
+            points: [start.1, end.1],
+        });
+    }
+
+    let t = a0_to_b0.cross2d(&s) / denominator;
+    let u = a0_to_b0.cross2d(&r) / denominator;
+
+    let range = -epsilon..=Scalar::ONE + epsilon;
+    if !range.contains(&t) || !range.contains(&u) {
+        return None;
+    }
+
+    // Prefer the exact endpoints, if the intersection is at one of them.
+    let point = [(t, a0, a1), (u, b0, b1)]
+        .into_iter()
+        .find_map(|(param, start, end)| {
+            if param.abs() <= epsilon {
+                Some(start)
+            } else if (param - Scalar::ONE).abs() <= epsilon {
+                Some(end)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(a0 + r * t);
+
+    Some(SegmentIntersection::Point { point })
+}
+
+/// The intersection of two 2D line segments
+///
+/// Returned by [`segment_intersection`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SegmentIntersection {
+    /// The segments intersect in a single point
+    Point {
+        /// The intersection point
+        point: Point<2>,
+    },
+
+    /// The segments are collinear and overlap
+    Overlap {
+        /// The end points of the overlapping part of the segments
+        ///
+        /// The points are ordered along the direction of the first segment.
+        points: [Point<2>; 2],
+    },
+}
+
+const RELATIVE_TOLERANCE: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use crate::Point;
+
+    use super::{segment_intersection, SegmentIntersection};
+
+    #[test]
+    fn crossing() {
+        let a = [[0., 0.], [2., 2.]].map(Point::from);
+        let b = [[0., 2.], [2., 0.]].map(Point::from);
+
+        assert_eq!(
+            segment_intersection(a, b),
+            Some(SegmentIntersection::Point {
+                point: Point::from([1., 1.])
+            })
+        );
+    }
+
+    #[test]
+    fn not_crossing() {
+        let a = [[0., 0.], [1., 1.]].map(Point::from);
+        let b = [[3., 0.], [2., 1.]].map(Point::from);
+
+        assert_eq!(segment_intersection(a, b), None);
+    }
+
+    #[test]
+    fn parallel() {
+        let a = [[0., 0.], [2., 0.]].map(Point::from);
+        let b = [[0., 1.], [2., 1.]].map(Point::from);
+
+        assert_eq!(segment_intersection(a, b), None);
+    }
+
+    #[test]
+    fn collinear_overlapping() {
+        let a = [[0., 0.], [2., 0.]].map(Point::from);
+        let b = [[3., 0.], [1., 0.]].map(Point::from);
+
+        assert_eq!(
+            segment_intersection(a, b),
+            Some(SegmentIntersection::Overlap {
+                points: [[1., 0.], [2., 0.]].map(Point::from)
+            })
+        );
+    }
+
+    #[test]
+    fn collinear_touching() {
+        let a = [[0., 0.], [1., 0.]].map(Point::from);
+        let b = [[1., 0.], [2., 0.]].map(Point::from);
+
+        assert_eq!(
+            segment_intersection(a, b),
+            Some(SegmentIntersection::Point {
+                point: Point::from([1., 0.])
+            })
+        );
+    }
+
+    #[test]
+    fn collinear_disjoint() {
+        let a = [[0., 0.], [1., 0.]].map(Point::from);
+        let b = [[2., 0.], [3., 0.]].map(Point::from);
+
+        assert_eq!(segment_intersection(a, b), None);
+    }
+
+    #[test]
+    fn t_junction() {
+        let a = [[0., 0.], [2., 0.]].map(Point::from);
+        let b = [[1., 1.], [1., 0.]].map(Point::from);
+
+        assert_eq!(
+            segment_intersection(a, b),
+            Some(SegmentIntersection::Point {
+                point: Point::from([1., 0.])
+            })
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops;
+use core::ops;
 
 use nalgebra::Perspective3;
 
@@ -47,6 +47,18 @@ impl Transform {
         ))
     }
 
+    /// Construct a non-uniform scaling
+    ///
+    /// Each component of `scaling_factors` defines the scaling along the
+    /// respective axis.
+    pub fn scale_non_uniform(scaling_factors: impl Into<Vector<3>>) -> Self {
+        let scaling_factors = scaling_factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::OMatrix::new_nonuniform_scaling(&scaling_factors.to_na()),
+        ))
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -143,6 +155,14 @@ impl Transform {
     }
 }
 
+impl From<nalgebra::Isometry3<f64>> for Transform {
+    fn from(isometry: nalgebra::Isometry3<f64>) -> Self {
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            isometry.to_homogeneous(),
+        ))
+    }
+}
+
 impl ops::Mul<Self> for Transform {
     type Output = Self;
 
@@ -159,6 +179,23 @@ mod tests {
 
     use super::Transform;
 
+    #[test]
+    fn from_isometry() {
+        let isometry = nalgebra::Isometry3::new(
+            nalgebra::Vector3::new(1., 2., 3.),
+            nalgebra::Vector3::z() * (Scalar::PI / 2.).into_f64(),
+        );
+
+        let expected = Transform::translation([1., 2., 3.])
+            * Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.));
+
+        assert_abs_diff_eq!(
+            Transform::from(isometry).data(),
+            expected.data(),
+            epsilon = 1e-8,
+        );
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
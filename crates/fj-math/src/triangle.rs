@@ -1,6 +1,8 @@
 use approx::AbsDiffEq;
-use parry3d_f64::query::{Ray, RayCast as _};
+#[cfg(feature = "std")]
+use parry3d_f64::query::{PointQuery as _, Ray, RayCast as _};
 
+#[cfg(feature = "std")]
 use crate::Vector;
 
 use super::{Point, Scalar};
@@ -67,6 +69,7 @@ impl<const D: usize> Triangle<D> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Triangle<2> {
     /// Returns the direction of the line through the points of the triangle.
     pub fn winding(&self) -> Winding {
@@ -90,6 +93,7 @@ impl Triangle<2> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Triangle<3> {
     /// Convert the triangle to a Parry triangle
     pub fn to_parry(self) -> parry3d_f64::shape::Triangle {
@@ -114,6 +118,15 @@ impl Triangle<3> {
             .map(Into::into)
     }
 
+    /// Compute the point on the triangle that is closest to the provided point
+    pub fn closest_point(&self, point: impl Into<Point<3>>) -> Point<3> {
+        let point = point.into();
+
+        let projection =
+            self.to_parry().project_local_point(&point.to_na(), true);
+        Point::from_na(projection.point)
+    }
+
     /// Compute the triangle's normal
     pub fn normal(&self) -> Vector<3> {
         self.to_parry()
@@ -155,7 +168,7 @@ impl Winding {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::{Point, Vector};
 
@@ -203,4 +216,19 @@ mod tests {
             Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
         assert_eq!(triangle.normal(), Vector::from([0.0, 0.0, -1.0]));
     }
+
+    #[test]
+    fn closest_point() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        assert_eq!(
+            triangle.closest_point([0.5, 0.5, 1.0]),
+            Point::from([0.5, 0.5, 0.0])
+        );
+        assert_eq!(
+            triangle.closest_point([-1.0, -1.0, 0.0]),
+            Point::from([0.0, 0.0, 0.0])
+        );
+    }
 }
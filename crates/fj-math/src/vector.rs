@@ -1,4 +1,4 @@
-use std::{fmt, ops};
+use core::{fmt, ops};
 
 use crate::Bivector;
 
@@ -82,6 +82,36 @@ impl<const D: usize> Vector<D> {
         self.to_na().magnitude().into()
     }
 
+    /// Indicate whether two vectors are approximately equal
+    ///
+    /// The vectors are considered equal, if the magnitude of their difference
+    /// is not larger than the provided tolerance.
+    pub fn approx_eq(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        (*self - *other).magnitude() <= tolerance.into()
+    }
+
+    /// Set components that are within the provided tolerance of zero to zero
+    ///
+    /// This removes noise from floating-point inaccuracy, like a component of
+    /// `1e-17` that should be exactly zero.
+    pub fn snap_zeros(self, tolerance: impl Into<Scalar>) -> Self {
+        let tolerance = tolerance.into();
+
+        let components = self.components.map(|component| {
+            if component.abs() <= tolerance {
+                Scalar::ZERO
+            } else {
+                component
+            }
+        });
+
+        Self { components }
+    }
+
     /// Compute a normalized version of the vector
     pub fn normalize(&self) -> Self {
         self.to_na().normalize().into()
@@ -370,6 +400,24 @@ impl<const D: usize> approx::AbsDiffEq for Vector<D> {
     }
 }
 
+impl<const D: usize> approx::RelativeEq for Vector<D> {
+    fn default_max_relative() -> Self::Epsilon {
+        Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Scalar, Vector};
@@ -395,6 +443,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn approx_eq() {
+        let a = Vector::from([1., 2.]);
+
+        assert!(a.approx_eq(&Vector::from([1. + 1e-9, 2.]), 1e-6));
+        assert!(!a.approx_eq(&Vector::from([2., 1.]), 1e-6));
+    }
+
     #[test]
     fn scalar_projection_onto() {
         let v = Vector::from([1., 2., 3.]);